@@ -14,7 +14,7 @@ use device_envoy::{
     led_strip::colors,
     led2d,
     led2d::{Led2dFont, layout::LedLayout},
-    wifi_auto::{WifiAuto, WifiAutoEvent},
+    wifi_auto::{WifiAuto, WifiAutoEvent, WifiPowerMode},
 };
 use embassy_executor::Spawner;
 use embassy_net::dns::DnsQueryType;
@@ -64,6 +64,9 @@ async fn inner_main(spawner: Spawner) -> Result<Infallible> {
         PressedTo::Ground,
         "PicoDemo", // Setup SSID
         [],         // Any custom fields
+        WifiPowerMode::PowerSave,
+        4,                       // Connect attempts
+        Duration::from_secs(40), // Timeout for each attempt
         spawner,
     )?;
 
@@ -75,11 +78,14 @@ async fn inner_main(spawner: Spawner) -> Result<Infallible> {
     let (stack, _button) = wifi_auto
         .connect(|event| async move {
             match event {
-                WifiAutoEvent::CaptivePortalReady => {
+                WifiAutoEvent::CaptivePortalReady { .. } => {
                     led8x12_ref.write_text("JO\nIN", COLORS).await? // Join setup network
                 }
                 WifiAutoEvent::Connecting { .. } => show_animated_dots(led8x12_ref).await?,
-                WifiAutoEvent::ConnectionFailed => led8x12_ref.write_text("FA\nIL", COLORS).await?,
+                WifiAutoEvent::Connected { .. } => {}
+                WifiAutoEvent::ConnectionFailed { .. } => {
+                    led8x12_ref.write_text("FA\nIL", COLORS).await?
+                }
             }
             Ok(())
         })
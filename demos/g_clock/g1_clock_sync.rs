@@ -14,9 +14,10 @@ use device_envoy::{
     clock_sync::{ClockSync, ClockSyncStatic, ONE_SECOND, h12_m_s},
     flash_array::FlashArray,
     wifi_auto::fields::{TimezoneField, TimezoneFieldStatic},
-    wifi_auto::{WifiAuto, WifiAutoEvent},
+    wifi_auto::{WifiAuto, WifiAutoEvent, WifiPowerMode},
 };
 use embassy_executor::Spawner;
+use embassy_time::Duration;
 use {defmt_rtt as _, panic_probe as _};
 
 #[embassy_executor::main]
@@ -47,19 +48,25 @@ async fn inner_main(spawner: Spawner) -> Result<Infallible> {
         PressedTo::Ground,
         "ClockSync",
         [timezone_field], // Additional field(s)
+        WifiPowerMode::PowerSave,
+        4,                       // Connect attempts
+        Duration::from_secs(40), // Timeout for each attempt
         spawner,
     )?;
 
     let (stack, _button) = wifi_auto
         .connect(|event| async move {
             match event {
-                WifiAutoEvent::CaptivePortalReady => {
+                WifiAutoEvent::CaptivePortalReady { .. } => {
                     info!("WifiAuto: setup mode ready");
                 }
                 WifiAutoEvent::Connecting { .. } => {
                     info!("WifiAuto: connecting");
                 }
-                WifiAutoEvent::ConnectionFailed => {
+                WifiAutoEvent::Connected { .. } => {
+                    info!("WifiAuto: connected");
+                }
+                WifiAutoEvent::ConnectionFailed { .. } => {
                     info!("WifiAuto: connection failed");
                 }
             }
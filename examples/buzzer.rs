@@ -0,0 +1,44 @@
+#![allow(missing_docs)]
+#![no_std]
+#![no_main]
+
+use core::{convert::Infallible, panic};
+use device_envoy::{Result, buzzer::Buzzer};
+use embassy_executor::Spawner;
+use embassy_time::{Duration, Timer};
+use {defmt_rtt as _, panic_probe as _};
+
+// Opening of "Twinkle, Twinkle, Little Star"; `None` is a rest.
+const MELODY: [(Option<u32>, Duration); 8] = [
+    (Some(262), Duration::from_millis(400)), // C4
+    (Some(262), Duration::from_millis(400)), // C4
+    (Some(392), Duration::from_millis(400)), // G4
+    (Some(392), Duration::from_millis(400)), // G4
+    (Some(440), Duration::from_millis(400)), // A4
+    (Some(440), Duration::from_millis(400)), // A4
+    (Some(392), Duration::from_millis(800)), // G4
+    (None, Duration::from_millis(400)),
+];
+
+#[embassy_executor::main]
+async fn main(spawner: Spawner) -> ! {
+    let err = inner_main(spawner).await.unwrap_err();
+    panic!("{err}");
+}
+
+async fn inner_main(_spawner: Spawner) -> Result<Infallible> {
+    let p = embassy_rp::init(Default::default());
+
+    // Create a buzzer on GPIO 15.
+    // GPIO 15 → (15/2) % 8 = 7 → PWM_SLICE7
+    let mut buzzer = Buzzer::new(p.PIN_15, p.PWM_SLICE7);
+
+    loop {
+        buzzer
+            .beep(2, Duration::from_millis(80), Duration::from_millis(80))
+            .await;
+        Timer::after(Duration::from_millis(500)).await;
+        buzzer.play(MELODY).await;
+        Timer::after(Duration::from_secs(2)).await;
+    }
+}
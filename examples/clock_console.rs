@@ -18,9 +18,11 @@ use device_envoy::clock_sync::{ClockSync, ClockSyncStatic, ONE_SECOND};
 use device_envoy::flash_array::FlashArray;
 use device_envoy::wifi_auto::WifiAuto;
 use device_envoy::wifi_auto::WifiAutoEvent;
+use device_envoy::wifi_auto::WifiPowerMode;
 use device_envoy::wifi_auto::fields::{TimezoneField, TimezoneFieldStatic};
 use device_envoy::{Error, Result};
 use embassy_executor::Spawner;
+use embassy_time::Duration;
 use panic_probe as _;
 
 #[embassy_executor::main]
@@ -55,6 +57,9 @@ async fn inner_main(spawner: Spawner) -> Result<Infallible> {
         PressedTo::Ground,
         "www.picoclock.net",
         [timezone_field],
+        WifiPowerMode::PowerSave,
+        4,                       // Connect attempts
+        Duration::from_secs(40), // Timeout for each attempt
         spawner,
     )?;
 
@@ -62,12 +67,13 @@ async fn inner_main(spawner: Spawner) -> Result<Infallible> {
     let (stack, _button) = wifi_auto
         .connect(|event| async move {
             match event {
-                WifiAutoEvent::CaptivePortalReady => {
+                WifiAutoEvent::CaptivePortalReady { .. } => {
                     info!("Captive portal ready - connect to WiFi network");
                 }
                 WifiAutoEvent::Connecting {
                     try_index,
                     try_count,
+                    ..
                 } => {
                     info!(
                         "Connecting to WiFi (attempt {} of {})...",
@@ -75,7 +81,10 @@ async fn inner_main(spawner: Spawner) -> Result<Infallible> {
                         try_count
                     );
                 }
-                WifiAutoEvent::ConnectionFailed => {
+                WifiAutoEvent::Connected { .. } => {
+                    info!("WiFi connected");
+                }
+                WifiAutoEvent::ConnectionFailed { .. } => {
                     info!("WiFi connection failed!");
                 }
             }
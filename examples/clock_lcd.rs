@@ -13,10 +13,11 @@ use device_envoy::button::PressedTo;
 use device_envoy::char_lcd::{CharLcd, CharLcdStatic};
 use device_envoy::clock_sync::{ClockSync, ClockSyncStatic, ONE_SECOND};
 use device_envoy::flash_array::FlashArray;
-use device_envoy::wifi_auto::WifiAuto;
 use device_envoy::wifi_auto::fields::{TimezoneField, TimezoneFieldStatic};
+use device_envoy::wifi_auto::{WifiAuto, WifiPowerMode};
 use device_envoy::{Error, Result};
 use embassy_executor::Spawner;
+use embassy_time::Duration;
 use heapless::String;
 use panic_probe as _;
 
@@ -39,7 +40,7 @@ async fn inner_main(spawner: Spawner) -> Result<Infallible> {
 
     // Initialize CharLcd
     static CHAR_LCD_STATIC: CharLcdStatic = CharLcd::new_static();
-    let char_lcd = CharLcd::new(&CHAR_LCD_STATIC, p.I2C0, p.PIN_5, p.PIN_4, spawner)?;
+    let char_lcd = CharLcd::new(&CHAR_LCD_STATIC, p.I2C0, p.PIN_5, p.PIN_4, 16, spawner)?;
 
     // Use two blocks of flash storage: Wi-Fi credentials + timezone
     let [wifi_credentials_flash_block, timezone_flash_block] = FlashArray::<2>::new(p.FLASH)?;
@@ -61,6 +62,9 @@ async fn inner_main(spawner: Spawner) -> Result<Infallible> {
         PressedTo::Ground,
         "www.picoclock.net",
         [timezone_field],
+        WifiPowerMode::PowerSave,
+        4,                       // Connect attempts
+        Duration::from_secs(40), // Timeout for each attempt
         spawner,
     )?;
 
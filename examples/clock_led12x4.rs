@@ -27,7 +27,7 @@ use device_envoy::led2d::Frame2d;
 use device_envoy::led2d::Led2dFont;
 use device_envoy::led2d::layout::LedLayout;
 use device_envoy::wifi_auto::fields::{TimezoneField, TimezoneFieldStatic};
-use device_envoy::wifi_auto::{WifiAuto, WifiAutoEvent};
+use device_envoy::wifi_auto::{WifiAuto, WifiAutoEvent, WifiPowerMode};
 use device_envoy::{Error, Result};
 use embassy_executor::Spawner;
 use embassy_futures::select::{Either, select};
@@ -98,6 +98,9 @@ async fn inner_main(spawner: Spawner) -> Result<Infallible> {
         PressedTo::Ground,
         "www.picoclock.net", // Captive-portal SSID
         [timezone_field],    // Custom fields to ask for
+        WifiPowerMode::PowerSave,
+        4,                       // Connect attempts
+        Duration::from_secs(40), // Timeout for each attempt
         spawner,
     )?;
 
@@ -111,18 +114,22 @@ async fn inner_main(spawner: Spawner) -> Result<Infallible> {
             let led12x4_ref = led12x4_ref;
             async move {
                 match event {
-                    WifiAutoEvent::CaptivePortalReady => {
+                    WifiAutoEvent::CaptivePortalReady { .. } => {
                         info!("WiFi: captive portal ready, displaying JOIN");
                         show_portal_ready(led12x4_ref).await?;
                     }
                     WifiAutoEvent::Connecting {
                         try_index,
                         try_count,
+                        ..
                     } => {
                         info!("WiFi: connecting (attempt {}/{})", try_index + 1, try_count);
                         show_connecting(led12x4_ref, try_index, try_count).await?;
                     }
-                    WifiAutoEvent::ConnectionFailed => {
+                    WifiAutoEvent::Connected { .. } => {
+                        info!("WiFi: connected");
+                    }
+                    WifiAutoEvent::ConnectionFailed { .. } => {
                         info!("WiFi: connection failed, displaying FAIL, device will reset");
                         show_connection_failed(led12x4_ref).await?;
                     }
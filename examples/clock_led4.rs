@@ -21,11 +21,12 @@ use device_envoy::clock_sync::{
 use device_envoy::flash_array::FlashArray;
 use device_envoy::led4::{BlinkState, Led4, Led4Static, OutputArray, circular_outline_animation};
 use device_envoy::wifi_auto::fields::{TimezoneField, TimezoneFieldStatic};
-use device_envoy::wifi_auto::{WifiAuto, WifiAutoEvent};
+use device_envoy::wifi_auto::{WifiAuto, WifiAutoEvent, WifiPowerMode};
 use device_envoy::{Error, Result};
 use embassy_executor::Spawner;
 use embassy_futures::select::{Either, select};
 use embassy_rp::gpio::{self, Level};
+use embassy_time::Duration;
 use panic_probe as _;
 
 const FAST_MODE_SPEED: f32 = 720.0;
@@ -66,6 +67,9 @@ async fn inner_main(spawner: Spawner) -> Result<Infallible> {
         PressedTo::Ground,
         "www.picoclock.net", // Captive-portal SSID
         [timezone_field],    // Custom fields to ask for
+        WifiPowerMode::PowerSave,
+        4,                       // Connect attempts
+        Duration::from_secs(40), // Timeout for each attempt
         spawner,
     )?;
 
@@ -96,13 +100,14 @@ async fn inner_main(spawner: Spawner) -> Result<Infallible> {
     let (stack, button) = wifi_auto
         .connect(|event| async move {
             match event {
-                WifiAutoEvent::CaptivePortalReady => {
+                WifiAutoEvent::CaptivePortalReady { .. } => {
                     led4_ref.write_text(['j', 'o', 'i', 'n'], BlinkState::BlinkingAndOn);
                 }
                 WifiAutoEvent::Connecting { .. } => {
                     led4_ref.animate_text(circular_outline_animation(true));
                 }
-                WifiAutoEvent::ConnectionFailed => {
+                WifiAutoEvent::Connected { .. } => {}
+                WifiAutoEvent::ConnectionFailed { .. } => {
                     led4_ref.write_text(['F', 'A', 'I', 'L'], BlinkState::BlinkingButOff);
                 }
             }
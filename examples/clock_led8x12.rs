@@ -30,7 +30,7 @@ use device_envoy::{
     led2d::Led2dFont,
     led2d::layout::LedLayout,
     wifi_auto::{
-        WifiAuto, WifiAutoEvent,
+        WifiAuto, WifiAutoEvent, WifiPowerMode,
         fields::{TimezoneField, TimezoneFieldStatic},
     },
 };
@@ -105,6 +105,9 @@ async fn inner_main(spawner: Spawner) -> Result<Infallible> {
         PressedTo::Ground,
         "www.picoclock.net", // Captive-portal SSID
         [timezone_field],    // Custom fields to ask for
+        WifiPowerMode::PowerSave,
+        4,                       // Connect attempts
+        Duration::from_secs(40), // Timeout for each attempt
         spawner,
     )?;
 
@@ -118,18 +121,22 @@ async fn inner_main(spawner: Spawner) -> Result<Infallible> {
             let led8x12_ref = led8x12_ref;
             async move {
                 match event {
-                    WifiAutoEvent::CaptivePortalReady => {
+                    WifiAutoEvent::CaptivePortalReady { .. } => {
                         info!("WiFi: captive portal ready, displaying JOIN");
                         show_portal_ready(led8x12_ref).await?;
                     }
                     WifiAutoEvent::Connecting {
                         try_index,
                         try_count,
+                        ..
                     } => {
                         info!("WiFi: connecting (attempt {}/{})", try_index + 1, try_count);
                         show_connecting(led8x12_ref, try_index, try_count).await?;
                     }
-                    WifiAutoEvent::ConnectionFailed => {
+                    WifiAutoEvent::Connected { .. } => {
+                        info!("WiFi: connected");
+                    }
+                    WifiAutoEvent::ConnectionFailed { .. } => {
                         info!("WiFi: connection failed, displaying FAIL, device will reset");
                         show_connection_failed(led8x12_ref).await?;
                     }
@@ -206,7 +213,12 @@ impl State {
         show_hours_minutes(led8x12, hours, minutes).await?;
         clock_sync.set_tick_interval(Some(ONE_MINUTE)).await;
         loop {
-            match select(button_watch13.wait_for_press_duration(), clock_sync.wait_for_tick()).await {
+            match select(
+                button_watch13.wait_for_press_duration(),
+                clock_sync.wait_for_tick(),
+            )
+            .await
+            {
                 // Button pushes
                 Either::First(press_duration) => {
                     info!(
@@ -249,7 +261,12 @@ impl State {
         show_minutes_seconds(led8x12, minutes, seconds).await?;
         clock_sync.set_tick_interval(Some(ONE_SECOND)).await;
         loop {
-            match select(button_watch13.wait_for_press_duration(), clock_sync.wait_for_tick()).await {
+            match select(
+                button_watch13.wait_for_press_duration(),
+                clock_sync.wait_for_tick(),
+            )
+            .await
+            {
                 // Button pushes
                 Either::First(press_duration) => {
                     info!(
@@ -21,7 +21,7 @@ use device_envoy::clock_sync::{
 use device_envoy::flash_array::FlashArray;
 use device_envoy::servo_player::{AtEnd, combine, linear, servo_player};
 use device_envoy::wifi_auto::fields::{TimezoneField, TimezoneFieldStatic};
-use device_envoy::wifi_auto::{WifiAuto, WifiAutoEvent};
+use device_envoy::wifi_auto::{WifiAuto, WifiAutoEvent, WifiPowerMode};
 use device_envoy::{Error, Result};
 use embassy_executor::Spawner;
 use embassy_futures::select::{Either, select};
@@ -81,6 +81,9 @@ async fn inner_main(spawner: Spawner) -> Result<Infallible> {
         PressedTo::Ground,
         "PicoServoClock", // Captive-portal SSID
         [timezone_field],
+        WifiPowerMode::PowerSave,
+        4,                       // Connect attempts
+        Duration::from_secs(40), // Timeout for each attempt
         spawner,
     )?;
 
@@ -96,11 +99,12 @@ async fn inner_main(spawner: Spawner) -> Result<Infallible> {
             let servo_display_ref = servo_display_ref;
             async move {
                 match event {
-                    WifiAutoEvent::CaptivePortalReady => {
+                    WifiAutoEvent::CaptivePortalReady { .. } => {
                         servo_display_ref.show_portal_ready().await;
                     }
                     WifiAutoEvent::Connecting { .. } => servo_display_ref.show_connecting().await,
-                    WifiAutoEvent::ConnectionFailed => {
+                    WifiAutoEvent::Connected { .. } => {}
+                    WifiAutoEvent::ConnectionFailed { .. } => {
                         // No-op; portal remains visible on failure.
                     }
                 }
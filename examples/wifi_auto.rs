@@ -17,7 +17,7 @@ use device_envoy::led4::{BlinkState, Led4, Led4Static, OutputArray, circular_out
 use device_envoy::wifi_auto::fields::{
     TextField, TextFieldStatic, TimezoneField, TimezoneFieldStatic,
 };
-use device_envoy::wifi_auto::{WifiAuto, WifiAutoEvent};
+use device_envoy::wifi_auto::{WifiAuto, WifiAutoEvent, WifiPowerMode};
 use device_envoy::{Error, Result};
 use embassy_executor::Spawner;
 use embassy_net::{Stack, dns::DnsQueryType, udp};
@@ -98,6 +98,9 @@ async fn inner_main(spawner: Spawner) -> Result<Infallible> {
         PressedTo::Ground,            // Button wiring
         "Pico",                       // Captive portal SSID to display
         [timezone_field, device_name_field, location_field],
+        WifiPowerMode::PowerSave,
+        4,                       // Connect attempts
+        Duration::from_secs(40), // Timeout for each attempt
         spawner,
     )?;
 
@@ -105,7 +108,7 @@ async fn inner_main(spawner: Spawner) -> Result<Infallible> {
     let (stack, mut button) = wifi_auto
         .connect(|event| async move {
             match event {
-                WifiAutoEvent::CaptivePortalReady => {
+                WifiAutoEvent::CaptivePortalReady { .. } => {
                     led4_ref.write_text(['C', 'O', 'N', 'N'], BlinkState::BlinkingAndOn);
                 }
 
@@ -113,7 +116,9 @@ async fn inner_main(spawner: Spawner) -> Result<Infallible> {
                     led4_ref.animate_text(circular_outline_animation((try_index & 1) == 0));
                 }
 
-                WifiAutoEvent::ConnectionFailed => {
+                WifiAutoEvent::Connected { .. } => {}
+
+                WifiAutoEvent::ConnectionFailed { .. } => {
                     led4_ref.write_text(['F', 'A', 'I', 'L'], BlinkState::BlinkingButOff);
                 }
             }
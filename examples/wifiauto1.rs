@@ -14,7 +14,7 @@ use device_envoy::{
     Result,
     button::PressedTo,
     flash_array::FlashArray,
-    wifi_auto::{WifiAuto, WifiAutoEvent},
+    wifi_auto::{WifiAuto, WifiAutoEvent, WifiPowerMode},
 };
 use embassy_net::dns::DnsQueryType;
 use embassy_time::{Duration, Timer};
@@ -42,19 +42,25 @@ async fn inner_main(spawner: embassy_executor::Spawner) -> Result<Infallible> {
         PressedTo::Ground,
         "PicoAccess", // Captive-portal SSID
         [],           // Any extra fields
+        WifiPowerMode::PowerSave,
+        4,                       // Connect attempts
+        Duration::from_secs(40), // Timeout for each attempt
         spawner,
     )?;
 
     let (stack, _button) = wifi_auto
         .connect(|event| async move {
             match event {
-                WifiAutoEvent::CaptivePortalReady => {
+                WifiAutoEvent::CaptivePortalReady { .. } => {
                     defmt::info!("Captive portal ready");
                 }
                 WifiAutoEvent::Connecting { .. } => {
                     defmt::info!("Connecting to WiFi");
                 }
-                WifiAutoEvent::ConnectionFailed => {
+                WifiAutoEvent::Connected { .. } => {
+                    defmt::info!("WiFi connected");
+                }
+                WifiAutoEvent::ConnectionFailed { .. } => {
                     defmt::info!("WiFi connection failed");
                 }
             }
@@ -15,8 +15,9 @@ use device_envoy::{
     Result,
     button::PressedTo,
     flash_array::FlashArray,
-    wifi_auto::WifiAuto,
+    wifi_auto::{WifiAuto, WifiPowerMode},
 };
+use embassy_time::Duration;
 
 #[embassy_executor::main]
 async fn main(spawner: embassy_executor::Spawner) -> ! {
@@ -41,6 +42,9 @@ async fn inner_main(spawner: embassy_executor::Spawner) -> Result<Infallible> {
         PressedTo::Ground,
         "PicoAccess", // Captive-portal SSID
         [],           // Any extra fields
+        WifiPowerMode::PowerSave,
+        4,                       // Connect attempts
+        Duration::from_secs(40), // Timeout for each attempt
         spawner,
     )?;
 
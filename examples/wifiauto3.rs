@@ -14,8 +14,9 @@ use device_envoy::{
     button::PressedTo,
     flash_array::FlashArray,
     wifi_auto::fields::{TextField, TextFieldStatic, TimezoneField, TimezoneFieldStatic},
-    wifi_auto::{WifiAuto, WifiAutoEvent},
+    wifi_auto::{WifiAuto, WifiAutoEvent, WifiPowerMode},
 };
+use embassy_time::Duration;
 
 #[embassy_executor::main]
 async fn main(spawner: embassy_executor::Spawner) -> ! {
@@ -53,18 +54,22 @@ async fn inner_main(spawner: embassy_executor::Spawner) -> Result<core::convert:
         PressedTo::Ground,
         "PicoAccess",                    // Captive-portal SSID
         [website_field, timezone_field], // Custom fields
+        WifiPowerMode::PowerSave,
+        4,                       // Connect attempts
+        Duration::from_secs(40), // Timeout for each attempt
         spawner,
     )?;
 
     let (stack, _button) = wifi_auto
         .connect(|event| async move {
             match event {
-                WifiAutoEvent::CaptivePortalReady => {
+                WifiAutoEvent::CaptivePortalReady { .. } => {
                     defmt::info!("Captive portal ready");
                 }
                 WifiAutoEvent::Connecting {
                     try_index,
                     try_count,
+                    ..
                 } => {
                     defmt::info!(
                         "Connecting to WiFi (attempt {} of {})...",
@@ -72,7 +77,10 @@ async fn inner_main(spawner: embassy_executor::Spawner) -> Result<core::convert:
                         try_count
                     );
                 }
-                WifiAutoEvent::ConnectionFailed => {
+                WifiAutoEvent::Connected { .. } => {
+                    defmt::info!("WiFi connected");
+                }
+                WifiAutoEvent::ConnectionFailed { .. } => {
                     defmt::info!("WiFi connection failed");
                 }
             }
@@ -97,6 +105,6 @@ async fn inner_main(spawner: embassy_executor::Spawner) -> Result<core::convert:
             defmt::info!("{}: lookup failed", query_name);
         }
 
-        embassy_time::Timer::after(embassy_time::Duration::from_secs(15)).await;
+        embassy_time::Timer::after(Duration::from_secs(15)).await;
     }
 }
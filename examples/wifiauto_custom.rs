@@ -16,7 +16,7 @@ use device_envoy::{
     led2d,
     led2d::{Led2dFont, layout::LedLayout},
     wifi_auto::fields::{TextField, TextFieldStatic},
-    wifi_auto::{WifiAuto, WifiAutoEvent},
+    wifi_auto::{WifiAuto, WifiAutoEvent, WifiPowerMode},
 };
 use embassy_executor::Spawner;
 use embassy_net::{
@@ -86,6 +86,9 @@ async fn inner_main(spawner: Spawner) -> Result<Infallible> {
         PressedTo::Ground,
         "PicoTime", // Captive-portal SSID
         [device_name_field],
+        WifiPowerMode::PowerSave,
+        4,                       // Connect attempts
+        Duration::from_secs(40), // Timeout for each attempt
         spawner,
     )?;
 
@@ -97,13 +100,14 @@ async fn inner_main(spawner: Spawner) -> Result<Infallible> {
     let (stack, _button) = wifi_auto
         .connect(|event| async move {
             match event {
-                WifiAutoEvent::CaptivePortalReady => {
+                WifiAutoEvent::CaptivePortalReady { .. } => {
                     led12x8_ref.write_text("JOIN", COLORS).await?;
                 }
                 WifiAutoEvent::Connecting { .. } => {
                     led12x8_ref.write_text("...", COLORS).await?;
                 }
-                WifiAutoEvent::ConnectionFailed => {
+                WifiAutoEvent::Connected { .. } => {}
+                WifiAutoEvent::ConnectionFailed { .. } => {
                     led12x8_ref.write_text("FAIL", COLORS).await?;
                 }
             }
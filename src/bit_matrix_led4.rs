@@ -47,6 +47,8 @@ impl Leds {
     const SEG_E: u8 = 0b_0001_0000;
     /// Segment F of the 7-segment display.
     const SEG_F: u8 = 0b_0010_0000;
+    /// Decimal point of the 7-segment display.
+    const DECIMAL_POINT: u8 = 0b_1000_0000;
 
     /// ASCII table mapping characters to their 7-segment display representations.
     const ASCII_TABLE: [u8; 128] = [
@@ -197,7 +199,7 @@ impl Leds {
 ///
 /// Represents the raw bit patterns for LED segments.
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) struct BitMatrixLed4([u8; CELL_COUNT]);
 
 impl BitMatrixLed4 {
@@ -218,6 +220,71 @@ impl BitMatrixLed4 {
         Self::new(bytes)
     }
 
+    /// Formats `value` right-aligned, with up to `max_decimals` digits after the lit
+    /// decimal point.
+    ///
+    /// Uses the most decimals that fit. A negative value uses one digit for its minus
+    /// sign. Values that don't fit (and non-finite values) show `----`.
+    pub(crate) fn from_float(value: f32, max_decimals: u8) -> Self {
+        const OVERFLOW: [char; CELL_COUNT] = ['-'; CELL_COUNT];
+        if !value.is_finite() {
+            return Self::from_text(&OVERFLOW);
+        }
+        let is_negative = value < 0.0;
+        let magnitude = if is_negative { -value } else { value };
+        let digit_count = CELL_COUNT - usize::from(is_negative);
+        // At least one digit stays before the decimal point.
+        let max_decimals = usize::from(max_decimals).min(digit_count - 1);
+        let limit = (0..digit_count).fold(1.0_f32, |limit, _| limit * 10.0);
+
+        for decimals in (0..=max_decimals).rev() {
+            let scale = (0..decimals).fold(1.0_f32, |scale, _| scale * 10.0);
+            // Round half up. Compare before converting so the conversion cannot overflow.
+            let scaled = magnitude * scale + 0.5;
+            if scaled >= limit {
+                continue;
+            }
+            #[expect(
+                clippy::cast_possible_truncation,
+                clippy::cast_sign_loss,
+                reason = "0 <= scaled < 10_000, and truncation is the rounding step"
+            )]
+            let mut remaining = scaled as u32;
+            let shows_minus = is_negative && remaining != 0;
+
+            let mut text = [' '; CELL_COUNT];
+            let mut cell_index = CELL_COUNT;
+            let mut digits_written = 0;
+            while digits_written <= decimals || remaining != 0 {
+                cell_index -= 1;
+                text[cell_index] = char::from_digit(remaining % 10, 10).expect("digit is below 10");
+                remaining /= 10;
+                digits_written += 1;
+            }
+            if shows_minus {
+                text[cell_index - 1] = '-';
+            }
+
+            let mut bit_matrix = Self::from_text(&text);
+            if decimals > 0 {
+                bit_matrix[CELL_COUNT - 1 - decimals] |= Leds::DECIMAL_POINT;
+            }
+            return bit_matrix;
+        }
+        Self::from_text(&OVERFLOW)
+    }
+
+    /// Lights or clears the decimal point after the second digit, which clock-style
+    /// displays wire to the colon.
+    pub(crate) const fn with_colon(mut self, is_on: bool) -> Self {
+        if is_on {
+            self.0[1] |= Leds::DECIMAL_POINT;
+        } else {
+            self.0[1] &= !Leds::DECIMAL_POINT;
+        }
+        self
+    }
+
     /// Converts to optimized index mapping for multiplexing.
     pub(crate) fn bits_to_indexes(&self, bits_to_index: &mut BitsToIndexes) -> Result<()> {
         bits_to_index.clear();
@@ -354,4 +421,45 @@ mod tests {
         assert_eq!(matrix[2], 0b_0000_1000); // '_'
         assert_eq!(matrix[3], 0b_1000_0000); // '.'
     }
+
+    #[test]
+    fn test_from_float() {
+        // Uses as many decimals as fit, lighting the decimal point after the ones digit
+        let mut expected = BitMatrixLed4::from_text(&[' ', '2', '3', '4']);
+        expected[2] |= Leds::DECIMAL_POINT;
+        assert_eq!(BitMatrixLed4::from_float(23.4, 1), expected);
+
+        let mut expected = BitMatrixLed4::from_text(&['1', '2', '3', '0']);
+        expected[0] |= Leds::DECIMAL_POINT;
+        assert_eq!(BitMatrixLed4::from_float(1.23, 3), expected);
+
+        // Negative values give up one digit to the minus sign
+        let mut expected = BitMatrixLed4::from_text(&['-', '1', '2', '3']);
+        expected[2] |= Leds::DECIMAL_POINT;
+        assert_eq!(BitMatrixLed4::from_float(-12.345, 2), expected);
+
+        // Rounding drops decimals when needed; values that don't fit overflow
+        assert_eq!(
+            BitMatrixLed4::from_float(999.96, 2),
+            BitMatrixLed4::from_text(&['1', '0', '0', '0'])
+        );
+        assert_eq!(
+            BitMatrixLed4::from_float(10_000.0, 2),
+            BitMatrixLed4::from_text(&['-', '-', '-', '-'])
+        );
+        assert_eq!(
+            BitMatrixLed4::from_float(-1_000.0, 0),
+            BitMatrixLed4::from_text(&['-', '-', '-', '-'])
+        );
+    }
+
+    #[test]
+    fn test_with_colon() {
+        // The colon is the second digit's decimal point and leaves the digits alone
+        let matrix = BitMatrixLed4::from_text(&['1', '2', '3', '4']);
+        let mut expected = matrix;
+        expected[1] |= Leds::DECIMAL_POINT;
+        assert_eq!(matrix.with_colon(true), expected);
+        assert_eq!(matrix.with_colon(true).with_colon(false), matrix);
+    }
 }
@@ -6,32 +6,49 @@
 //!   button monitoring.
 //! - [`button_watch!`](crate::button_watch!) — Monitors a button in a background task
 //!   so that it works even in a fast loop/select.
-//! 
+//!
+//! For battery projects, `Button::dormant_until_pressed` stops the chip's clocks until
+//! the next press.
+//!
+//! Under `feature = "host"`, `Button` is a stub with no GPIO. Presses come from
+//! `Button::trigger_virtual` or a `ButtonSimulator`, so button-driven state machines can be
+//! tested without hardware.
+//!
 
+#[cfg(not(feature = "host"))]
 mod button_watch;
+#[cfg(not(feature = "host"))]
 pub mod button_watch_generated;
 
 // Must be public for macro expansion in downstream crates, but not user-facing API.
+#[cfg(not(feature = "host"))]
 #[doc(hidden)]
 pub use button_watch::{ButtonWatch, ButtonWatchStatic};
 
 // Must be public for macro expansion in downstream crates, but not user-facing API.
+#[cfg(not(feature = "host"))]
 #[doc(hidden)]
 pub use button_watch::{button_watch_task, button_watch_task_from_input};
 
+#[cfg(not(feature = "host"))]
 use embassy_futures::select::{Either, select};
+#[cfg(not(feature = "host"))]
 use embassy_rp::Peri;
-use embassy_rp::gpio::{Input, Pull};
-use embassy_time::{Duration, Timer};
+#[cfg(not(feature = "host"))]
+use embassy_rp::gpio::{DormantWakeConfig, Input, Pull};
+#[cfg(not(feature = "host"))]
+use embassy_time::{Duration, Timer, block_for};
 
 // ============================================================================
 // Constants
 // ============================================================================
 
 /// Debounce delay for the button.
+#[cfg(not(feature = "host"))]
 pub(crate) const BUTTON_DEBOUNCE_DELAY: Duration = Duration::from_millis(10);
 
 /// Duration representing a long button press.
+#[cfg(not(feature = "host"))]
 pub(crate) const LONG_PRESS_DURATION: Duration = Duration::from_millis(500);
 
 // ============================================================================
@@ -45,7 +62,7 @@ pub enum PressedTo {
     /// Uses internal pull-down resistor. Pin reads HIGH when pressed.
     ///
     /// Note: The original Pico 2 (RP2350) has a known silicon bug with pull-down resistors
-    /// that can cause pins to stay HIGH after button release. Use ToGround instead.
+    /// that can cause pins to stay HIGH after button release. Use [`Ground`](Self::Ground) instead.
     Voltage,
 
     /// Button connects pin to ground (GND) when pressed.
@@ -121,11 +138,13 @@ pub enum PressDuration {
 ///     }
 /// }
 /// ```
+#[cfg(not(feature = "host"))]
 pub struct Button<'a> {
     input: Input<'a>,
     pressed_to: PressedTo,
 }
 
+#[cfg(not(feature = "host"))]
 impl<'a> Button<'a> {
     /// Creates a new `Button` instance from a pin.
     ///
@@ -145,6 +164,11 @@ impl<'a> Button<'a> {
     }
 
     /// Returns whether the button is currently pressed.
+    ///
+    /// This reads the raw GPIO level once and applies **no** debounce, so it can
+    /// report a bounce while the contacts settle. Use
+    /// [`is_pressed_debounced()`](Self::is_pressed_debounced) for a one-off check
+    /// that must be reliable, or the `wait_for_*` methods for press events.
     #[must_use]
     pub fn is_pressed(&self) -> bool {
         match self.pressed_to {
@@ -153,6 +177,22 @@ impl<'a> Button<'a> {
         }
     }
 
+    /// Returns whether the button is pressed, with debounce.
+    ///
+    /// Reads the GPIO, busy-waits for the debounce delay (10ms), then reads again.
+    /// Returns `true` only if both reads report a press.
+    ///
+    /// This blocks the executor for the debounce delay, so it is meant for one-off
+    /// checks such as "is the button held at startup?", not for polling in a loop.
+    #[must_use]
+    pub fn is_pressed_debounced(&self) -> bool {
+        if !self.is_pressed() {
+            return false;
+        }
+        block_for(BUTTON_DEBOUNCE_DELAY);
+        self.is_pressed()
+    }
+
     #[inline]
     async fn wait_for_button_up(&mut self) -> &mut Self {
         loop {
@@ -231,6 +271,35 @@ impl<'a> Button<'a> {
         self.wait_for_stable_up().await;
     }
 
+    /// Puts the whole chip into the low-power `DORMANT` state until the button is pressed.
+    ///
+    /// The button's pin becomes the dormant-wake source, then every internal clock is
+    /// stopped, which cuts idle current to a small fraction of normal operation. Returns
+    /// immediately if the button is already pressed. Waking is level-triggered, so a press
+    /// that lands just before the chip goes dormant still wakes it.
+    ///
+    /// This is a blocking call: while dormant, no task runs, the `embassy_time` clock does
+    /// not advance, and peripherals that need a clock (WiFi, PIO animations, PWM) stop.
+    /// Quiet those devices first. Clocks are restored before this returns. The wake is not
+    /// debounced; follow with [`wait_for_release`](Self::wait_for_release) if the waking
+    /// press should not also count as input.
+    ///
+    /// See [`Button`] for usage example
+    pub fn dormant_until_pressed(&mut self) {
+        if self.is_pressed() {
+            return;
+        }
+        let wake_config = DormantWakeConfig {
+            edge_high: false,
+            edge_low: false,
+            level_high: self.pressed_to == PressedTo::Voltage,
+            level_low: self.pressed_to == PressedTo::Ground,
+        };
+        // Dropping the guard after waking removes the pin as a wake source.
+        let _dormant_wake = self.input.dormant_wake(wake_config);
+        embassy_rp::clocks::dormant_sleep();
+    }
+
     /// Consumes the button and returns its internal components.
     ///
     /// This is useful for converting a `Button` (returned from `WifiAuto::connect`)
@@ -245,5 +314,235 @@ impl<'a> Button<'a> {
     }
 }
 
+#[cfg(not(feature = "host"))]
 #[doc(inline)]
 pub use crate::button_watch;
+
+// ============================================================================
+// Host stub - Button driven by simulated presses
+// ============================================================================
+
+/// Stub button type for host testing.
+///
+/// This type reads no GPIO. Instead, it keeps a queue of simulated press and release
+/// edges, filled by [`trigger_virtual`](Self::trigger_virtual) or a [`ButtonSimulator`].
+/// The `wait_for_*` methods consume that queue, so a queued press makes
+/// [`wait_for_press`](Self::wait_for_press) return immediately.
+///
+/// There is no time on the host, so [`wait_for_press_duration`](Self::wait_for_press_duration)
+/// reports a press that is still held once the queue runs out as
+/// [`PressDuration::Long`], and a press whose release is already queued as
+/// [`PressDuration::Short`].
+///
+/// See [`ButtonSimulator`] for usage.
+#[cfg(feature = "host")]
+pub struct Button<'a> {
+    simulated: std::sync::Arc<std::sync::Mutex<VirtualButton>>,
+    // The level as seen by the `wait_for_*` methods, which may lag the queued edges.
+    is_down_seen: bool,
+    _lifetime: core::marker::PhantomData<&'a ()>,
+}
+
+// Shared between a stub `Button` and the `ButtonSimulator` that presses it.
+#[cfg(feature = "host")]
+struct VirtualButton {
+    is_down: bool,
+    edges: std::collections::VecDeque<VirtualEdge>,
+    waker: Option<core::task::Waker>,
+}
+
+#[cfg(feature = "host")]
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum VirtualEdge {
+    Down,
+    Up,
+}
+
+#[cfg(feature = "host")]
+impl Button<'_> {
+    /// Creates a released stub button with no queued presses.
+    #[must_use]
+    pub fn new_virtual() -> Self {
+        Self {
+            simulated: std::sync::Arc::new(std::sync::Mutex::new(VirtualButton {
+                is_down: false,
+                edges: std::collections::VecDeque::new(),
+                waker: None,
+            })),
+            is_down_seen: false,
+            _lifetime: core::marker::PhantomData,
+        }
+    }
+
+    /// Queues a complete short press: the button goes down and then back up.
+    ///
+    /// The next [`wait_for_press`](Self::wait_for_press) returns immediately, and
+    /// [`wait_for_press_duration`](Self::wait_for_press_duration) reports
+    /// [`PressDuration::Short`].
+    pub fn trigger_virtual(&self) {
+        self.push_edge(VirtualEdge::Down);
+        self.push_edge(VirtualEdge::Up);
+    }
+
+    /// Returns whether the simulated button is currently held down.
+    ///
+    /// Like the GPIO read on hardware, this reflects the latest simulated edge, even if the
+    /// `wait_for_*` methods have not consumed it yet.
+    #[must_use]
+    pub fn is_pressed(&self) -> bool {
+        self.lock().is_down
+    }
+
+    /// Returns whether the simulated button is currently held down.
+    ///
+    /// Simulated edges do not bounce, so this is the same as [`is_pressed`](Self::is_pressed).
+    #[must_use]
+    pub fn is_pressed_debounced(&self) -> bool {
+        self.is_pressed()
+    }
+
+    /// Waits for the next queued press. Does not wait for release.
+    ///
+    /// See [`ButtonSimulator`] for usage.
+    pub async fn wait_for_press(&mut self) {
+        while self.next_edge().await != VirtualEdge::Down {}
+    }
+
+    /// Waits for the next queued press and returns whether it was short or long.
+    ///
+    /// The press is short if its release is already queued, and long otherwise.
+    ///
+    /// See [`ButtonSimulator`] for usage.
+    pub async fn wait_for_press_duration(&mut self) -> PressDuration {
+        self.wait_for_press().await;
+        match self.take_edge() {
+            Some(VirtualEdge::Up) => PressDuration::Short,
+            Some(VirtualEdge::Down) | None => PressDuration::Long,
+        }
+    }
+
+    /// Waits until the queued edges release the button.
+    pub async fn wait_for_release(&mut self) {
+        while self.is_down_seen {
+            self.next_edge().await;
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, VirtualButton> {
+        self.simulated
+            .lock()
+            .expect("virtual button lock is not poisoned")
+    }
+
+    // Queues `edge` unless the button is already at that level.
+    fn push_edge(&self, edge: VirtualEdge) {
+        let mut simulated = self.lock();
+        let is_down = edge == VirtualEdge::Down;
+        if simulated.is_down == is_down {
+            return;
+        }
+        simulated.is_down = is_down;
+        simulated.edges.push_back(edge);
+        if let Some(waker) = simulated.waker.take() {
+            waker.wake();
+        }
+    }
+
+    fn take_edge(&mut self) -> Option<VirtualEdge> {
+        let edge = self.lock().edges.pop_front()?;
+        self.is_down_seen = edge == VirtualEdge::Down;
+        Some(edge)
+    }
+
+    async fn next_edge(&mut self) -> VirtualEdge {
+        core::future::poll_fn(|context| {
+            if let Some(edge) = self.take_edge() {
+                return core::task::Poll::Ready(edge);
+            }
+            self.lock().waker = Some(context.waker().clone());
+            core::task::Poll::Pending
+        })
+        .await
+    }
+}
+
+/// Host-only set of `N` stub [`Button`]s that tests press and release by index.
+///
+/// Each `press` or `release` queues an edge on that button, which its `wait_for_*`
+/// methods then consume. Lend a button to the code under test with
+/// [`button`](Self::button).
+///
+/// # Example
+///
+/// ```rust
+/// use device_envoy::button::{Button, ButtonSimulator, PressDuration};
+/// use embassy_futures::block_on;
+///
+/// // The state machine under test: a short press selects, a long press goes back.
+/// async fn next_action(button: &mut Button<'_>) -> &'static str {
+///     match button.wait_for_press_duration().await {
+///         PressDuration::Short => "select",
+///         PressDuration::Long => "back",
+///     }
+/// }
+///
+/// let mut button_simulator = ButtonSimulator::<2>::new();
+///
+/// // A press with its release already queued is short.
+/// button_simulator.press(0);
+/// button_simulator.release(0);
+/// assert_eq!(block_on(next_action(button_simulator.button(0))), "select");
+///
+/// // A press that is still held is long.
+/// button_simulator.press(1);
+/// assert_eq!(block_on(next_action(button_simulator.button(1))), "back");
+/// ```
+#[cfg(feature = "host")]
+pub struct ButtonSimulator<const N: usize> {
+    buttons: [Button<'static>; N],
+}
+
+#[cfg(feature = "host")]
+impl<const N: usize> ButtonSimulator<N> {
+    /// Creates `N` released stub buttons with no queued presses.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            buttons: core::array::from_fn(|_| Button::new_virtual()),
+        }
+    }
+
+    /// Queues a press on button `index`. Does nothing if it is already held.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not less than `N`.
+    pub fn press(&self, index: usize) {
+        self.buttons[index].push_edge(VirtualEdge::Down);
+    }
+
+    /// Queues a release on button `index`. Does nothing if it is already released.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not less than `N`.
+    pub fn release(&self, index: usize) {
+        self.buttons[index].push_edge(VirtualEdge::Up);
+    }
+
+    /// Lends button `index` to the code under test.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not less than `N`.
+    pub const fn button(&mut self, index: usize) -> &mut Button<'static> {
+        &mut self.buttons[index]
+    }
+}
+
+#[cfg(feature = "host")]
+impl<const N: usize> Default for ButtonSimulator<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,188 @@
+//! A device abstraction for piezo buzzers driven by PWM.
+//!
+//! This module provides a simple interface for playing tones, beeps, and short melodies on a
+//! passive piezo buzzer (or small speaker) connected to a PWM-capable GPIO pin. See
+//! [`Buzzer`] for usage examples.
+
+use core::borrow::Borrow;
+
+use defmt::info;
+use embassy_rp::clocks::clk_sys_freq;
+use embassy_rp::pwm::{Config, Pwm};
+use embassy_time::{Duration, Timer};
+
+use crate::servo::ServoPwmPin;
+
+/// Lowest tone frequency accepted by [`Buzzer`], in Hz.
+pub const BUZZER_MIN_FREQ_HZ: u32 = 20;
+
+/// Highest tone frequency accepted by [`Buzzer`], in Hz.
+pub const BUZZER_MAX_FREQ_HZ: u32 = 20_000;
+
+/// A device abstraction for a passive piezo buzzer.
+///
+/// The PWM frequency sets the pitch and the duty cycle is fixed at 50%. Each method waits
+/// until its sound has finished, so no background task is needed; use
+/// [`select`](embassy_futures::select::select) to play sound alongside other work.
+///
+/// # Hardware Requirements
+///
+/// Connect a *passive* buzzer (one without a built-in oscillator) between a GPIO pin and
+/// GND. The pin must be driven by the matching [PWM slice](crate#glossary):
+/// `PWM slice = (pin / 2) % 8`.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # #![no_std]
+/// # #![no_main]
+/// use device_envoy::buzzer::Buzzer;
+/// use embassy_time::Duration;
+/// # use core::panic::PanicInfo;
+/// # #[panic_handler]
+/// # fn panic(_info: &PanicInfo) -> ! { loop {} }
+/// async fn example(p: embassy_rp::Peripherals) {
+///     // GPIO 15 → (15/2) % 8 = 7 → PWM_SLICE7
+///     let mut buzzer = Buzzer::new(p.PIN_15, p.PWM_SLICE7);
+///
+///     // A single 440 Hz tone for half a second.
+///     buzzer.tone(440, Duration::from_millis(500)).await;
+///
+///     // Three short beeps.
+///     buzzer
+///         .beep(3, Duration::from_millis(100), Duration::from_millis(100))
+///         .await;
+///
+///     // A short melody: `None` is a rest.
+///     const MELODY: [(Option<u32>, Duration); 4] = [
+///         (Some(523), Duration::from_millis(200)), // C5
+///         (Some(659), Duration::from_millis(200)), // E5
+///         (None, Duration::from_millis(100)),
+///         (Some(784), Duration::from_millis(400)), // G5
+///     ];
+///     buzzer.play(MELODY).await;
+/// }
+/// ```
+///
+/// If a future from `tone`, `beep`, or `play` is dropped before it completes (for example,
+/// when it loses a `select`), the current note keeps sounding; call [`Buzzer::stop`].
+pub struct Buzzer<'d> {
+    pwm: Pwm<'d>,
+    cfg: Config, // Store config to avoid recreating default (which resets divider)
+    is_channel_a: bool,
+}
+
+impl<'d> Buzzer<'d> {
+    /// Frequency used by [`beep`](Self::beep), in Hz. Most piezo buzzers are loudest
+    /// around 2–4 kHz.
+    pub const BEEP_FREQ_HZ: u32 = 2_000;
+
+    /// Create a silent buzzer on `pin`, driven by its PWM `slice`.
+    ///
+    /// See the [`Buzzer`] example for usage.
+    #[must_use]
+    pub fn new<P, S>(pin: embassy_rp::Peri<'d, P>, slice: embassy_rp::Peri<'d, S>) -> Self
+    where
+        P: ServoPwmPin<S>,
+        S: embassy_rp::PeripheralType,
+    {
+        let mut buzzer = Self {
+            pwm: P::new_pwm(slice, pin),
+            cfg: Config::default(),
+            is_channel_a: P::IS_CHANNEL_A,
+        };
+        buzzer.stop();
+        buzzer
+    }
+
+    /// Play a tone of `freq_hz` for `duration`, then fall silent.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `freq_hz` is outside
+    /// [`BUZZER_MIN_FREQ_HZ`]`..=`[`BUZZER_MAX_FREQ_HZ`].
+    ///
+    /// See the [`Buzzer`] example for usage.
+    pub async fn tone(&mut self, freq_hz: u32, duration: Duration) {
+        self.start_tone(freq_hz);
+        Timer::after(duration).await;
+        self.stop();
+    }
+
+    /// Play `count` beeps at [`BEEP_FREQ_HZ`](Self::BEEP_FREQ_HZ), each `on_time` long and
+    /// separated by `off_time` of silence.
+    ///
+    /// See the [`Buzzer`] example for usage.
+    pub async fn beep(&mut self, count: u8, on_time: Duration, off_time: Duration) {
+        for beep_index in 0..count {
+            if beep_index > 0 {
+                Timer::after(off_time).await;
+            }
+            self.tone(Self::BEEP_FREQ_HZ, on_time).await;
+        }
+    }
+
+    /// Play a sequence of `(frequency, duration)` steps, where `None` is a rest.
+    ///
+    /// Accepts arrays, slices, or any iterator of `(Option<u32>, Duration)` tuples.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a frequency is outside
+    /// [`BUZZER_MIN_FREQ_HZ`]`..=`[`BUZZER_MAX_FREQ_HZ`].
+    ///
+    /// See the [`Buzzer`] example for usage.
+    pub async fn play<I>(&mut self, steps: I)
+    where
+        I: IntoIterator,
+        I::Item: Borrow<(Option<u32>, Duration)>,
+    {
+        for step in steps {
+            let (freq_hz, duration) = *step.borrow();
+            match freq_hz {
+                Some(freq_hz) => self.tone(freq_hz, duration).await,
+                None => Timer::after(duration).await,
+            }
+        }
+    }
+
+    /// Silence the buzzer immediately.
+    ///
+    /// The pin is held low, so magnetic buzzers do not draw current while idle.
+    pub fn stop(&mut self) {
+        self.set_compare(0);
+        self.cfg.enable = true;
+        self.pwm.set_config(&self.cfg);
+    }
+
+    /// Start a continuous tone at `freq_hz`. Internal shared logic.
+    fn start_tone(&mut self, freq_hz: u32) {
+        assert!(
+            (BUZZER_MIN_FREQ_HZ..=BUZZER_MAX_FREQ_HZ).contains(&freq_hz),
+            "freq_hz must be between BUZZER_MIN_FREQ_HZ and BUZZER_MAX_FREQ_HZ"
+        );
+        let clk = clk_sys_freq(); // Hz
+        // Use the smallest integer divider that keeps the period within the 16-bit counter.
+        let div_int = clk.div_ceil(freq_hz * 0x1_0000).clamp(1, 255);
+        let top = u16::try_from(clk / (div_int * freq_hz) - 1)
+            .expect("the divider keeps the PWM period within 16 bits");
+        self.cfg.divider = u8::try_from(div_int)
+            .expect("divider is clamped to 255")
+            .into();
+        self.cfg.top = top;
+        self.cfg.phase_correct = false;
+        self.set_compare(top / 2 + 1); // 50% duty cycle
+        self.cfg.enable = true;
+        self.pwm.set_config(&self.cfg);
+
+        info!("buzzer tone {}Hz div={} top={}", freq_hz, div_int, top);
+    }
+
+    fn set_compare(&mut self, compare: u16) {
+        if self.is_channel_a {
+            self.cfg.compare_a = compare;
+        } else {
+            self.cfg.compare_b = compare;
+        }
+    }
+}
@@ -21,6 +21,10 @@ pub(crate) enum CharLcdMessage {
         text: String<64>, // 64 chars supports up to 20x4 displays (80 chars)
         duration_ms: u32,
     },
+    /// Draw a horizontal progress bar across one row
+    ProgressBar { row: u8, fraction: u8 },
+    /// Scroll text across one row from right to left, once
+    Ticker { row: u8, text: String<64> },
 }
 
 /// Static type for the `CharLcd` device abstraction.
@@ -54,7 +58,7 @@ impl CharLcdStatic {
 ///     spawner: embassy_executor::Spawner,
 /// ) -> device_envoy::Result<()> {
 ///     static CHAR_LCD_STATIC: CharLcdStatic = CharLcd::new_static();
-///     let lcd = CharLcd::new(&CHAR_LCD_STATIC, p.I2C0, p.PIN_1, p.PIN_0, spawner)?;
+///     let lcd = CharLcd::new(&CHAR_LCD_STATIC, p.I2C0, p.PIN_1, p.PIN_0, 16, spawner)?;
 ///     let mut text: heapless::String<64> = "Hello!".try_into().unwrap();
 ///     lcd.write_text(text, 1_000).await;
 ///     Ok(())
@@ -75,20 +79,29 @@ impl CharLcd {
     ///
     /// Note: Hardcoded to I2C0 peripheral (like WiFi's internal pins).
     /// However, SCL and SDA can be any pins compatible with I2C0.
+    ///
+    /// `columns` is the display width in characters (16 or 20). Progress bars and tickers
+    /// span the full width.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `columns` is not 16 or 20.
     pub fn new<SCL, SDA>(
         char_lcd_static: &'static CharLcdStatic,
         i2c_peripheral: Peri<'static, I2C0>,
         scl: Peri<'static, SCL>,
         sda: Peri<'static, SDA>,
+        columns: u8,
         spawner: Spawner,
     ) -> Result<Self>
     where
         SCL: SclPin<I2C0>,
         SDA: SdaPin<I2C0>,
     {
+        assert!(matches!(columns, 16 | 20), "columns must be 16 or 20");
         // Create the I2C instance and pass it to the task
         let i2c = i2c::I2c::new_blocking(i2c_peripheral, scl, sda, I2cConfig::default());
-        let token = lcd_task(i2c, char_lcd_static);
+        let token = lcd_task(i2c, columns, char_lcd_static);
         spawner.spawn(token).map_err(Error::TaskSpawn)?;
         Ok(Self { char_lcd_static })
     }
@@ -99,12 +112,78 @@ impl CharLcd {
             .send(CharLcdMessage::Display { text, duration_ms })
             .await;
     }
+
+    /// Draw a horizontal progress bar across `row` (async, waits until queued).
+    ///
+    /// `fraction` runs from 0 (empty) to 255 (full). The bar spans every character cell of
+    /// the row, each with 5 pixel columns, so a 16-column display shows 80 visible steps
+    /// and a 20-column display 100. Only `row` is redrawn; other rows keep their text.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row` is 4 or greater.
+    pub async fn progress_bar(&self, row: u8, fraction: u8) {
+        assert!(row < 4, "row must be less than 4");
+        self.char_lcd_static
+            .send(CharLcdMessage::ProgressBar { row, fraction })
+            .await;
+    }
+
+    /// Return a ticker that scrolls messages across `row`, one after another.
+    ///
+    /// See [`CharLcdTicker`] for details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row` is 4 or greater.
+    #[must_use]
+    pub fn ticker(&self, row: u8) -> CharLcdTicker {
+        assert!(row < 4, "row must be less than 4");
+        CharLcdTicker {
+            char_lcd_static: self.char_lcd_static,
+            row,
+        }
+    }
+}
+
+/// A news-ticker feed for one row of a [`CharLcd`], created with [`CharLcd::ticker`].
+///
+/// Each pushed message enters at the right edge, scrolls left one character every
+/// 250 ms, and fully leaves before the next one starts. Messages share the LCD's queue
+/// with [`CharLcd::write_text`] and [`CharLcd::progress_bar`], so everything is shown in
+/// the order it was sent, and other rows keep their text. The queue holds 8 messages;
+/// when it is full, [`push`](Self::push) waits for room.
+///
+/// The ticker is `Copy`, so several tasks can feed the same row.
+#[derive(Clone, Copy)]
+pub struct CharLcdTicker {
+    char_lcd_static: &'static CharLcdStatic,
+    row: u8,
+}
+
+impl CharLcdTicker {
+    /// Queue `text` to scroll across the ticker's row (async, waits until queued).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::TextDoesNotFit`] if `text` is longer than 64 bytes.
+    pub async fn push(&self, text: &str) -> Result<()> {
+        let text = String::try_from(text).map_err(|()| Error::TextDoesNotFit)?;
+        self.char_lcd_static
+            .send(CharLcdMessage::Ticker {
+                row: self.row,
+                text,
+            })
+            .await;
+        Ok(())
+    }
 }
 
 // Internal LCD driver implementation (used by the background task)
 struct LcdDriver {
     i2c: i2c::I2c<'static, I2C0, i2c::Blocking>,
     address: u8,
+    columns: u8,
 }
 
 // PCF8574 pin mapping: P0=RS, P1=RW, P2=E, P3=Backlight, P4-P7=Data
@@ -112,9 +191,20 @@ const LCD_BACKLIGHT: u8 = 0x08;
 const LCD_ENABLE: u8 = 0x04;
 const LCD_RS: u8 = 0x01;
 
+// Progress bars span the display width, using CGRAM slots 0-4, which hold glyphs with
+// 1-5 lit pixel columns.
+const PROGRESS_BAR_GLYPH_COLUMNS: u8 = 5;
+
+// Tickers scroll across the display width, one step per interval.
+const TICKER_STEP_MS: u64 = 250;
+
 impl LcdDriver {
-    fn new(i2c: i2c::I2c<'static, I2C0, i2c::Blocking>) -> Self {
-        Self { i2c, address: 0x27 }
+    fn new(i2c: i2c::I2c<'static, I2C0, i2c::Blocking>, columns: u8) -> Self {
+        Self {
+            i2c,
+            address: 0x27,
+            columns,
+        }
     }
 
     async fn init(&mut self) {
@@ -137,6 +227,40 @@ impl LcdDriver {
         Timer::after_millis(2).await;
         // Entry mode: increment cursor, no shift
         self.write_byte_internal(0x06, false).await;
+
+        self.load_progress_bar_glyphs().await;
+    }
+
+    #[expect(clippy::arithmetic_side_effects, reason = "Bit operations")]
+    async fn load_progress_bar_glyphs(&mut self) {
+        for slot in 0..PROGRESS_BAR_GLYPH_COLUMNS {
+            // Light the leftmost `slot + 1` of the 5 pixel columns on every row.
+            let row_bits = (0x1F << (PROGRESS_BAR_GLYPH_COLUMNS - 1 - slot)) & 0x1F;
+            // Set CGRAM address: 8 rows per glyph
+            self.write_byte_internal(0x40 | (slot << 3), false).await;
+            for _ in 0..8 {
+                self.write_byte_internal(row_bits, true).await;
+            }
+        }
+        // Return to DDRAM so later writes land on the display
+        self.set_cursor(0, 0).await;
+    }
+
+    #[expect(clippy::arithmetic_side_effects, reason = "Values are bounded by 100")]
+    async fn progress_bar(&mut self, row: u8, fraction: u8) {
+        let total_columns = u16::from(self.columns) * u16::from(PROGRESS_BAR_GLYPH_COLUMNS);
+        let mut lit_columns = u8::try_from(u16::from(fraction) * total_columns / 255)
+            .expect("lit columns never exceed the bar width");
+        self.set_cursor(row, 0).await;
+        for _ in 0..self.columns {
+            let cell_columns = lit_columns.min(PROGRESS_BAR_GLYPH_COLUMNS);
+            lit_columns -= cell_columns;
+            let glyph = match cell_columns {
+                0 => b' ',
+                columns => columns - 1, // CGRAM slot holding `columns` lit columns
+            };
+            self.write_byte_internal(glyph, true).await;
+        }
     }
 
     #[expect(clippy::arithmetic_side_effects, reason = "Bit operations")]
@@ -177,6 +301,28 @@ impl LcdDriver {
         self.write_byte_internal(0x80 | address, false).await;
     }
 
+    // Scroll `text` in from the right edge of `row` until it has fully left on the left.
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "Step and column values are bounded by the text length plus the width"
+    )]
+    async fn ticker(&mut self, row: u8, text: &str) {
+        let text = text.as_bytes();
+        let columns = usize::from(self.columns);
+        for step in 1..=text.len() + columns {
+            self.set_cursor(row, 0).await;
+            for column in 0..columns {
+                let byte = (step + column)
+                    .checked_sub(columns)
+                    .and_then(|index| text.get(index))
+                    .copied()
+                    .unwrap_or(b' ');
+                self.write_byte_internal(byte, true).await;
+            }
+            Timer::after_millis(TICKER_STEP_MS).await;
+        }
+    }
+
     async fn print(&mut self, s: &str) {
         for ch in s.bytes() {
             self.write_byte_internal(ch, true).await;
@@ -187,9 +333,10 @@ impl LcdDriver {
 #[embassy_executor::task]
 async fn lcd_task(
     i2c: i2c::I2c<'static, I2C0, i2c::Blocking>,
+    columns: u8,
     commands: &'static CharLcdStatic,
 ) -> ! {
-    let mut lcd = LcdDriver::new(i2c);
+    let mut lcd = LcdDriver::new(i2c, columns);
     lcd.init().await;
 
     loop {
@@ -222,6 +369,12 @@ async fn lcd_task(
                     Timer::after_millis(duration_ms.into()).await;
                 }
             }
+            CharLcdMessage::ProgressBar { row, fraction } => {
+                lcd.progress_bar(row, fraction).await;
+            }
+            CharLcdMessage::Ticker { row, text } => {
+                lcd.ticker(row, &text).await;
+            }
         }
     }
 }
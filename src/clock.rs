@@ -24,6 +24,7 @@ use portable_atomic::{AtomicI64, AtomicU64};
 use time::{Duration as TimeDuration, OffsetDateTime, UtcOffset};
 
 use crate::Result;
+use crate::led4::{BlinkState, Led4};
 use crate::time_sync::UnixSeconds;
 
 // ============================================================================
@@ -183,6 +184,30 @@ impl Clock {
         utc.to_offset(offset)
     }
 
+    /// Get the current local hour (0–23) and minute, as shown on an `HH:MM` display.
+    #[must_use]
+    pub fn hhmm(&self) -> (u8, u8) {
+        let local_time = self.now_local();
+        (local_time.hour(), local_time.minute())
+    }
+
+    /// Show the local time on `led4` as `HH:MM`, updating at the start of every second.
+    ///
+    /// The colon is lit on even seconds and dark on odd ones. This never returns; run it in
+    /// its own task or `select` it with other work.
+    pub async fn drive_led4(&self, led4: &Led4<'_>) -> ! {
+        loop {
+            let local_time = self.now_local();
+            led4.set_colon(local_time.second() % 2 == 0);
+            led4.write_text(
+                hhmm_text(local_time.hour(), local_time.minute()),
+                BlinkState::Solid,
+            );
+            let until_next_second = 1_000_000 - u64::from(local_time.microsecond());
+            Timer::after(Duration::from_micros(until_next_second)).await;
+        }
+    }
+
     /// Set the current UTC time. See [`Clock`] docs for usage.
     pub async fn set_utc_time(&self, unix_seconds: UnixSeconds) {
         let unix_seconds = unix_seconds.as_i64();
@@ -348,6 +373,17 @@ fn scaled_interval_microseconds(interval_ms: u64, speed_scaled_ppm: u64) -> u64
     scaled
 }
 
+/// Formats hours and minutes as the four digits of an `HH:MM` display.
+fn hhmm_text(hours: u8, minutes: u8) -> [char; 4] {
+    let digit = |value: u8| char::from_digit(u32::from(value), 10).expect("value is below 10");
+    [
+        digit(hours / 10),
+        digit(hours % 10),
+        digit(minutes / 10),
+        digit(minutes % 10),
+    ]
+}
+
 fn scale_elapsed_microseconds(elapsed_ticks: u64, speed_scaled_ppm: u64) -> i64 {
     assert!(speed_scaled_ppm > 0, "speed must be positive");
     let scaled =
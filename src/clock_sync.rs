@@ -13,6 +13,7 @@ use portable_atomic::{AtomicBool, AtomicU64, Ordering};
 use time::OffsetDateTime;
 
 use crate::clock::{Clock, ClockStatic};
+use crate::led4::Led4;
 pub use crate::time_sync::UnixSeconds;
 use crate::time_sync::{TimeSync, TimeSyncEvent, TimeSyncStatic};
 
@@ -78,9 +79,10 @@ pub struct ClockSyncStatic {
 ///     clock_sync::{ClockSync, ClockSyncStatic, ONE_SECOND, h12_m_s},
 ///     flash_array::FlashArray,
 ///     wifi_auto::fields::{TimezoneField, TimezoneFieldStatic},
-///     wifi_auto::{WifiAuto, WifiAutoEvent},
+///     wifi_auto::{WifiAuto, WifiAutoEvent, WifiPowerMode},
 /// };
 /// use defmt::info;
+/// use embassy_time::Duration;
 ///
 /// async fn run(
 ///     spawner: embassy_executor::Spawner,
@@ -103,19 +105,25 @@ pub struct ClockSyncStatic {
 ///         PressedTo::Ground,
 ///         "ClockSync",
 ///         [timezone_field],
+///         WifiPowerMode::PowerSave,
+///         4,                       // Connect attempts
+///         Duration::from_secs(40), // Timeout for each attempt
 ///         spawner,
 ///     )?;
 ///
 ///     let (stack, _button) = wifi_auto
 ///         .connect(|event| async move {
 ///             match event {
-///                 WifiAutoEvent::CaptivePortalReady => {
+///                 WifiAutoEvent::CaptivePortalReady { .. } => {
 ///                     info!("WifiAuto: setup mode ready");
 ///                 }
 ///                 WifiAutoEvent::Connecting { .. } => {
 ///                     info!("WifiAuto: connecting");
 ///                 }
-///                 WifiAutoEvent::ConnectionFailed => {
+///                 WifiAutoEvent::Connected { .. } => {
+///                     info!("WifiAuto: connected");
+///                 }
+///                 WifiAutoEvent::ConnectionFailed { .. } => {
 ///                     info!("WifiAuto: connection failed");
 ///                 }
 ///             }
@@ -233,6 +241,22 @@ impl ClockSync {
         self.clock.now_local()
     }
 
+    /// Get the current local hour (0–23) and minute, as shown on an `HH:MM` display.
+    #[must_use]
+    pub fn hhmm(&self) -> (u8, u8) {
+        self.clock.hhmm()
+    }
+
+    /// Wait for the first sync, then show the local time on `led4` as `HH:MM` with a
+    /// colon that blinks with the seconds.
+    ///
+    /// The display updates at the start of every second. This never returns; run it in its
+    /// own task or `select` it with other work.
+    pub async fn drive_led4(&self, led4: &Led4<'_>) -> ! {
+        self.wait_for_first_sync().await;
+        self.clock.drive_led4(led4).await
+    }
+
     /// Update the UTC offset used for local time.
     pub async fn set_offset_minutes(&self, minutes: i32) {
         self.clock.set_offset_minutes(minutes).await;
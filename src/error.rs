@@ -37,6 +37,12 @@ pub enum Error {
     #[display("MFRC522 version read failed: {_0:?}")]
     Mfrc522Version(#[error(not(source))] PCDErrorCode),
 
+    #[display("MFRC522 tag read failed: {_0:?}")]
+    Mfrc522Read(#[error(not(source))] PCDErrorCode),
+
+    #[display("Tag has no supported NDEF record")]
+    NdefUnsupported,
+
     #[display("Format error")]
     FormatError,
 
@@ -46,6 +52,12 @@ pub enum Error {
     #[display("Network Time Protocol (NTP) error: {_0}")]
     Ntp(#[error(not(source))] &'static str),
 
+    #[display("HTTPS request error: {_0}")]
+    Https(#[error(not(source))] &'static str),
+
+    #[display("HTTP status {_0}")]
+    HttpStatus(#[error(not(source))] u16),
+
     #[cfg(not(feature = "host"))]
     #[display("Flash operation failed: {_0:?}")]
     Flash(#[error(not(source))] embassy_rp::flash::Error),
@@ -55,6 +67,21 @@ pub enum Error {
 
     #[display("animation disabled (max_frames = {_0})")]
     AnimationDisabled(#[error(not(source))] usize),
+
+    #[display("Stored animation is for {stored} LEDs, not {expected}")]
+    AnimationLengthMismatch { stored: usize, expected: usize },
+
+    #[display("Text does not fit on the display")]
+    TextDoesNotFit,
+
+    #[display("IR mapping is full")]
+    IrMappingFull,
+
+    #[display("Too many servo players for relax_all")]
+    TooManyServoPlayers,
+
+    #[display("Invalid LED layout CSV: {_0}")]
+    LayoutCsv(#[error(not(source))] &'static str),
 }
 
 impl From<()> for Error {
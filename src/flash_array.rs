@@ -37,6 +37,9 @@ const HEADER_SIZE: usize = 4 + 4 + 2; // Magic + TypeHash + PayloadLen
 const CRC_SIZE: usize = 4;
 const MAX_PAYLOAD_SIZE: usize = ERASE_SIZE - HEADER_SIZE - CRC_SIZE; // 3900 bytes
 const TOTAL_BLOCKS: u32 = (INTERNAL_FLASH_SIZE / ERASE_SIZE) as u32;
+const JOURNAL_MAGIC: u32 = 0x4A52_4E4C; // 'JRNL'
+const JOURNAL_HEADER_SIZE: usize = 4 + 2 + 2; // Magic + EntryCount + BodyLen
+const JOURNAL_ENTRY_HEADER_SIZE: usize = 4 + 4 + 2; // Offset + TypeHash + PayloadLen
 
 /// Shared flash manager that owns the hardware driver and allocation cursor.
 struct FlashManager {
@@ -77,7 +80,35 @@ impl FlashManager {
         }
         Ok(array::from_fn(|idx| FlashBlock {
             manager: self,
-            block: start + idx as u32,
+            offset: block_offset(start + idx as u32),
+        }))
+    }
+
+    fn reserve_in_region<const N: usize>(
+        &'static self,
+        region_offset: u32,
+        region_size: u32,
+    ) -> Result<[FlashBlock; N]> {
+        assert!(
+            region_offset % ERASE_SIZE as u32 == 0,
+            "flash region offset must be a multiple of the erase size (4096)"
+        );
+        assert!(
+            region_size % ERASE_SIZE as u32 == 0,
+            "flash region size must be a multiple of the erase size (4096)"
+        );
+        assert!(
+            region_offset
+                .checked_add(region_size)
+                .is_some_and(|region_end| region_end <= INTERNAL_FLASH_SIZE as u32),
+            "flash region must lie within internal flash"
+        );
+        if N > (region_size as usize) / ERASE_SIZE {
+            return Err(Error::IndexOutOfBounds);
+        }
+        Ok(array::from_fn(|idx| FlashBlock {
+            manager: self,
+            offset: region_offset + (idx * ERASE_SIZE) as u32,
         }))
     }
 }
@@ -87,7 +118,7 @@ impl FlashManager {
 /// See [`FlashArray`] for usage examples.
 pub struct FlashBlock {
     manager: &'static FlashManager,
-    block: u32,
+    offset: u32,
 }
 
 impl FlashBlock {
@@ -98,7 +129,7 @@ impl FlashBlock {
     where
         T: Serialize + for<'de> Deserialize<'de>,
     {
-        load_block(self.manager, self.block)
+        load_block(self.manager, self.offset)
     }
 
     /// Save data to this block.
@@ -108,15 +139,48 @@ impl FlashBlock {
     where
         T: Serialize + for<'de> Deserialize<'de>,
     {
-        save_block(self.manager, self.block, value)
+        save_block(self.manager, self.offset, value)
     }
 
     /// Clear this block.
     pub fn clear(&mut self) -> Result<()> {
-        clear_block(self.manager, self.block)
+        clear_block(self.manager, self.offset)
+    }
+
+    /// Number of serialized payload bytes this block can hold.
+    ///
+    /// This is one flash sector (4096 bytes) minus the block's header and checksum.
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        MAX_PAYLOAD_SIZE
+    }
+
+    /// Number of serialized payload bytes currently stored in this block, or 0 if the
+    /// block is empty.
+    ///
+    /// Reads only the block's header, so it works without knowing the stored type.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Flash`] if the read fails, or [`Error::StorageCorrupted`] if the
+    /// header records a length larger than [`capacity`](Self::capacity).
+    pub fn used(&self) -> Result<usize> {
+        used_block(self.manager, self.offset)
+    }
+
+    /// Returns `true` if the stored payload fills the block's whole
+    /// [`capacity`](Self::capacity).
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`used`](Self::used).
+    pub fn is_full(&self) -> Result<bool> {
+        Ok(self.used()? >= self.capacity())
     }
 }
 
+static FLASH_STATIC: FlashArrayStatic = FlashArrayStatic::new();
+
 /// Static resources for [`FlashArray`].
 pub(crate) struct FlashArrayStatic {
     manager_cell: StaticCell<FlashManager>,
@@ -153,6 +217,8 @@ impl FlashArrayStatic {
 /// You choose the number of storage blocks at compile time. Each block holds up to
 /// 3900 bytes of postcard-serialized data (a hardware-determined 4 KB flash block
 /// minus metadata space).
+/// Check how much of a block is in use with [`FlashBlock::used`],
+/// [`FlashBlock::capacity`], and [`FlashBlock::is_full`].
 ///
 /// # Features
 ///
@@ -169,9 +235,15 @@ impl FlashArrayStatic {
 /// the end of memory backward. Your code can split that array using destructuring
 /// assignment and hand individual blocks to subsystems that need persistent storage.
 ///
+/// By default, [`new`](Self::new) takes blocks from the end of internal flash (2 MB on
+/// Pico 1, 4 MB on Pico 2). To pin storage to a
+/// known place, such as a region shared with a bootloader, use
+/// [`new_in_region`](Self::new_in_region) instead.
+///
 /// ⚠️ **Warning**: Pico 1 and Pico 2 store firmware, vector tables, and user data in the
-/// same flash device. Allocating too many blocks can overwrite your firmware.
-
+/// same flash device. Allocating too many blocks can overwrite your firmware. Reserving an
+/// explicit region (and keeping it out of the `FLASH` region in your `memory.x`) stops
+/// growing firmware from silently running into your data.
 ///
 /// # Example
 ///
@@ -222,6 +294,33 @@ impl FlashArrayStatic {
 ///     future::pending().await // Keep running
 /// }
 /// ```
+///
+/// # Saving several blocks together
+///
+/// Each [`save`](FlashBlock::save) rewrites one block. If related values live in
+/// separate blocks, a power loss between two saves leaves them inconsistent. To save them
+/// all or none, reserve one more block as a [`FlashJournal`] and save through an
+/// [`AtomicWrite`]:
+///
+/// ```rust,no_run
+/// # #![no_std]
+/// # #![no_main]
+/// # use panic_probe as _;
+/// use device_envoy::flash_array::{FlashArray, FlashJournal};
+///
+/// fn save_schedule(p: embassy_rp::Peripherals) -> device_envoy::Result<()> {
+///     let [mut alarm_block, mut volume_block, journal_block] = FlashArray::<3>::new(p.FLASH)?;
+///     // Finishes any atomic write that a power loss interrupted.
+///     let mut journal = FlashJournal::new(journal_block)?;
+///
+///     let mut write = journal.write_atomic();
+///     write.save(&mut alarm_block, &(7_u8, 30_u8))?; // 7:30
+///     write.save(&mut volume_block, &5_u8)?;
+///     write.commit()
+/// }
+/// ```
+///
+/// All values in one atomic write share the journal's sector, about 4 KB in total.
 pub struct FlashArray<const N: usize>;
 
 impl<const N: usize> FlashArray<N> {
@@ -229,13 +328,217 @@ impl<const N: usize> FlashArray<N> {
     ///
     /// See [`FlashArray`] for usage examples.
     pub fn new(peripheral: Peri<'static, FLASH>) -> Result<[FlashBlock; N]> {
-        static FLASH_STATIC: FlashArrayStatic = FlashArrayStatic::new();
         let manager = FLASH_STATIC.manager(peripheral);
         manager.reserve::<N>()
     }
+
+    /// Reserve `N` contiguous blocks inside an explicit flash region.
+    ///
+    /// `region_offset` is counted from the start of flash (not the `0x1000_0000` XIP
+    /// address). Blocks are laid out from `region_offset` forward, so block 0 starts at
+    /// `region_offset`. The region is not tracked: it must not overlap your firmware,
+    /// blocks from [`new`](Self::new), or another region.
+    ///
+    /// Returns [`Error::IndexOutOfBounds`] if `N` blocks don't fit in `region_size`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `region_offset` or `region_size` is not a multiple of the 4 KB erase
+    /// size, or if the region extends past the end of internal flash.
+    ///
+    /// See [`FlashArray`] for usage examples.
+    pub fn new_in_region(
+        peripheral: Peri<'static, FLASH>,
+        region_offset: u32,
+        region_size: u32,
+    ) -> Result<[FlashBlock; N]> {
+        let manager = FLASH_STATIC.manager(peripheral);
+        manager.reserve_in_region::<N>(region_offset, region_size)
+    }
+}
+
+/// A reserved [`FlashBlock`] that makes saves to several other blocks all-or-nothing.
+///
+/// See [Saving several blocks together](FlashArray#saving-several-blocks-together) for
+/// usage.
+pub struct FlashJournal {
+    block: FlashBlock,
+}
+
+impl FlashJournal {
+    /// Use `journal_block` as the journal, first finishing any atomic write that a power
+    /// loss interrupted.
+    ///
+    /// Create the journal at startup, before loading the blocks it protects.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Flash`] if reading or replaying the journal fails.
+    ///
+    /// See [Saving several blocks together](FlashArray#saving-several-blocks-together)
+    /// for usage.
+    pub fn new(journal_block: FlashBlock) -> Result<Self> {
+        let journal = Self {
+            block: journal_block,
+        };
+        journal.replay()?;
+        Ok(journal)
+    }
+
+    /// Start an atomic write. Stage values with [`AtomicWrite::save`], then write them all
+    /// with [`AtomicWrite::commit`].
+    ///
+    /// See [Saving several blocks together](FlashArray#saving-several-blocks-together)
+    /// for usage.
+    pub fn write_atomic(&mut self) -> AtomicWrite<'_> {
+        AtomicWrite {
+            journal: self,
+            buffer: [0xFF; ERASE_SIZE],
+            len: JOURNAL_HEADER_SIZE,
+            entry_count: 0,
+        }
+    }
+
+    // Apply a committed journal, then erase it. A journal with a bad CRC was interrupted
+    // while being written, so none of its saves were committed; it is discarded.
+    fn replay(&self) -> Result<()> {
+        let manager = self.block.manager;
+        let mut buffer = [0u8; ERASE_SIZE];
+        manager.with_flash(|flash| {
+            flash
+                .blocking_read(self.block.offset, &mut buffer)
+                .map_err(Error::Flash)
+        })?;
+
+        let magic = u32::from_le_bytes(buffer[0..4].try_into().unwrap());
+        if magic != JOURNAL_MAGIC {
+            return Ok(());
+        }
+        let entry_count = u16::from_le_bytes(buffer[4..6].try_into().unwrap());
+        let body_len = u16::from_le_bytes(buffer[6..8].try_into().unwrap()) as usize;
+        let crc_offset = JOURNAL_HEADER_SIZE + body_len;
+        let crc_is_valid =
+            buffer
+                .get(crc_offset..crc_offset + CRC_SIZE)
+                .is_some_and(|stored_crc| {
+                    stored_crc == compute_crc(&buffer[..crc_offset]).to_le_bytes()
+                });
+        if crc_is_valid {
+            info!("Flash: Replaying {} journaled saves", entry_count);
+            let mut entries = &buffer[JOURNAL_HEADER_SIZE..crc_offset];
+            for _ in 0..entry_count {
+                let (offset, type_hash, payload, rest) =
+                    split_journal_entry(entries).ok_or(Error::StorageCorrupted)?;
+                write_block_image(manager, offset, type_hash, payload)?;
+                entries = rest;
+            }
+        } else {
+            error!("Flash: Discarding incomplete journal");
+        }
+        clear_block(manager, self.block.offset)
+    }
+}
+
+/// An all-or-nothing group of saves, started with [`FlashJournal::write_atomic`].
+///
+/// All staged values share the journal's one flash sector, so together they hold up to
+/// about 4 KB of serialized data (10 bytes of overhead per value).
+///
+/// See [Saving several blocks together](FlashArray#saving-several-blocks-together) for
+/// usage.
+pub struct AtomicWrite<'a> {
+    journal: &'a mut FlashJournal,
+    buffer: [u8; ERASE_SIZE],
+    len: usize,
+    entry_count: u16,
+}
+
+impl AtomicWrite<'_> {
+    /// Stage `value` to be saved to `block` on [`commit`](Self::commit). Nothing is
+    /// written to flash yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::FormatError`] if serialization fails or the staged values no
+    /// longer fit in the journal, and [`Error::IndexOutOfBounds`] if `block` is the
+    /// journal's own block.
+    pub fn save<T>(&mut self, block: &mut FlashBlock, value: &T) -> Result<()>
+    where
+        T: Serialize + for<'de> Deserialize<'de>,
+    {
+        if block.offset == self.journal.block.offset {
+            return Err(Error::IndexOutOfBounds);
+        }
+        let payload_start = self.len + JOURNAL_ENTRY_HEADER_SIZE;
+        let payload_area = self
+            .buffer
+            .get_mut(payload_start..ERASE_SIZE - CRC_SIZE)
+            .ok_or(Error::FormatError)?;
+        let max_payload_len = payload_area.len().min(MAX_PAYLOAD_SIZE);
+        let payload_len = postcard::to_slice(value, &mut payload_area[..max_payload_len])
+            .map_err(|_| {
+                error!("Flash: Serialization failed or journal full");
+                Error::FormatError
+            })?
+            .len();
+
+        let entry = &mut self.buffer[self.len..payload_start];
+        entry[0..4].copy_from_slice(&block.offset.to_le_bytes());
+        entry[4..8].copy_from_slice(&compute_type_hash::<T>().to_le_bytes());
+        entry[8..10].copy_from_slice(&(payload_len as u16).to_le_bytes());
+        self.len = payload_start + payload_len;
+        self.entry_count += 1;
+        Ok(())
+    }
+
+    /// Save every staged value, or none of them if power is lost before the journal is
+    /// written.
+    ///
+    /// The staged values are first written to the journal block with a CRC; once that
+    /// write completes, the saves are committed. Each target block is then written and
+    /// the journal erased. If power is lost in between, [`FlashJournal::new`] finishes
+    /// the saves on the next boot.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Flash`] if a flash operation fails.
+    pub fn commit(mut self) -> Result<()> {
+        let body_len = self.len - JOURNAL_HEADER_SIZE;
+        self.buffer[0..4].copy_from_slice(&JOURNAL_MAGIC.to_le_bytes());
+        self.buffer[4..6].copy_from_slice(&self.entry_count.to_le_bytes());
+        self.buffer[6..8].copy_from_slice(&(body_len as u16).to_le_bytes());
+        let crc = compute_crc(&self.buffer[..self.len]);
+        self.buffer[self.len..self.len + CRC_SIZE].copy_from_slice(&crc.to_le_bytes());
+
+        let journal_offset = self.journal.block.offset;
+        self.journal.block.manager.with_flash(|flash| {
+            flash
+                .blocking_erase(journal_offset, journal_offset + ERASE_SIZE as u32)
+                .map_err(Error::Flash)?;
+            flash
+                .blocking_write(journal_offset, &self.buffer)
+                .map_err(Error::Flash)?;
+            Ok(())
+        })?;
+        info!("Flash: Journaled {} saves", self.entry_count);
+
+        // Apply the saves from the journal as written, so a bad write is caught by its CRC.
+        self.journal.replay()
+    }
+}
+
+/// Split one journal entry off the front of `entries`, returning its block offset, type
+/// hash, payload, and the remaining entries.
+fn split_journal_entry(entries: &[u8]) -> Option<(u32, u32, &[u8], &[u8])> {
+    let (entry_header, rest) = entries.split_at_checked(JOURNAL_ENTRY_HEADER_SIZE)?;
+    let offset = u32::from_le_bytes(entry_header[0..4].try_into().ok()?);
+    let type_hash = u32::from_le_bytes(entry_header[4..8].try_into().ok()?);
+    let payload_len = u16::from_le_bytes(entry_header[8..10].try_into().ok()?) as usize;
+    let (payload, rest) = rest.split_at_checked(payload_len)?;
+    Some((offset, type_hash, payload, rest))
 }
 
-fn save_block<T>(manager: &'static FlashManager, block: u32, value: &T) -> Result<()>
+fn save_block<T>(manager: &'static FlashManager, offset: u32, value: &T) -> Result<()>
 where
     T: Serialize + for<'de> Deserialize<'de>,
 {
@@ -250,17 +553,32 @@ where
         })?
         .len();
 
+    write_block_image(
+        manager,
+        offset,
+        compute_type_hash::<T>(),
+        &payload_buffer[..payload_len],
+    )
+}
+
+/// Erase the block at `offset` and write `payload` to it with a header and CRC.
+fn write_block_image(
+    manager: &'static FlashManager,
+    offset: u32,
+    type_hash: u32,
+    payload: &[u8],
+) -> Result<()> {
+    let payload_len = payload.len();
     let mut buffer = [0xFFu8; ERASE_SIZE];
     buffer[0..4].copy_from_slice(&MAGIC.to_le_bytes());
-    buffer[4..8].copy_from_slice(&compute_type_hash::<T>().to_le_bytes());
+    buffer[4..8].copy_from_slice(&type_hash.to_le_bytes());
     buffer[8..10].copy_from_slice(&(payload_len as u16).to_le_bytes());
-    buffer[HEADER_SIZE..HEADER_SIZE + payload_len].copy_from_slice(&payload_buffer[..payload_len]);
+    buffer[HEADER_SIZE..HEADER_SIZE + payload_len].copy_from_slice(payload);
 
     let crc_offset = HEADER_SIZE + payload_len;
     let crc = compute_crc(&buffer[0..crc_offset]);
     buffer[crc_offset..crc_offset + CRC_SIZE].copy_from_slice(&crc.to_le_bytes());
 
-    let offset = block_offset(block);
     manager.with_flash(|flash| {
         flash
             .blocking_erase(offset, offset + ERASE_SIZE as u32)
@@ -271,15 +589,14 @@ where
         Ok(())
     })?;
 
-    info!("Flash: Saved {} bytes to block {}", payload_len, block);
+    info!("Flash: Saved {} bytes to offset {:#x}", payload_len, offset);
     Ok(())
 }
 
-fn load_block<T>(manager: &'static FlashManager, block: u32) -> Result<Option<T>>
+fn load_block<T>(manager: &'static FlashManager, offset: u32) -> Result<Option<T>>
 where
     T: Serialize + for<'de> Deserialize<'de>,
 {
-    let offset = block_offset(block);
     let mut buffer = [0u8; ERASE_SIZE];
 
     manager.with_flash(|flash| {
@@ -291,7 +608,7 @@ where
 
     let magic = u32::from_le_bytes(buffer[0..4].try_into().unwrap());
     if magic != MAGIC {
-        info!("Flash: No data at block {}", block);
+        info!("Flash: No data at offset {:#x}", offset);
         return Ok(None);
     }
 
@@ -299,8 +616,8 @@ where
     let expected_type_hash = compute_type_hash::<T>();
     if stored_type_hash != expected_type_hash {
         info!(
-            "Flash: Type mismatch at block {} (expected hash {}, found {})",
-            block, expected_type_hash, stored_type_hash
+            "Flash: Type mismatch at offset {:#x} (expected hash {}, found {})",
+            offset, expected_type_hash, stored_type_hash
         );
         return Ok(None);
     }
@@ -308,8 +625,8 @@ where
     let payload_len = u16::from_le_bytes(buffer[8..10].try_into().unwrap()) as usize;
     if payload_len > MAX_PAYLOAD_SIZE {
         error!(
-            "Flash: Invalid payload length {} at block {}",
-            payload_len, block
+            "Flash: Invalid payload length {} at offset {:#x}",
+            payload_len, offset
         );
         return Err(Error::StorageCorrupted);
     }
@@ -323,31 +640,56 @@ where
     let computed_crc = compute_crc(&buffer[0..crc_offset]);
     if stored_crc != computed_crc {
         error!(
-            "Flash: CRC mismatch at block {} (expected {}, found {})",
-            block, computed_crc, stored_crc
+            "Flash: CRC mismatch at offset {:#x} (expected {}, found {})",
+            offset, computed_crc, stored_crc
         );
         return Err(Error::StorageCorrupted);
     }
 
     let payload = &buffer[HEADER_SIZE..HEADER_SIZE + payload_len];
     let value: T = postcard::from_bytes(payload).map_err(|_| {
-        error!("Flash: Deserialization failed at block {}", block);
+        error!("Flash: Deserialization failed at offset {:#x}", offset);
         Error::StorageCorrupted
     })?;
 
-    info!("Flash: Loaded data from block {}", block);
+    info!("Flash: Loaded data from offset {:#x}", offset);
     Ok(Some(value))
 }
 
-fn clear_block(manager: &'static FlashManager, block: u32) -> Result<()> {
-    let offset = block_offset(block);
+fn used_block(manager: &'static FlashManager, offset: u32) -> Result<usize> {
+    let mut header = [0u8; HEADER_SIZE];
+
+    manager.with_flash(|flash| {
+        flash
+            .blocking_read(offset, &mut header)
+            .map_err(Error::Flash)?;
+        Ok(())
+    })?;
+
+    let magic = u32::from_le_bytes(header[0..4].try_into().unwrap());
+    if magic != MAGIC {
+        return Ok(0);
+    }
+
+    let payload_len = u16::from_le_bytes(header[8..10].try_into().unwrap()) as usize;
+    if payload_len > MAX_PAYLOAD_SIZE {
+        error!(
+            "Flash: Invalid payload length {} at offset {:#x}",
+            payload_len, offset
+        );
+        return Err(Error::StorageCorrupted);
+    }
+    Ok(payload_len)
+}
+
+fn clear_block(manager: &'static FlashManager, offset: u32) -> Result<()> {
     manager.with_flash(|flash| {
         flash
             .blocking_erase(offset, offset + ERASE_SIZE as u32)
             .map_err(Error::Flash)?;
         Ok(())
     })?;
-    info!("Flash: Cleared block {}", block);
+    info!("Flash: Cleared offset {:#x}", offset);
     Ok(())
 }
 
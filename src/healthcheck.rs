@@ -0,0 +1,210 @@
+//! Heartbeats for detecting stalled embassy tasks.
+//!
+//! A task that is stuck in a long computation, or waits forever on a signal, is invisible:
+//! the rest of the system keeps running, but the device that task drives stops responding.
+//! Each task you want to watch creates a [`TaskHeartbeat`] and [`pat`](TaskHeartbeat::pat)s
+//! it regularly; [`monitor`] resolves with the [`TaskId`] of the first task that misses
+//! its deadline, so you can log it or reset the board with the watchdog.
+//!
+//! See [`TaskHeartbeat`] for the primary example.
+
+use core::cell::RefCell;
+
+use defmt::error;
+use embassy_sync::blocking_mutex::Mutex;
+use embassy_sync::blocking_mutex::raw::CriticalSectionRawMutex;
+use embassy_time::{Duration, Instant, Timer};
+use heapless::Vec;
+use portable_atomic::{AtomicU32, Ordering};
+
+/// Maximum number of tasks that can register a [`TaskHeartbeat`].
+pub const MAX_TASKS: usize = 16;
+
+static HEARTBEAT_TABLE: HeartbeatTable = HeartbeatTable::new();
+
+// One millisecond timestamp per registered task, plus the tasks' names. The timestamps
+// wrap after about 49 days, so only differences between them are meaningful.
+struct HeartbeatTable {
+    last_pat_ms: [AtomicU32; MAX_TASKS],
+    names: Mutex<CriticalSectionRawMutex, RefCell<Vec<&'static str, MAX_TASKS>>>,
+}
+
+impl HeartbeatTable {
+    const fn new() -> Self {
+        Self {
+            last_pat_ms: [const { AtomicU32::new(0) }; MAX_TASKS],
+            names: Mutex::new(RefCell::new(Vec::new())),
+        }
+    }
+
+    fn register(&self, name: &'static str) -> TaskId {
+        self.names.lock(|names| {
+            let mut names = names.borrow_mut();
+            let task_index = names.len();
+            assert!(
+                task_index < MAX_TASKS,
+                "healthcheck supports at most MAX_TASKS heartbeats"
+            );
+            // Stamp before the name becomes visible, so `monitor` never sees a stale time.
+            self.pat(task_index);
+            names.push(name).expect("length checked above");
+            TaskId(task_index)
+        })
+    }
+
+    fn pat(&self, task_index: usize) {
+        if let Some(last_pat_ms) = self.last_pat_ms.get(task_index) {
+            last_pat_ms.store(now_ms(), Ordering::Relaxed);
+        }
+    }
+
+    fn name(&self, task_index: usize) -> &'static str {
+        self.names
+            .lock(|names| names.borrow().get(task_index).copied().unwrap_or("?"))
+    }
+
+    fn first_stalled(&self, timeout_ms: u32) -> Option<TaskId> {
+        let registered_count = self.names.lock(|names| names.borrow().len());
+        self.last_pat_ms
+            .iter()
+            .take(registered_count)
+            .position(|last_pat_ms| {
+                // Load the pat before reading the clock: a pat landing after `now_ms` would
+                // look like it came from the future and wrap into a false stall.
+                let last_pat_ms = last_pat_ms.load(Ordering::Relaxed);
+                now_ms().wrapping_sub(last_pat_ms) > timeout_ms
+            })
+            .map(TaskId)
+    }
+}
+
+#[expect(
+    clippy::cast_possible_truncation,
+    reason = "a wrapping millisecond counter is enough to compare recent timestamps"
+)]
+fn now_ms() -> u32 {
+    Instant::now().as_millis() as u32
+}
+
+/// Identifies a task that registered a [`TaskHeartbeat`].
+///
+/// See the [`TaskHeartbeat` struct example](TaskHeartbeat) for usage.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, defmt::Format)]
+pub struct TaskId(usize);
+
+impl TaskId {
+    /// Registration order of the task, starting at 0.
+    #[must_use]
+    pub const fn index(self) -> usize {
+        self.0
+    }
+
+    /// Name the task gave to [`TaskHeartbeat::new`].
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        HEARTBEAT_TABLE.name(self.0)
+    }
+}
+
+/// A heartbeat that one task pats to show it is still making progress.
+///
+/// Create one at the start of each task you want to watch, then call
+/// [`pat`](Self::pat) more often than the timeout given to [`monitor`]. Heartbeats live
+/// for the rest of the program; there is no way to unregister one.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # #![no_std]
+/// # #![no_main]
+/// # use panic_probe as _;
+/// use device_envoy::healthcheck::{self, TaskHeartbeat};
+/// use embassy_rp::watchdog::Watchdog;
+/// use embassy_time::{Duration, Timer};
+///
+/// #[embassy_executor::task]
+/// async fn sensor_task() -> ! {
+///     let heartbeat = TaskHeartbeat::new("sensor");
+///     loop {
+///         heartbeat.pat();
+///         // ... read and report the sensor ...
+///         Timer::after_millis(100).await;
+///     }
+/// }
+///
+/// async fn supervise(p: embassy_rp::Peripherals) -> ! {
+///     let mut watchdog = Watchdog::new(p.WATCHDOG);
+///     // Resolves once any registered task goes 500 ms without a pat.
+///     let stalled_task = healthcheck::monitor(Duration::from_millis(500)).await;
+///     defmt::error!("resetting because {} stalled", stalled_task.name());
+///     watchdog.trigger_reset();
+///     loop {
+///         Timer::after_secs(1).await;
+///     }
+/// }
+/// ```
+pub struct TaskHeartbeat {
+    task_id: TaskId,
+}
+
+impl TaskHeartbeat {
+    /// Register a heartbeat for the current task under `name`. It counts as patted now.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`MAX_TASKS`] heartbeats are already registered.
+    ///
+    /// See the [`TaskHeartbeat` struct example](Self) for usage.
+    #[must_use]
+    pub fn new(name: &'static str) -> Self {
+        Self {
+            task_id: HEARTBEAT_TABLE.register(name),
+        }
+    }
+
+    /// Record that the task is still making progress.
+    ///
+    /// This is a single atomic store, so it is cheap enough to call on every loop pass.
+    pub fn pat(&self) {
+        HEARTBEAT_TABLE.pat(self.task_id.0);
+    }
+
+    /// The [`TaskId`] that [`monitor`] reports if this heartbeat stalls.
+    #[must_use]
+    pub const fn task_id(&self) -> TaskId {
+        self.task_id
+    }
+}
+
+/// Wait until a registered task goes longer than `timeout` without a
+/// [`pat`](TaskHeartbeat::pat), log an error, and return its [`TaskId`].
+///
+/// Checks every quarter of `timeout`, so a stall is reported between `timeout` and
+/// 1.25 × `timeout` after the last pat. Tasks that register after `monitor` starts are
+/// watched too. To recover from the stall, reset the board with
+/// [`Watchdog::trigger_reset`](embassy_rp::watchdog::Watchdog::trigger_reset).
+///
+/// # Panics
+///
+/// Panics if `timeout` is zero or longer than 24 days.
+///
+/// See the [`TaskHeartbeat` struct example](TaskHeartbeat) for usage.
+pub async fn monitor(timeout: Duration) -> TaskId {
+    let timeout_ms = u32::try_from(timeout.as_millis())
+        .ok()
+        .filter(|timeout_ms| (1..=i32::MAX.unsigned_abs()).contains(timeout_ms))
+        .expect("timeout must be between 1 ms and 24 days");
+    let check_interval = timeout / 4;
+    loop {
+        Timer::after(check_interval).await;
+        if let Some(task_id) = HEARTBEAT_TABLE.first_stalled(timeout_ms) {
+            error!(
+                "healthcheck: task {} ({}) has not patted its heartbeat for over {} ms",
+                task_id.name(),
+                task_id.index(),
+                timeout_ms
+            );
+            return task_id;
+        }
+    }
+}
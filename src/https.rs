@@ -0,0 +1,228 @@
+//! A minimal HTTPS client for fetching a resource over WiFi (requires the `tls` feature).
+//!
+//! [`https_get`] resolves the host, opens a TCP connection on port 443, performs a TLS 1.3
+//! handshake, sends an HTTP `GET`, and returns the response body in a fixed-size buffer.
+//!
+//! The server certificate chain is verified against a single pinned certificate authority
+//! (CA), given as DER bytes. Chains signed with ECDSA P-256 or P-384 are supported; RSA
+//! chains are not. Certificate validity dates are not checked because the device may not
+//! know the time yet.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! # #![no_std]
+//! # #![no_main]
+//! # use defmt_rtt as _;
+//! # use panic_probe as _;
+//! use device_envoy::{Result, https::https_get};
+//! use defmt::info;
+//!
+//! // ISRG Root X2, the ECDSA root used by Let's Encrypt, in DER form. In a real project,
+//! // use `include_bytes!("isrg-root-x2.der")`.
+//! const ISRG_ROOT_X2: &[u8] = &[];
+//!
+//! async fn fetch_ip(stack: &'static embassy_net::Stack<'static>) -> Result<()> {
+//!     let body = https_get::<1024>(stack, "api.example.com", "/ip", ISRG_ROOT_X2).await?;
+//!     info!("Body: {}", core::str::from_utf8(&body).unwrap_or("<not UTF-8>"));
+//!     Ok(())
+//! }
+//! ```
+
+#![allow(clippy::future_not_send, reason = "single-threaded")]
+
+use core::fmt::Write as _;
+
+use defmt::{info, warn};
+use embassy_net::{Stack, dns::DnsQueryType, tcp::TcpSocket};
+use embassy_rp::clocks::RoscRng;
+use embassy_time::Duration;
+use embedded_tls::{
+    Aes128GcmSha256, Certificate, CryptoProvider, CryptoRngCore, NoClock, TlsConfig, TlsConnection,
+    TlsContext, TlsError, TlsVerifier, pki::CertVerifier,
+};
+
+use crate::{Error, Result};
+
+const HTTPS_PORT: u16 = 443;
+const SOCKET_TIMEOUT: Duration = Duration::from_secs(10);
+const TCP_BUFFER_LEN: usize = 4096;
+// The largest TLS record a server may send (16 KiB of data plus overhead).
+const TLS_READ_BUFFER_LEN: usize = 16_640;
+const TLS_WRITE_BUFFER_LEN: usize = 4096;
+// Room for the server's certificate chain while it is being verified.
+const CERT_BUFFER_LEN: usize = 4096;
+const REQUEST_LEN: usize = 512;
+
+/// Fetch `https://{host}{path}` and return the response body.
+///
+/// The whole response is read into one `N`-byte buffer, so `N` must hold the status
+/// line and headers as well as the body; headers commonly add a few hundred bytes. The
+/// request is sent as HTTP/1.0 so the server replies with a plain (not chunked) body and
+/// closes the connection when done.
+///
+/// `ca` is the DER encoding of the certificate authority that signs the server's chain.
+/// The connection is refused if the chain does not lead to `ca` or if the certificate
+/// does not name `host`.
+///
+/// The returned future holds about 30 KB of buffers, so await it from a task rather than
+/// a deeply nested call.
+///
+/// # Errors
+///
+/// Returns [`Error::Https`] if DNS, the connection, the TLS handshake, or the transfer
+/// fails, if the response is malformed, or if the headers and body together do not fit
+/// in `N` bytes.
+/// Returns [`Error::HttpStatus`] if the server answers with a status other than 200.
+///
+/// See the [module example](self) for usage.
+pub async fn https_get<const N: usize>(
+    stack: &Stack<'static>,
+    host: &str,
+    path: &str,
+    ca: &[u8],
+) -> Result<heapless::Vec<u8, N>> {
+    info!("Resolving HTTPS host {}...", host);
+    let dns_result = stack
+        .dns_query(host, DnsQueryType::A)
+        .await
+        .map_err(|err| {
+            warn!("DNS lookup failed: {:?}", err);
+            Error::Https("DNS lookup failed")
+        })?;
+    let server_addr = dns_result.first().ok_or(Error::Https("No DNS results"))?;
+
+    let mut rx_buffer = [0u8; TCP_BUFFER_LEN];
+    let mut tx_buffer = [0u8; TCP_BUFFER_LEN];
+    let mut socket = TcpSocket::new(*stack, &mut rx_buffer, &mut tx_buffer);
+    socket.set_timeout(Some(SOCKET_TIMEOUT));
+    socket
+        .connect((*server_addr, HTTPS_PORT))
+        .await
+        .map_err(|err| {
+            warn!("TCP connect failed: {:?}", err);
+            Error::Https("TCP connect failed")
+        })?;
+
+    let mut tls_read_buffer = [0u8; TLS_READ_BUFFER_LEN];
+    let mut tls_write_buffer = [0u8; TLS_WRITE_BUFFER_LEN];
+    let mut tls: TlsConnection<'_, _, Aes128GcmSha256> =
+        TlsConnection::new(socket, &mut tls_read_buffer, &mut tls_write_buffer);
+    let config = TlsConfig::new().with_server_name(host);
+    let provider = PinnedCaProvider {
+        rng: RoscRng,
+        verifier: CertVerifier::new(Certificate::X509(ca)),
+    };
+    tls.open(TlsContext::new(&config, provider))
+        .await
+        .map_err(|err| {
+            warn!("TLS handshake failed: {:?}", err);
+            Error::Https("TLS handshake failed")
+        })?;
+
+    let mut request = heapless::String::<REQUEST_LEN>::new();
+    write!(
+        request,
+        "GET {path} HTTP/1.0\r\nHost: {host}\r\nUser-Agent: device-envoy\r\n\r\n"
+    )
+    .map_err(|_| Error::Https("Request does not fit in buffer"))?;
+    let mut request_bytes = request.as_bytes();
+    while !request_bytes.is_empty() {
+        let bytes_written = tls.write(request_bytes).await.map_err(|err| {
+            warn!("TLS write failed: {:?}", err);
+            Error::Https("TLS write failed")
+        })?;
+        request_bytes = &request_bytes[bytes_written..];
+    }
+    tls.flush().await.map_err(|err| {
+        warn!("TLS flush failed: {:?}", err);
+        Error::Https("TLS write failed")
+    })?;
+
+    let mut response = heapless::Vec::<u8, N>::new();
+    response
+        .resize_default(N)
+        .map_err(|()| Error::Https("Response buffer allocation failed"))?;
+    let mut response_len = 0;
+    loop {
+        if response_len == N {
+            let mut extra = [0u8; 1];
+            if read_or_eof(&mut tls, &mut extra).await? > 0 {
+                return Err(Error::Https("Response does not fit in buffer"));
+            }
+            break;
+        }
+        let bytes_read = read_or_eof(&mut tls, &mut response[response_len..]).await?;
+        if bytes_read == 0 {
+            break;
+        }
+        response_len += bytes_read;
+    }
+    // The server closes the connection after the body, so a failed close is only logged.
+    if let Err((_socket, err)) = tls.close().await {
+        warn!("TLS close failed: {:?}", err);
+    }
+    response.truncate(response_len);
+
+    let body_start = parse_response_head(&response)?;
+    response.copy_within(body_start.., 0);
+    response.truncate(response_len - body_start);
+    Ok(response)
+}
+
+/// Read decrypted bytes, treating a closed connection as end of response.
+async fn read_or_eof(
+    tls: &mut TlsConnection<'_, TcpSocket<'_>, Aes128GcmSha256>,
+    buffer: &mut [u8],
+) -> Result<usize> {
+    match tls.read(buffer).await {
+        Ok(bytes_read) => Ok(bytes_read),
+        Err(TlsError::ConnectionClosed) => Ok(0),
+        Err(err) => {
+            warn!("TLS read failed: {:?}", err);
+            Err(Error::Https("TLS read failed"))
+        }
+    }
+}
+
+/// Check the status line and return the offset where the body starts.
+fn parse_response_head(response: &[u8]) -> Result<usize> {
+    let head_len = response
+        .windows(4)
+        .position(|window| window == b"\r\n\r\n")
+        .ok_or(Error::Https("Response has no header terminator"))?;
+    let head = core::str::from_utf8(&response[..head_len])
+        .map_err(|_| Error::Https("Response header is not UTF-8"))?;
+    let status = head
+        .lines()
+        .next()
+        .and_then(|status_line| status_line.split_whitespace().nth(1))
+        .and_then(|status| status.parse::<u16>().ok())
+        .ok_or(Error::Https("Response has no status code"))?;
+    if status != 200 {
+        warn!("HTTPS request returned status {}", status);
+        return Err(Error::HttpStatus(status));
+    }
+    Ok(head_len + 4)
+}
+
+/// Crypto provider that verifies the server against one pinned CA.
+struct PinnedCaProvider<'a> {
+    rng: RoscRng,
+    verifier: CertVerifier<'a, Aes128GcmSha256, NoClock, CERT_BUFFER_LEN>,
+}
+
+impl CryptoProvider for PinnedCaProvider<'_> {
+    type CipherSuite = Aes128GcmSha256;
+    type Signature = &'static [u8];
+
+    fn rng(&mut self) -> impl CryptoRngCore {
+        &mut self.rng
+    }
+
+    fn verifier(
+        &mut self,
+    ) -> core::result::Result<&mut impl TlsVerifier<Self::CipherSuite>, TlsError> {
+        Ok(&mut self.verifier)
+    }
+}
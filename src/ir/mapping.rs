@@ -7,9 +7,11 @@ use embassy_rp::Peri;
 use embassy_rp::gpio::Pin;
 use embassy_rp::pio::PioPin;
 use heapless::LinearMap;
+use serde::{Deserialize, Serialize};
 
-use crate::Result;
+use crate::flash_array::FlashBlock;
 use crate::ir::{Ir, IrEvent, IrPioPeripheral, IrStatic};
+use crate::{Error, Result};
 
 /// Static channel for IR mapping events.
 ///
@@ -60,6 +62,43 @@ impl IrMappingStatic {
 ///     }
 /// }
 /// ```
+///
+/// # Learning buttons at runtime
+///
+/// The button map is only a starting point. [`wait_for_code`](Self::wait_for_code)
+/// returns the raw code of the next press, whether mapped or not, and
+/// [`remap`](Self::remap) assigns a code to a button, so an app can teach itself any
+/// remote without reflashing. [`save_to_flash`](Self::save_to_flash) and
+/// [`load_from_flash`](Self::load_from_flash) keep the learned map across power cycles.
+///
+/// ```rust,no_run
+/// # #![no_std]
+/// # #![no_main]
+/// use device_envoy::flash_array::FlashArray;
+/// use device_envoy::ir::{IrMapping, IrMappingStatic};
+/// # #[panic_handler]
+/// # fn panic(_info: &core::panic::PanicInfo) -> ! { loop {} }
+/// #[derive(serde::Serialize, serde::Deserialize, Debug, Clone, Copy)]
+/// enum RemoteButton { Power, Play, Stop }
+/// async fn example(
+///     p: embassy_rp::Peripherals,
+///     spawner: embassy_executor::Spawner,
+/// ) -> device_envoy::Result<()> {
+///     let button_map = [(0x0000, 0x45, RemoteButton::Power)];
+///     static IR_MAPPING_STATIC: IrMappingStatic = IrMapping::<RemoteButton, 3>::new_static();
+///     let mut ir_mapping: IrMapping<RemoteButton, 3> = IrMapping::new(&IR_MAPPING_STATIC, p.PIN_15, p.PIO0, &button_map, spawner)?;
+///     let [mut ir_block] = FlashArray::<1>::new(p.FLASH)?;
+///
+///     // At startup, restore any buttons learned earlier.
+///     ir_mapping.load_from_flash(&mut ir_block)?;
+///
+///     // Learn the "Play" button: ask the user to press it, then map that code.
+///     let (addr, cmd) = ir_mapping.wait_for_code().await;
+///     ir_mapping.remap(addr, cmd, RemoteButton::Play)?;
+///     ir_mapping.save_to_flash(&mut ir_block)?;
+///     Ok(())
+/// }
+/// ```
 pub struct IrMapping<'a, B, const N: usize> {
     ir: Ir<'a>,
     button_map: LinearMap<(u16, u8), B, N>,
@@ -132,4 +171,58 @@ where
             defmt::info!("  (unrecognized - ignoring)");
         }
     }
+
+    /// Wait for the next button press and return its `(address, command)` code, whether
+    /// or not it is in the button map.
+    ///
+    /// Use it to learn a remote's codes before calling [`remap`](Self::remap).
+    pub async fn wait_for_code(&self) -> (u16, u8) {
+        let IrEvent::Press { addr, cmd } = self.ir.wait_for_press().await;
+        (addr, cmd)
+    }
+
+    /// Map the `(addr, cmd)` code to `button`, replacing any button it was mapped to.
+    ///
+    /// Several codes may map to the same button, so a button can be taught a second
+    /// remote without forgetting the first.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::IrMappingFull`] if the code is new and the map already holds `N`
+    /// codes.
+    pub fn remap(&mut self, addr: u16, cmd: u8, button: B) -> Result<()> {
+        self.button_map
+            .insert((addr, cmd), button)
+            .map_err(|_| Error::IrMappingFull)?;
+        Ok(())
+    }
+
+    /// Save the current button map, including remapped codes, to `block`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the flash write fails or the map does not fit in the block.
+    pub fn save_to_flash(&self, block: &mut FlashBlock) -> Result<()>
+    where
+        B: Serialize + for<'de> Deserialize<'de>,
+    {
+        block.save(&self.button_map)
+    }
+
+    /// Replace the button map with one saved by [`save_to_flash`](Self::save_to_flash).
+    ///
+    /// If `block` holds no saved map, the current map is kept.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the flash read fails or `block` holds data of another type.
+    pub fn load_from_flash(&mut self, block: &mut FlashBlock) -> Result<()>
+    where
+        B: Serialize + for<'de> Deserialize<'de>,
+    {
+        if let Some(button_map) = block.load()? {
+            self.button_map = button_map;
+        }
+        Ok(())
+    }
 }
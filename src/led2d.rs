@@ -15,7 +15,10 @@
 //! - [`Led2dGenerated`](`crate::led2d::led2d_generated::Led2dGenerated`) — Sample struct type generated by the [`led2d!`](macro@crate::led2d) macro, showing all methods and constants.
 //! - [`LedLayout`] — Compile-type description of panel geometry and wiring, including dimensions (with examples)
 //! - [`Frame2d`] — 2D pixel array used for general graphics (includes examples)
+//! - [`Led2dTiled`] — Several identical panels, each on its own strip, drawn as one large surface (includes examples)
 //! - [`led_strips!`](crate::led_strips) — Alternative macro to share a PIO resource with other panels or LED strips (includes examples)
+//! - [`LedPanel`] — Trait implemented by generated panel and strip types, for generic code over devices of different sizes
+//! - [`LedDisplay`] — Object-safe trait for clearing or filling any generated panel or strip through `&dyn LedDisplay`
 //!
 //! # Example: Write Text
 //!
@@ -137,6 +140,61 @@
 //!     future::pending().await // run forever
 //! }
 //! ```
+//!
+//! # Stack Usage on Large Panels
+//!
+//! A frame takes 3 bytes per LED, so a `Frame2d<32, 32>` is 3 KB. `write_frame` also
+//! builds a strip-order copy of the frame, and `animate` gathers up to `max_frames` strip
+//! frames on the caller's stack (16 × 3 KB ≈ 48 KB for a 32×32 panel).
+//!
+//! When the frames are known at compile time, convert them to strip order in a `const` with
+//! [`LedLayout::remap`], keep them in a `static`, and pass them by reference to
+//! `write_static_frame` or `animate_static_frames`. Nothing is copied onto the caller's
+//! stack, and the device task copies only the one frame it is showing. Set `max_frames: 0`
+//! if the panel never uses `animate`. For frames drawn at run time, `animate_generated`
+//! computes one frame at a time instead.
+//!
+//! ```rust,no_run
+//! # #![no_std]
+//! # #![no_main]
+//! # use panic_probe as _;
+//! # use core::convert::Infallible;
+//! # use core::future;
+//! # use embassy_executor::Spawner;
+//! # use embassy_rp::init;
+//! use device_envoy::{Result, led2d, led2d::layout::LedLayout, led2d::Frame2d, led_strip::{Frame1d, colors}};
+//! use embassy_time::Duration;
+//!
+//! const LED_LAYOUT: LedLayout<1024, 32, 32> = LedLayout::serpentine_column_major();
+//!
+//! led2d! {
+//!     Led32x32 {
+//!         pin: PIN_4,
+//!         led_layout: LED_LAYOUT,
+//!         max_frames: 0, // no `animate` buffer needed
+//!     }
+//! }
+//!
+//! // Converted to strip order at compile time and stored in flash.
+//! static STRIP_FRAMES: [(Frame1d<1024>, Duration); 2] = [
+//!     (LED_LAYOUT.remap(&Frame2d::filled(colors::RED)), Duration::from_millis(500)),
+//!     (LED_LAYOUT.remap(&Frame2d::filled(colors::BLUE)), Duration::from_millis(500)),
+//! ];
+//!
+//! # #[embassy_executor::main]
+//! # pub async fn main(spawner: Spawner) -> ! {
+//! #     let err = example(spawner).await.unwrap_err();
+//! #     core::panic!("{err}");
+//! # }
+//! async fn example(spawner: Spawner) -> Result<Infallible> {
+//!     let p = init(Default::default());
+//!     let led_32x32 = Led32x32::new(p.PIN_4, p.PIO0, p.DMA_CH0, spawner)?;
+//!
+//!     led_32x32.animate_static_frames(&STRIP_FRAMES)?;
+//!
+//!     future::pending().await // run forever
+//! }
+//! ```
 
 // Re-export for macro use
 #[doc(hidden)]
@@ -150,8 +208,14 @@ pub use embedded_graphics::geometry::Point;
 ///
 /// # [`embedded-graphics::Size`](https://docs.rs/embedded-graphics/latest/embedded_graphics/geometry/struct.Size.html) Documentation:
 pub use embedded_graphics::geometry::Size;
+/// Re-exported from the [`embedded-graphics`](https://docs.rs/embedded-graphics) crate.
+///
+/// # [`embedded-graphics::Rectangle`](https://docs.rs/embedded-graphics/latest/embedded_graphics/primitives/rectangle/struct.Rectangle.html) Documentation:
+pub use embedded_graphics::primitives::Rectangle;
 
 pub mod layout;
+#[cfg(feature = "host")]
+pub mod layout_csv;
 
 pub mod led2d_generated;
 
@@ -177,31 +241,495 @@ use embedded_graphics::{
         mapping::StrGlyphMapping,
     },
     prelude::*,
+    primitives::{Circle, PrimitiveStyle},
 };
 use smart_leds::RGB8;
 
+use crate::led_strip::Frame1d as StripFrame;
 #[cfg(not(feature = "host"))]
-use crate::led_strip::{Frame1d as StripFrame, LedStrip};
+use crate::led_strip::LedStrip;
 #[cfg(feature = "host")]
-type StripFrame<const N: usize> = [RGB8; N];
+use crate::led_strip::{AnimationClock, FrameObserver};
+use crate::led_strip::{FrameSummary, write_colors};
+/// Size and animation capacity of an LED device, available at runtime.
+///
+/// Returned by [`LedPanel::device_info`]. An LED strip reports itself as one row of
+/// `len` LEDs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, defmt::Format)]
+pub struct DeviceInfo {
+    /// Total number of LEDs.
+    pub len: usize,
+    /// Number of columns.
+    pub width: usize,
+    /// Number of rows.
+    pub height: usize,
+    /// Maximum number of animation frames.
+    pub max_frames: usize,
+}
+
+/// Common interface of the LED panel and LED strip types generated by this crate's macros.
+///
+/// Use it to write one generic function that works with several devices of different
+/// sizes. Read a device's size at runtime with [`device_info`](Self::device_info), or at
+/// compile time from the generated type's `WIDTH`/`HEIGHT`/`LEN` constants.
+///
+/// Implemented by types from [`led2d!`](macro@crate::led2d),
+/// [`led_strip!`](macro@crate::led_strip), and [`led_strips!`](crate::led_strips).
+pub trait LedPanel {
+    /// The frame type the device displays: [`Frame2d`] for panels,
+    /// [`Frame1d`](crate::led_strip::Frame1d) for strips.
+    type Frame;
+
+    /// Returns the device's size and animation capacity.
+    fn device_info(&self) -> DeviceInfo;
+
+    /// Writes a frame to the device. It remains displayed until another command
+    /// replaces it.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as the device's own `write_frame`.
+    fn write_frame(&self, frame: Self::Frame) -> Result<()>;
+
+    /// Loops forever through a sequence of `(frame, duration)` pairs.
+    ///
+    /// Accepts pairs by value or by reference, so `&FRAMES` animates a `const` table
+    /// without copying it first.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as the device's own `animate`.
+    fn animate<I>(&self, frames: I) -> Result<()>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<(Self::Frame, Duration)>;
+}
+
+/// Minimal display interface shared by every LED panel and LED strip type generated by
+/// this crate's macros.
+///
+/// Unlike [`LedPanel`], this trait has no frame type, so it can be used as a trait object:
+/// code that only shows status colors can take `&dyn LedDisplay` without knowing whether it
+/// drives a strip or a panel, or how large it is, and a `&[&dyn LedDisplay]` can hold every
+/// device in a project. Like `write_frame`, the methods hand the frame to the device's
+/// background task and return without waiting.
+///
+/// Implemented by types from [`led2d!`](macro@crate::led2d),
+/// [`led_strip!`](macro@crate::led_strip), and [`led_strips!`](crate::led_strips).
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # #![no_std]
+/// # #![no_main]
+/// # use panic_probe as _;
+/// use device_envoy::{Result, led_strip::colors, led2d::LedDisplay};
+///
+/// fn show_status(displays: &[&dyn LedDisplay], is_ok: bool) -> Result<()> {
+///     for display in displays {
+///         if is_ok {
+///             display.fill(colors::GREEN)?;
+///         } else {
+///             display.clear()?;
+///         }
+///     }
+///     Ok(())
+/// }
+/// ```
+pub trait LedDisplay {
+    /// Turns all LEDs off. The display stays dark until another command replaces it.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as the device's own `write_frame`.
+    fn clear(&self) -> Result<()> {
+        self.fill(RGB8::new(0, 0, 0))
+    }
+
+    /// Sets every LED to `color`. It remains displayed until another command replaces it.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as the device's own `write_frame`.
+    fn fill(&self, color: RGB8) -> Result<()>;
+
+    /// Returns the number of LEDs, the same as the `len` reported by
+    /// [`LedPanel::device_info`].
+    fn len(&self) -> usize;
+
+    /// Returns `true` if the device has no LEDs.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
 #[cfg(feature = "host")]
 /// Stub LED strip type for host testing.
 ///
-/// This type provides no-op implementations for testing 2D LED panel code on host machines.
+/// This type drives no hardware. Instead, it reports each frame to a
+/// [`FrameObserver`] so tests can assert on what would have been displayed.
+/// It also tracks which frame would be displayed at the time given by an
+/// [`AnimationClock`]; see [`displayed_frame`](Self::displayed_frame).
 /// See the [`led2d`](self) module documentation for usage.
-pub struct LedStrip<const N: usize, const MAX_FRAMES: usize>;
+pub struct LedStrip<const N: usize, const MAX_FRAMES: usize> {
+    frame_observer: &'static dyn FrameObserver<N>,
+    animation_clock: &'static dyn AnimationClock,
+    displayed: std::sync::Mutex<HostDisplayed<N>>,
+    // The last frame written with `write_frame` or `write_segment`, as on hardware.
+    current_frame: std::sync::Mutex<StripFrame<N>>,
+    brightness: std::sync::Mutex<u8>,
+}
+
+// What the host stub strip would be showing.
+#[cfg(feature = "host")]
+enum HostDisplayed<const N: usize> {
+    Nothing,
+    Frame(StripFrame<N>),
+    Animation {
+        frames: std::vec::Vec<(StripFrame<N>, Duration)>,
+        started_at: embassy_time::Instant,
+    },
+    ShutDown,
+}
+
 #[cfg(feature = "host")]
 impl<const N: usize, const MAX_FRAMES: usize> LedStrip<N, MAX_FRAMES> {
-    fn write_frame(&self, _frame: StripFrame<N>) -> Result<()> {
+    /// Create a stub strip that reports frames to `frame_observer`.
+    ///
+    /// Pass `&()` for the default, no-op observer. Time is frozen at zero; use
+    /// [`new_with_clock`](Self::new_with_clock) to test animation timing.
+    #[must_use]
+    pub const fn new(frame_observer: &'static dyn FrameObserver<N>) -> Self {
+        Self::new_with_clock(frame_observer, &())
+    }
+
+    /// Create a stub strip that reports frames to `frame_observer` and reads the
+    /// time from `animation_clock`.
+    ///
+    /// Pass a [`VirtualClock`](crate::led_strip::VirtualClock) and advance it to
+    /// check which animation frame [`displayed_frame`](Self::displayed_frame) reports.
+    #[must_use]
+    pub const fn new_with_clock(
+        frame_observer: &'static dyn FrameObserver<N>,
+        animation_clock: &'static dyn AnimationClock,
+    ) -> Self {
+        Self {
+            frame_observer,
+            animation_clock,
+            displayed: std::sync::Mutex::new(HostDisplayed::Nothing),
+            current_frame: std::sync::Mutex::new(StripFrame::new()),
+            brightness: std::sync::Mutex::new(u8::MAX),
+        }
+    }
+
+    /// Returns the frame the strip would be displaying at the clock's current time,
+    /// or `None` if nothing has been written yet.
+    ///
+    /// Animations loop forever, just like on hardware.
+    #[must_use]
+    pub fn displayed_frame(&self) -> Option<StripFrame<N>> {
+        let displayed = self
+            .displayed
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        match &*displayed {
+            HostDisplayed::Nothing => None,
+            HostDisplayed::Frame(frame) => Some(*frame),
+            HostDisplayed::ShutDown => Some(StripFrame::new()),
+            HostDisplayed::Animation { frames, started_at } => {
+                let cycle_ticks: u64 = frames.iter().map(|(_, duration)| duration.as_ticks()).sum();
+                let mut remaining_ticks = self
+                    .animation_clock
+                    .now()
+                    .as_ticks()
+                    .saturating_sub(started_at.as_ticks())
+                    % cycle_ticks;
+                frames.iter().find_map(|(frame, duration)| {
+                    if remaining_ticks < duration.as_ticks() {
+                        Some(*frame)
+                    } else {
+                        remaining_ticks -= duration.as_ticks();
+                        None
+                    }
+                })
+            }
+        }
+    }
+
+    /// Report a single frame to the observer.
+    ///
+    /// # Errors
+    ///
+    /// Never returns an error; the signature matches the embedded strip.
+    pub fn write_frame(&self, frame: StripFrame<N>) -> Result<()> {
+        if self.is_shut_down() {
+            return Ok(());
+        }
+        *self
+            .current_frame
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner) = frame;
+        self.frame_observer.on_frame(&frame);
+        *self
+            .displayed
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner) = HostDisplayed::Frame(frame);
+        Ok(())
+    }
+
+    /// Report each animation frame to the observer, once and in order.
+    ///
+    /// # Errors
+    ///
+    /// Never returns an error; the signature matches the embedded strip.
+    pub fn animate<I>(&self, frames: I) -> Result<()>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<(StripFrame<N>, Duration)>,
+    {
+        self.record_animation(frames, None);
+        Ok(())
+    }
+
+    /// Report each animation frame to the observer, once and in order, calling
+    /// `callback(frame_index)` after each one.
+    ///
+    /// # Errors
+    ///
+    /// Never returns an error; the signature matches the embedded strip.
+    pub fn animate_with_callback<I>(&self, frames: I, callback: fn(usize)) -> Result<()>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<(StripFrame<N>, Duration)>,
+    {
+        self.record_animation(frames, Some(callback));
+        Ok(())
+    }
+
+    /// Report each frame to the observer like [`animate`](Self::animate), all with the
+    /// same `frame_duration`.
+    ///
+    /// # Errors
+    ///
+    /// Never returns an error; the signature matches the embedded strip.
+    pub fn animate_uniform<I>(&self, frames: I, frame_duration: Duration) -> Result<()>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<StripFrame<N>>,
+    {
+        self.record_animation(
+            frames
+                .into_iter()
+                .map(|frame| (*frame.borrow(), frame_duration)),
+            None,
+        );
         Ok(())
     }
 
-    fn animate(&self, _frames: impl IntoIterator<Item = (StripFrame<N>, Duration)>) -> Result<()> {
+    /// Merge `pixels` into the last written frame starting at `start_led`, then report the
+    /// merged frame like [`write_frame`](Self::write_frame).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::IndexOutOfBounds`](crate::Error::IndexOutOfBounds) if the segment
+    /// extends past the end of the strip, like the embedded strip.
+    pub fn write_segment(&self, pixels: &[RGB8], start_led: usize) -> Result<()> {
+        let mut frame = *self
+            .current_frame
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        start_led
+            .checked_add(pixels.len())
+            .and_then(|end_led| frame.get_mut(start_led..end_led))
+            .ok_or(crate::Error::IndexOutOfBounds)?
+            .copy_from_slice(pixels);
+        self.write_frame(frame)
+    }
+
+    /// Report a frame from static memory like [`write_frame`](Self::write_frame).
+    ///
+    /// # Errors
+    ///
+    /// Never returns an error; the signature matches the embedded strip.
+    pub fn write_static_frame(&self, frame: &'static StripFrame<N>) -> Result<()> {
+        self.write_frame(*frame)
+    }
+
+    /// Report frames from static memory like [`animate`](Self::animate).
+    ///
+    /// # Errors
+    ///
+    /// Never returns an error; the signature matches the embedded strip.
+    pub fn animate_static_frames(
+        &self,
+        frames: &'static [(StripFrame<N>, Duration)],
+    ) -> Result<()> {
+        self.animate(frames)
+    }
+
+    /// Generate `frame_count` frames up front and report them like [`animate`](Self::animate).
+    ///
+    /// # Errors
+    ///
+    /// Never returns an error; the signature matches the embedded strip.
+    pub fn animate_generated(
+        &self,
+        frame_count: usize,
+        frame_duration: Duration,
+        generate: fn(usize) -> StripFrame<N>,
+    ) -> Result<()> {
+        self.record_animation(
+            (0..frame_count).map(|frame_index| (generate(frame_index), frame_duration)),
+            None,
+        );
         Ok(())
     }
+
+    /// Returns `true` while an animation is displayed, like the embedded strip.
+    #[must_use]
+    pub fn is_animating(&self) -> bool {
+        matches!(
+            *self
+                .displayed
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner),
+            HostDisplayed::Animation { .. }
+        )
+    }
+
+    /// Resolve once no animation is displayed, like the embedded strip.
+    ///
+    /// The stub applies commands at once, so this resolves immediately unless an
+    /// animation is displayed; then it resolves after another thread writes a frame.
+    pub async fn wait_idle(&self) {
+        core::future::poll_fn(|context| {
+            if self.is_animating() {
+                context.waker().wake_by_ref();
+                core::task::Poll::Pending
+            } else {
+                core::task::Poll::Ready(())
+            }
+        })
+        .await;
+    }
+
+    /// Record the brightness, like the embedded strip.
+    ///
+    /// Reported frames are not scaled, just as gamma and the current limit are not
+    /// applied.
+    pub fn set_brightness(&self, brightness: u8) {
+        *self
+            .brightness
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner) = brightness;
+    }
+
+    /// The brightness last set with [`set_brightness`](Self::set_brightness).
+    #[must_use]
+    pub fn brightness(&self) -> u8 {
+        *self
+            .brightness
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    /// Report an all-off frame, like the embedded strip's final write, then ignore all
+    /// later commands.
+    pub fn shutdown(&self) {
+        if self.is_shut_down() {
+            return;
+        }
+        self.frame_observer.on_frame(&StripFrame::new());
+        *self
+            .displayed
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner) = HostDisplayed::ShutDown;
+    }
+
+    fn is_shut_down(&self) -> bool {
+        matches!(
+            *self
+                .displayed
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner),
+            HostDisplayed::ShutDown
+        )
+    }
+
+    fn record_animation<I>(&self, frames: I, callback: Option<fn(usize)>)
+    where
+        I: IntoIterator,
+        I::Item: Borrow<(StripFrame<N>, Duration)>,
+    {
+        if self.is_shut_down() {
+            return;
+        }
+        let mut sequence = std::vec::Vec::new();
+        for (frame_index, frame) in frames.into_iter().enumerate() {
+            let (frame, duration) = *frame.borrow();
+            assert!(
+                duration.as_ticks() > 0,
+                "animation frame duration must be positive"
+            );
+            self.frame_observer.on_frame(&frame);
+            if let Some(callback) = callback {
+                callback(frame_index);
+            }
+            sequence.push((frame, duration));
+        }
+        assert!(
+            !sequence.is_empty(),
+            "animation requires at least one frame"
+        );
+        *self
+            .displayed
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner) = HostDisplayed::Animation {
+            frames: sequence,
+            started_at: self.animation_clock.now(),
+        };
+    }
+}
+
+#[cfg(feature = "host")]
+impl<const N: usize, const MAX_FRAMES: usize> LedPanel for LedStrip<N, MAX_FRAMES> {
+    type Frame = StripFrame<N>;
+
+    fn device_info(&self) -> DeviceInfo {
+        DeviceInfo {
+            len: N,
+            width: N,
+            height: 1,
+            max_frames: MAX_FRAMES,
+        }
+    }
+
+    fn write_frame(&self, frame: Self::Frame) -> Result<()> {
+        Self::write_frame(self, frame)
+    }
+
+    fn animate<I>(&self, frames: I) -> Result<()>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<(Self::Frame, Duration)>,
+    {
+        Self::animate(self, frames)
+    }
+}
+
+#[cfg(feature = "host")]
+impl<const N: usize, const MAX_FRAMES: usize> LedDisplay for LedStrip<N, MAX_FRAMES> {
+    fn fill(&self, color: RGB8) -> Result<()> {
+        self.write_frame(StripFrame::filled(color))
+    }
+
+    fn len(&self) -> usize {
+        N
+    }
 }
 use crate::Result;
 use crate::led_strip::ToRgb888;
+use crate::led_strip::{FireHeat, heat_to_color};
 
 // Packed bitmap for the internal 3x4 font (ASCII 0x20-0x7E).
 const BIT_MATRIX3X4_FONT_DATA: [u8; 144] = [
@@ -238,13 +766,66 @@ pub fn bit_matrix3x4_font() -> MonoFont<'static> {
 
 #[doc(hidden)]
 /// Render text into a frame using the provided font.
+///
+/// With `proportional`, each character advances by its inked width plus one pixel
+/// instead of the font's fixed cell width, so narrow characters like `i` take less room.
+/// Blank characters such as space keep the fixed width, and `spacing_reduction.0` is
+/// then unused.
 pub fn render_text_to_frame<const W: usize, const H: usize>(
     frame: &mut Frame2d<W, H>,
     font: &embedded_graphics::mono_font::MonoFont<'static>,
     text: &str,
     colors: &[RGB8],
     spacing_reduction: (i32, i32),
+    proportional: bool,
 ) -> Result<()> {
+    render_text_run(
+        frame,
+        font,
+        text,
+        colors,
+        spacing_reduction,
+        Point::zero(),
+        proportional,
+    );
+    Ok(())
+}
+
+#[doc(hidden)]
+/// Render text into a frame starting at `position` (top-left of the first character cell).
+///
+/// Returns the cursor position where the next character would be drawn, so text runs
+/// with different colors can be chained on one line. A `\n` returns the cursor to
+/// `position.x` on the next line.
+pub fn render_text_to_frame_at<const W: usize, const H: usize>(
+    frame: &mut Frame2d<W, H>,
+    font: &embedded_graphics::mono_font::MonoFont<'static>,
+    text: &str,
+    colors: &[RGB8],
+    spacing_reduction: (i32, i32),
+    position: Point,
+) -> Result<Point> {
+    Ok(render_text_run(
+        frame,
+        font,
+        text,
+        colors,
+        spacing_reduction,
+        position,
+        false,
+    ))
+}
+
+// Lay out and draw one run of text; returns the cursor after it.
+fn render_text_run<const W: usize, const H: usize>(
+    frame: &mut Frame2d<W, H>,
+    font: &embedded_graphics::mono_font::MonoFont<'static>,
+    text: &str,
+    colors: &[RGB8],
+    spacing_reduction: (i32, i32),
+    position: Point,
+    proportional: bool,
+) -> Point {
     let glyph_width = font.character_size.width as i32;
     let glyph_height = font.character_size.height as i32;
     let advance_x = glyph_width - spacing_reduction.0;
@@ -252,16 +833,16 @@ pub fn render_text_to_frame<const W: usize, const H: usize>(
     let width_limit = W as i32;
     let height_limit = H as i32;
     if height_limit <= 0 || width_limit <= 0 {
-        return Ok(());
+        return position;
     }
     let baseline = font.baseline as i32;
-    let mut x = 0i32;
-    let mut y = baseline;
+    let mut x = position.x;
+    let mut y = position.y + baseline;
     let mut color_index: usize = 0;
 
     for ch in text.chars() {
         if ch == '\n' {
-            x = 0;
+            x = position.x;
             y += advance_y;
             if y - baseline >= height_limit {
                 break;
@@ -269,8 +850,16 @@ pub fn render_text_to_frame<const W: usize, const H: usize>(
             continue;
         }
 
+        // In proportional mode, draw the glyph shifted left so its ink starts at `x`.
+        let (ink_offset, ink_width, advance) = match glyph_ink_columns(font, ch) {
+            Some((first_column, ink_width)) if proportional => {
+                (first_column, ink_width, ink_width + 1)
+            }
+            _ => (0, advance_x, advance_x),
+        };
+
         // Clip characters that exceed width limit (no wrapping until explicit \n)
-        if x + advance_x > width_limit {
+        if x + ink_width > width_limit {
             continue;
         }
 
@@ -284,24 +873,250 @@ pub fn render_text_to_frame<const W: usize, const H: usize>(
         let mut buf = [0u8; 4];
         let slice = ch.encode_utf8(&mut buf);
         let style = embedded_graphics::mono_font::MonoTextStyle::new(font, color.to_rgb888());
-        let position = embedded_graphics::prelude::Point::new(x, y);
+        let glyph_position = embedded_graphics::prelude::Point::new(x - ink_offset, y);
         embedded_graphics::Drawable::draw(
-            &embedded_graphics::text::Text::new(slice, position, style),
+            &embedded_graphics::text::Text::new(slice, glyph_position, style),
             frame,
         )
         .expect("drawing into frame cannot fail");
 
-        x += advance_x;
+        x += advance;
+    }
+
+    Point::new(x, y - baseline)
+}
+
+// The first inked column of `ch`'s glyph and the inked width, or `None` if the glyph is
+// blank.
+fn glyph_ink_columns(
+    font: &embedded_graphics::mono_font::MonoFont<'static>,
+    ch: char,
+) -> Option<(i32, i32)> {
+    use embedded_graphics::image::GetPixel;
+    use embedded_graphics::pixelcolor::BinaryColor;
+
+    let glyph_width = i32::try_from(font.character_size.width).ok()?;
+    let glyph_height = i32::try_from(font.character_size.height).ok()?;
+    let image_width = i32::try_from(font.image.size().width).ok()?;
+    if glyph_width == 0 || image_width < glyph_width {
+        return None;
+    }
+    // Glyphs are laid out left to right, then top to bottom, as in `MonoFont`.
+    let glyphs_per_row = image_width / glyph_width;
+    let glyph_index = i32::try_from(font.glyph_mapping.index(ch)).ok()?;
+    let glyph_left = (glyph_index % glyphs_per_row) * glyph_width;
+    let glyph_top = (glyph_index / glyphs_per_row) * glyph_height;
+
+    let is_inked = |column: i32| {
+        (0..glyph_height).any(|row| {
+            font.image
+                .pixel(Point::new(glyph_left + column, glyph_top + row))
+                == Some(BinaryColor::On)
+        })
+    };
+    let first_column = (0..glyph_width).find(|&column| is_inked(column))?;
+    let last_column = (0..glyph_width).rev().find(|&column| is_inked(column))?;
+    Some((first_column, last_column - first_column + 1))
+}
+
+#[doc(hidden)]
+/// Render text into a frame and return the smallest rectangle enclosing the lit
+/// (non-black) pixels the text drew, or `None` if it drew nothing visible.
+pub fn render_text_to_frame_with_bounds<const W: usize, const H: usize>(
+    frame: &mut Frame2d<W, H>,
+    font: &embedded_graphics::mono_font::MonoFont<'static>,
+    text: &str,
+    colors: &[RGB8],
+    spacing_reduction: (i32, i32),
+) -> Result<Option<Rectangle>> {
+    // Render on a blank frame first so pixels already lit in `frame` don't count.
+    let mut text_frame = Frame2d::<W, H>::new();
+    render_text_to_frame(
+        &mut text_frame,
+        font,
+        text,
+        colors,
+        spacing_reduction,
+        false,
+    )?;
+    render_text_to_frame(frame, font, text, colors, spacing_reduction, false)?;
+    Ok(lit_bounding_box(&text_frame))
+}
+
+#[doc(hidden)]
+/// Render text rotated 90° clockwise, so it reads normally when the panel is turned
+/// sideways (top of the panel to the left).
+///
+/// Characters run top-to-bottom and each character spans the panel's width. A `\n`
+/// starts a new column of characters to the left.
+///
+/// # Errors
+///
+/// Returns [`Error::TextDoesNotFit`](crate::Error::TextDoesNotFit) if the font's line
+/// height is larger than the panel width.
+pub fn render_text_to_frame_vertical<const W: usize, const H: usize>(
+    frame: &mut Frame2d<W, H>,
+    font: &embedded_graphics::mono_font::MonoFont<'static>,
+    text: &str,
+    colors: &[RGB8],
+    spacing_reduction: (i32, i32),
+) -> Result<()> {
+    let line_height = i32::try_from(font.character_size.height).expect("font height fits in i32")
+        - spacing_reduction.1;
+    if line_height > i32::try_from(W).expect("frame width fits in i32") {
+        return Err(crate::Error::TextDoesNotFit);
     }
 
+    // Lay the text out on a sideways frame (width and height swapped), then rotate it
+    // onto the panel.
+    let mut sideways_frame = Frame2d::<H, W>::new();
+    render_text_to_frame(
+        &mut sideways_frame,
+        font,
+        text,
+        colors,
+        spacing_reduction,
+        false,
+    )?;
+    let black = RGB8::new(0, 0, 0);
+    for (sideways_y_index, row) in sideways_frame.0.iter().enumerate() {
+        for (sideways_x_index, pixel) in row.iter().enumerate() {
+            if *pixel != black {
+                frame.0[sideways_x_index][W - 1 - sideways_y_index] = *pixel;
+            }
+        }
+    }
     Ok(())
 }
 
-/// Fonts available for use with [led2d module](mod@crate::led2d) panels.
+#[doc(hidden)]
+/// Number of frames in one pass of a scrolling marquee: the text enters from the right
+/// edge, crosses the panel, and fully leaves on the left.
+#[must_use]
+pub fn marquee_frame_count(
+    font: &embedded_graphics::mono_font::MonoFont<'static>,
+    text: &str,
+    spacing_reduction: (i32, i32),
+    width: usize,
+) -> usize {
+    let advance_x = usize::try_from(
+        i32::try_from(font.character_size.width).expect("font width fits in i32")
+            - spacing_reduction.0,
+    )
+    .unwrap_or(0);
+    text.chars().count() * advance_x + width
+}
+
+#[doc(hidden)]
+/// Render frame `frame_index` of a scrolling rainbow marquee.
 ///
-/// Fonts with `Trim` suffix remove blank spacing to pack text more tightly on small displays.
-#[derive(Clone, Copy, Debug)]
-pub enum Led2dFont {
+/// The text starts just past the right edge and moves one column left per frame. Lit
+/// pixels take their color from a hue wheel that spans the panel width and shifts by
+/// `hue_speed` steps (of 256 per turn) each frame, so letters shimmer as they move.
+pub fn render_rainbow_marquee_frame<const W: usize, const H: usize>(
+    frame: &mut Frame2d<W, H>,
+    font: &embedded_graphics::mono_font::MonoFont<'static>,
+    text: &str,
+    spacing_reduction: (i32, i32),
+    frame_index: usize,
+    hue_speed: u8,
+) {
+    let advance_x = i32::try_from(font.character_size.width).expect("font width fits in i32")
+        - spacing_reduction.0;
+    let width_limit = i32::try_from(W).expect("frame width fits in i32");
+    let frame_offset = i32::try_from(frame_index).unwrap_or(i32::MAX);
+    let style = embedded_graphics::mono_font::MonoTextStyle::new(
+        font,
+        smart_leds::colors::WHITE.to_rgb888(),
+    );
+    let baseline = i32::try_from(font.baseline).expect("font baseline fits in i32");
+
+    // Draw every glyph, even partly visible ones, so letters slide in and out smoothly.
+    let mut x = width_limit.saturating_sub(frame_offset);
+    for ch in text.chars() {
+        if x >= width_limit {
+            break;
+        }
+        if x + advance_x > 0 {
+            let mut buf = [0u8; 4];
+            let slice = ch.encode_utf8(&mut buf);
+            embedded_graphics::Drawable::draw(
+                &embedded_graphics::text::Text::new(slice, Point::new(x, baseline), style),
+                frame,
+            )
+            .expect("drawing into frame cannot fail");
+        }
+        x += advance_x;
+    }
+
+    let black = RGB8::new(0, 0, 0);
+    let time_hue = frame_index.wrapping_mul(usize::from(hue_speed));
+    for row in &mut frame.0 {
+        for (x_index, pixel) in row.iter_mut().enumerate() {
+            if *pixel != black {
+                let column_hue = x_index * 256 / W;
+                let hue = u8::try_from(time_hue.wrapping_add(column_hue) % 256)
+                    .expect("value below 256 fits in u8");
+                *pixel = hue_to_rgb8(hue);
+            }
+        }
+    }
+}
+
+#[doc(hidden)]
+/// Render one flame per column into `frame`, with each flame's base on the bottom row.
+pub fn render_fire_frame<const W: usize, const H: usize>(
+    frame: &mut Frame2d<W, H>,
+    columns: &[FireHeat<H>; W],
+) {
+    for (x_index, column) in columns.iter().enumerate() {
+        for (cell_index, heat) in column.heat().iter().enumerate() {
+            frame.0[H - 1 - cell_index][x_index] = heat_to_color(*heat);
+        }
+    }
+}
+
+/// Fully saturated color at `hue` on a 256-step color wheel (0 = red, ~85 = green,
+/// ~170 = blue).
+fn hue_to_rgb8(hue: u8) -> RGB8 {
+    let position = u16::from(hue) * 3;
+    let channel = |value: u16| u8::try_from(value).expect("wheel channel fits in u8");
+    match position {
+        0..255 => RGB8::new(channel(255 - position), channel(position), 0),
+        255..510 => RGB8::new(0, channel(510 - position), channel(position - 255)),
+        _ => RGB8::new(channel(position - 510), 0, channel(765 - position)),
+    }
+}
+
+fn lit_bounding_box<const W: usize, const H: usize>(frame: &Frame2d<W, H>) -> Option<Rectangle> {
+    let black = RGB8::new(0, 0, 0);
+    let mut corners: Option<(Point, Point)> = None;
+    for (y_index, row) in frame.0.iter().enumerate() {
+        for (x_index, pixel) in row.iter().enumerate() {
+            if *pixel == black {
+                continue;
+            }
+            let point = Point::new(
+                i32::try_from(x_index).expect("frame width fits in i32"),
+                i32::try_from(y_index).expect("frame height fits in i32"),
+            );
+            corners = Some(match corners {
+                None => (point, point),
+                Some((top_left, bottom_right)) => (
+                    top_left.component_min(point),
+                    bottom_right.component_max(point),
+                ),
+            });
+        }
+    }
+    corners.map(|(top_left, bottom_right)| Rectangle::with_corners(top_left, bottom_right))
+}
+
+/// Fonts available for use with [led2d module](mod@crate::led2d) panels.
+///
+/// Fonts with `Trim` suffix remove blank spacing to pack text more tightly on small displays.
+#[derive(Clone, Copy, Debug)]
+pub enum Led2dFont {
     /// 3x4 monospace font, trimmed (compact layout).
     Font3x4Trim,
     /// 4x6 monospace font.
@@ -488,7 +1303,9 @@ impl Led2dFont {
 /// - Coordinates are `(x, y)` with `(0, 0)` at the top-left. The x-axis increases to the right,
 ///   and the y-axis increases downward.
 /// - Set pixels using tuple indexing: `frame[(x, y)] = colors::RED;`.
-/// - For shapes, lines, and text rendering, use the [`embedded-graphics`](https://docs.rs/embedded-graphics) crate.
+/// - For filled rectangles and circles, use [`fill_rect`](Self::fill_rect) and
+///   [`fill_circle`](Self::fill_circle).
+/// - For other shapes, lines, and text rendering, use the [`embedded-graphics`](https://docs.rs/embedded-graphics) crate.
 /// - Frames are rendered by a panel type generated with [`led2d!`](macro@crate::led2d).
 ///   See [`Led2dGenerated`](crate::led2d::led2d_generated::Led2dGenerated) for the full API of the generated panel type.
 /// - For animation, call [`animate`](crate::led2d::led2d_generated::Led2dGenerated::animate) with a sequence
@@ -610,6 +1427,504 @@ impl<const W: usize, const H: usize> Frame2d<W, H> {
     pub const fn filled(color: RGB8) -> Self {
         Self([[color; W]; H])
     }
+
+    /// Create a checkerboard test pattern: `even_color` where `(col + row) % 2 == 0`,
+    /// including the top-left pixel, and `odd_color` elsewhere.
+    ///
+    /// Written to a panel, any mistake in its [`LedLayout`] shows up as a broken pattern,
+    /// such as stripes where rows or columns are reversed.
+    #[must_use]
+    pub const fn checkerboard(even_color: RGB8, odd_color: RGB8) -> Self {
+        let mut frame = Self::filled(even_color);
+        let mut row = 0;
+        while row < H {
+            let mut col = (row + 1) % 2;
+            while col < W {
+                frame.0[row][col] = odd_color;
+                col += 2;
+            }
+            row += 1;
+        }
+        frame
+    }
+
+    /// Flatten the frame row by row, top row first, into a strip frame.
+    ///
+    /// This matches the physical LED order only when the panel is wired row-major; check
+    /// with [`LedLayout::is_row_major`]. For any other wiring, use [`LedLayout::remap`].
+    /// [`Frame1d::as_frame2d_row_major`](crate::led_strip::Frame1d::as_frame2d_row_major)
+    /// reshapes it back.
+    ///
+    /// ```rust,no_run
+    /// # #![no_std]
+    /// # #![no_main]
+    /// # #[panic_handler]
+    /// # fn panic(_: &core::panic::PanicInfo) -> ! { loop {} }
+    /// use device_envoy::led2d::{Frame2d, layout::LedLayout};
+    /// use device_envoy::led_strip::{Frame1d, colors};
+    ///
+    /// const LAYOUT: LedLayout<6, 3, 2> =
+    ///     LedLayout::new([(0, 0), (1, 0), (2, 0), (0, 1), (1, 1), (2, 1)]);
+    /// const _: () = assert!(LAYOUT.is_row_major());
+    ///
+    /// let mut frame = Frame2d::<3, 2>::new();
+    /// frame[(0, 1)] = colors::RED;
+    /// let strip_frame: Frame1d<6> = frame.as_frame1d_row_major();
+    /// assert_eq!(strip_frame[3], colors::RED);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is not `W * H`. In a const context, this is a compile error.
+    #[must_use]
+    pub const fn as_frame1d_row_major<const N: usize>(&self) -> StripFrame<N> {
+        assert!(N == W * H, "N must equal W * H");
+        let mut strip_frame = StripFrame::new();
+        let mut led_index = 0;
+        while led_index < N {
+            strip_frame.0[led_index] = self.0[led_index / W][led_index % W];
+            led_index += 1;
+        }
+        strip_frame
+    }
+
+    /// Create a frame with a circular gradient from `center_color` at the frame center
+    /// to `edge_color` at the edge of the inscribed circle.
+    ///
+    /// Pixels outside the inscribed circle use `edge_color`. Swap the colors for a
+    /// gradient that brightens toward the edges.
+    ///
+    /// # Panics
+    ///
+    /// Panics only if the frame dimensions do not fit in `i64`.
+    #[must_use]
+    pub fn fill_gradient_radial(center_color: RGB8, edge_color: RGB8) -> Self {
+        // Work in half-pixel units so even-sized frames center between pixels.
+        let to_i64 = |value: usize| i64::try_from(value).expect("frame dimensions fit in i64");
+        let column_center2 = to_i64(W) - 1;
+        let row_center2 = to_i64(H) - 1;
+        let radius2 = u64::try_from(W.min(H)).expect("frame dimensions fit in u64");
+        let mut frame = Self::new();
+        if radius2 == 0 {
+            return frame;
+        }
+        for (y_index, row) in frame.0.iter_mut().enumerate() {
+            for (x_index, pixel) in row.iter_mut().enumerate() {
+                let column_offset2 = 2 * to_i64(x_index) - column_center2;
+                let row_offset2 = 2 * to_i64(y_index) - row_center2;
+                let distance2 = (column_offset2.unsigned_abs().pow(2)
+                    + row_offset2.unsigned_abs().pow(2))
+                .isqrt();
+                let amount = u8::try_from(distance2 * 255 / radius2).unwrap_or(u8::MAX);
+                *pixel = lerp_rgb8(center_color, edge_color, amount);
+            }
+        }
+        frame
+    }
+
+    /// Fill a rectangle with an LED color.
+    ///
+    /// Parts of the rectangle outside the frame are clipped. A shorthand for drawing an
+    /// [`embedded-graphics`](https://docs.rs/embedded-graphics) filled [`Rectangle`].
+    ///
+    /// See the [`Frame2d` struct example](Self) for drawing with embedded-graphics directly.
+    pub fn fill_rect(&mut self, rect: Rectangle, color: RGB8) {
+        // Drawing into a frame is infallible.
+        let Ok(()) = rect
+            .into_styled(PrimitiveStyle::with_fill(color.to_rgb888()))
+            .draw(self);
+    }
+
+    /// Fill a circle with an LED color.
+    ///
+    /// The circle is centered on `center` and `diameter` pixels across. Parts outside the
+    /// frame are clipped. A shorthand for drawing an
+    /// [`embedded-graphics`](https://docs.rs/embedded-graphics) filled `Circle`.
+    ///
+    /// See the [`Frame2d` struct example](Self) for drawing with embedded-graphics directly.
+    pub fn fill_circle(&mut self, center: Point, diameter: u32, color: RGB8) {
+        let Ok(()) = Circle::with_center(center, diameter)
+            .into_styled(PrimitiveStyle::with_fill(color.to_rgb888()))
+            .draw(self);
+    }
+
+    /// Set every pixel on the outermost rows and columns to `color`.
+    ///
+    /// Equivalent to [`draw_border_width`](Self::draw_border_width) with a width of 1.
+    ///
+    /// See the [`Frame2d` struct example](Self) for drawing with embedded-graphics directly.
+    pub fn draw_border(&mut self, color: RGB8) {
+        self.draw_border_width(color, 1);
+    }
+
+    /// Set the outer `width` rings of pixels to `color`.
+    ///
+    /// `width` is clamped to `min(W, H) / 2` so opposite sides never overlap; pixels
+    /// inside the border are left unchanged.
+    ///
+    /// See the [`Frame2d` struct example](Self) for drawing with embedded-graphics directly.
+    pub fn draw_border_width(&mut self, color: RGB8, width: usize) {
+        let width = width.min(W.min(H) / 2);
+        for (y_index, row) in self.0.iter_mut().enumerate() {
+            let row_depth = y_index.min(H - 1 - y_index);
+            for (x_index, pixel) in row.iter_mut().enumerate() {
+                let depth = row_depth.min(x_index).min(W - 1 - x_index);
+                if depth < width {
+                    *pixel = color;
+                }
+            }
+        }
+    }
+
+    /// Iterate over the rows from top to bottom, each as an array of `W` pixels.
+    pub fn row_iter(&self) -> impl Iterator<Item = &[RGB8; W]> {
+        self.0.iter()
+    }
+
+    /// Iterate mutably over the rows from top to bottom.
+    pub fn row_iter_mut(&mut self) -> impl Iterator<Item = &mut [RGB8; W]> {
+        self.0.iter_mut()
+    }
+
+    /// Iterate over the pixels of column `col` from top to bottom.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `col >= W`.
+    pub fn col_iter(&self, col: usize) -> impl Iterator<Item = &RGB8> {
+        assert!(col < W, "column index out of bounds");
+        self.0.iter().map(move |row| &row[col])
+    }
+
+    /// Iterate mutably over the pixels of column `col` from top to bottom.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `col >= W`.
+    pub fn col_iter_mut(&mut self, col: usize) -> impl Iterator<Item = &mut RGB8> {
+        assert!(col < W, "column index out of bounds");
+        self.0.iter_mut().map(move |row| &mut row[col])
+    }
+
+    /// Return the pixel at `(x, y)`, or `None` if it is outside the frame.
+    ///
+    /// Unlike indexing with `frame[(x, y)]`, this never panics, so effects that probe
+    /// neighboring pixels near the edges (for example, blur kernels) need no bounds
+    /// checks of their own. This shadows the slice `get` reached through `Deref`; use
+    /// `frame.0.get(y)` for a whole row.
+    #[must_use]
+    pub fn get(&self, x: usize, y: usize) -> Option<&RGB8> {
+        self.0.get(y)?.get(x)
+    }
+
+    /// Return a mutable reference to the pixel at `(x, y)`, or `None` if it is outside
+    /// the frame.
+    ///
+    /// See [`get`](Self::get).
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut RGB8> {
+        self.0.get_mut(y)?.get_mut(x)
+    }
+
+    /// Return `true` if every pixel matches the pixel at the same position in `other`.
+    ///
+    /// Compares the `r`, `g`, and `b` channels directly. `==` on frames uses this, so
+    /// host snapshot tests can write `assert_eq!(frame, expected)`.
+    #[must_use]
+    pub fn equal_to(&self, other: &Self) -> bool {
+        self.0
+            .iter()
+            .flatten()
+            .zip(other.0.iter().flatten())
+            .all(|(pixel, other_pixel)| {
+                pixel.r == other_pixel.r && pixel.g == other_pixel.g && pixel.b == other_pixel.b
+            })
+    }
+
+    /// Return a copy with every pixel's HSV saturation raised toward full by
+    /// `amount / 255`, for more vivid colors.
+    ///
+    /// Hue and value (the brightest channel) are kept, so the pattern and brightness are
+    /// unchanged. `amount` 0 leaves the frame as is; 255 makes every colored pixel fully
+    /// saturated. Gray pixels have no hue and stay gray. Integer math only.
+    #[must_use]
+    pub fn saturate(mut self, amount: u8) -> Self {
+        for pixel in self.0.iter_mut().flatten() {
+            let max = u32::from(pixel.r.max(pixel.g).max(pixel.b));
+            let chroma = max - u32::from(pixel.r.min(pixel.g).min(pixel.b));
+            if chroma > 0 {
+                let new_chroma = chroma + (max - chroma) * u32::from(amount) / 255;
+                *pixel = scale_chroma(*pixel, new_chroma, chroma);
+            }
+        }
+        self
+    }
+
+    /// Return a copy with every pixel's HSV saturation scaled to `factor / 255`, for a
+    /// washed-out, "inactive" look.
+    ///
+    /// Hue and value (the brightest channel) are kept, so the pattern stays visible and
+    /// [`saturate`](Self::saturate) can bring back vivid colors later. `factor` 255
+    /// leaves the frame as is; 0 turns it to grayscale, with each pixel set to the value of
+    /// its brightest channel. Integer math only.
+    #[must_use]
+    pub fn desaturate(mut self, factor: u8) -> Self {
+        for pixel in self.0.iter_mut().flatten() {
+            *pixel = scale_chroma(*pixel, u32::from(factor), 255);
+        }
+        self
+    }
+
+    /// Soften the frame with a box blur: each pixel becomes the average of the
+    /// `(2 * radius + 1)²` pixels around it, per channel.
+    ///
+    /// The blur runs as separate horizontal and vertical passes with running sums, so the
+    /// cost does not grow with `radius`, and uses integer math only. Pixels past the edges
+    /// repeat the nearest edge pixel. `radius` 0 leaves the frame unchanged; a radius
+    /// larger than the frame's longer side is clamped to it.
+    ///
+    /// Blurring bright text on a dim background turns hard edges into a soft glow on
+    /// diffused panels.
+    pub fn blur(&mut self, radius: usize) {
+        let radius = radius.min(W.max(H));
+        if radius == 0 {
+            return;
+        }
+        for row in &mut self.0 {
+            box_blur_line(row, radius);
+        }
+        for x_index in 0..W {
+            let mut column: [RGB8; H] = core::array::from_fn(|y_index| self.0[y_index][x_index]);
+            box_blur_line(&mut column, radius);
+            for (pixel, blurred) in self.col_iter_mut(x_index).zip(column) {
+                *pixel = blurred;
+            }
+        }
+    }
+
+    /// Blend toward `other`, per channel: `amount` 0 gives this frame, 255 gives `other`.
+    #[must_use]
+    pub fn lerp(mut self, other: Self, amount: u8) -> Self {
+        for (pixel, other_pixel) in self.0.iter_mut().flatten().zip(other.0.iter().flatten()) {
+            *pixel = lerp_rgb8(*pixel, *other_pixel, amount);
+        }
+        self
+    }
+
+    /// Tween through `keyframes` in a loop: each keyframe blends into the next over
+    /// `steps_per_transition` frames, and the last blends back into the first.
+    ///
+    /// Each transition starts with its keyframe, and its blend amount follows `easing`.
+    /// Yields `keyframes.len() * steps_per_transition` frames; pass them to
+    /// `animate_uniform`, which needs them to fit in the panel's `MAX_FRAMES`.
+    ///
+    /// ```rust
+    /// # use device_envoy::led2d::{Easing, Frame2d};
+    /// # use smart_leds::colors;
+    /// let keyframes = [Frame2d::<4, 3>::filled(colors::RED), Frame2d::filled(colors::BLUE)];
+    /// let frames = Frame2d::tween(&keyframes, 8, Easing::EaseInOut);
+    /// assert_eq!(frames.count(), 16);
+    /// ```
+    pub fn tween(
+        keyframes: &[Self],
+        steps_per_transition: usize,
+        easing: Easing,
+    ) -> impl Iterator<Item = Self> + '_ {
+        keyframes
+            .iter()
+            .zip(keyframes.iter().cycle().skip(1))
+            .flat_map(move |(from, to)| {
+                (0..steps_per_transition).map(move |step| {
+                    let amount = u8::try_from(step * 255 / steps_per_transition).unwrap_or(u8::MAX);
+                    from.lerp(*to, easing.apply(amount))
+                })
+            })
+    }
+}
+
+#[cfg(feature = "host")]
+impl<const W: usize, const H: usize> Frame2d<W, H> {
+    /// Return the luminance-weighted centroid `(x, y)` of the frame, or `None` if every
+    /// pixel is black.
+    ///
+    /// Each pixel counts with its Rec. 709 luminance, so a dim pixel pulls the centroid
+    /// less than a bright one. Coordinates are pixel indexes, so a centered shape on a
+    /// 12×4 frame has its centroid at `(5.5, 1.5)`. Host-only, for tests such as checking
+    /// that text is visually centered.
+    #[must_use]
+    pub fn center_of_mass(&self) -> Option<(f32, f32)> {
+        let mut total_luminance = 0.0_f32;
+        let mut x_sum = 0.0_f32;
+        let mut y_sum = 0.0_f32;
+        for (y_index, row) in self.0.iter().enumerate() {
+            for (x_index, pixel) in row.iter().enumerate() {
+                let luminance = 0.0722_f32.mul_add(
+                    f32::from(pixel.b),
+                    0.2126_f32.mul_add(f32::from(pixel.r), 0.7152 * f32::from(pixel.g)),
+                );
+                total_luminance += luminance;
+                x_sum = luminance.mul_add(index_to_f32(x_index), x_sum);
+                y_sum = luminance.mul_add(index_to_f32(y_index), y_sum);
+            }
+        }
+        (total_luminance > 0.0).then(|| (x_sum / total_luminance, y_sum / total_luminance))
+    }
+
+    /// Return the perceptual luminance of each pixel as a grayscale `[[u8; W]; H]`.
+    ///
+    /// Each entry is `(299 * r + 587 * g + 114 * b) / 1000` (Rec. 601 weights, integer
+    /// math), indexed `[y][x]` like the frame itself. Host-only, for analysis such as
+    /// brightness checks or edge detection.
+    #[must_use]
+    pub fn luminance(&self) -> [[u8; W]; H] {
+        self.0.map(|row| row.map(pixel_luminance))
+    }
+
+    /// Return the mean [`luminance`](Self::luminance) over all pixels, rounded down.
+    ///
+    /// Returns 0 for a frame with no pixels.
+    #[must_use]
+    pub fn average_luminance(&self) -> u8 {
+        let pixel_count = W * H;
+        if pixel_count == 0 {
+            return 0;
+        }
+        let total: usize = self
+            .0
+            .iter()
+            .flatten()
+            .map(|pixel| usize::from(pixel_luminance(*pixel)))
+            .sum();
+        // The mean of u8 values always fits in u8.
+        u8::try_from(total / pixel_count).unwrap_or(u8::MAX)
+    }
+
+    /// Return a frame that is red where `self` and `other` differ and black where they
+    /// match.
+    ///
+    /// Host-only, for debugging a failed [`equal_to`](Self::equal_to) comparison: render
+    /// or print the result to see which pixels changed.
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Self {
+        let mut result = Self::new();
+        for ((result_row, row), other_row) in result.0.iter_mut().zip(&self.0).zip(&other.0) {
+            for ((result_pixel, pixel), other_pixel) in
+                result_row.iter_mut().zip(row).zip(other_row)
+            {
+                if pixel != other_pixel {
+                    *result_pixel = RGB8::new(255, 0, 0);
+                }
+            }
+        }
+        result
+    }
+
+    /// Return the largest [`luminance`](Self::luminance) of any pixel.
+    ///
+    /// Returns 0 for a frame with no pixels.
+    #[must_use]
+    pub fn max_luminance(&self) -> u8 {
+        self.0
+            .iter()
+            .flatten()
+            .map(|pixel| pixel_luminance(*pixel))
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+#[cfg(feature = "host")]
+fn pixel_luminance(pixel: RGB8) -> u8 {
+    let weighted = u32::from(pixel.r) * 299 + u32::from(pixel.g) * 587 + u32::from(pixel.b) * 114;
+    u8::try_from(weighted / 1000).expect("weights sum to 1000, so luminance fits in u8")
+}
+
+#[cfg(feature = "host")]
+#[expect(
+    clippy::cast_precision_loss,
+    reason = "pixel indexes are far below f32's exact integer range"
+)]
+const fn index_to_f32(index: usize) -> f32 {
+    index as f32
+}
+
+/// Scale a pixel's HSV saturation by `numerator / denominator`, keeping its hue and
+/// value: each channel's distance below the brightest channel is scaled by that ratio.
+/// The scaled chroma must not exceed the brightest channel.
+fn scale_chroma(pixel: RGB8, numerator: u32, denominator: u32) -> RGB8 {
+    let max = u32::from(pixel.r.max(pixel.g).max(pixel.b));
+    let scale_channel = |channel: u8| -> u8 {
+        let gap = (max - u32::from(channel)) * numerator / denominator;
+        u8::try_from(max - gap).expect("scaled channel stays between 0 and the brightest")
+    };
+    RGB8::new(
+        scale_channel(pixel.r),
+        scale_channel(pixel.g),
+        scale_channel(pixel.b),
+    )
+}
+
+/// `sin(fraction × 90°) × 1000` at each tenth of a quarter turn.
+const QUARTER_SINE_PERMILLE: [usize; 11] = [0, 156, 309, 454, 588, 707, 809, 891, 951, 988, 1000];
+
+/// Integer approximation of `sin(amount / 255 × 90°) × 255`, for `amount` in `0..=255`,
+/// by linear interpolation in [`QUARTER_SINE_PERMILLE`].
+fn quarter_sine(amount: usize) -> usize {
+    let fraction_permille = amount * 1000 / 255;
+    let segment = fraction_permille / 100;
+    let within = fraction_permille % 100;
+    let low = QUARTER_SINE_PERMILLE[segment.min(10)];
+    let high = QUARTER_SINE_PERMILLE[(segment + 1).min(10)];
+    (low + (high - low) * within / 100) * 255 / 1000
+}
+
+/// Linearly interpolate between two colors; `amount` 0 gives `start`, 255 gives `end`.
+fn lerp_rgb8(start: RGB8, end: RGB8, amount: u8) -> RGB8 {
+    let lerp_channel = |start: u8, end: u8| -> u8 {
+        let amount = u16::from(amount);
+        let mixed = (u16::from(start) * (255 - amount) + u16::from(end) * amount) / 255;
+        u8::try_from(mixed).expect("weighted average of u8 values fits in u8")
+    };
+    RGB8::new(
+        lerp_channel(start.r, end.r),
+        lerp_channel(start.g, end.g),
+        lerp_channel(start.b, end.b),
+    )
+}
+
+/// Replace each pixel of `line` with the rounded average of the `2 * radius + 1` pixels
+/// centered on it, repeating the end pixels past either end. Uses a running sum.
+fn box_blur_line<const LEN: usize>(line: &mut [RGB8; LEN], radius: usize) {
+    let Some(last) = LEN.checked_sub(1) else {
+        return;
+    };
+    let source = *line;
+    let window = 2 * radius + 1;
+    let channels = |pixel: RGB8| {
+        [
+            usize::from(pixel.r),
+            usize::from(pixel.g),
+            usize::from(pixel.b),
+        ]
+    };
+    let average = |sum: usize| -> u8 {
+        u8::try_from((sum + window / 2) / window).expect("average of u8 values fits in u8")
+    };
+
+    let mut sums = [0_usize; 3];
+    for offset in 0..window {
+        let entering = channels(source[offset.saturating_sub(radius).min(last)]);
+        for (sum, channel) in sums.iter_mut().zip(entering) {
+            *sum += channel;
+        }
+    }
+    for (index, pixel) in line.iter_mut().enumerate() {
+        *pixel = RGB8::new(average(sums[0]), average(sums[1]), average(sums[2]));
+        let entering = channels(source[(index + radius + 1).min(last)]);
+        let leaving = channels(source[index.saturating_sub(radius)]);
+        for ((sum, entering), leaving) in sums.iter_mut().zip(entering).zip(leaving) {
+            *sum = *sum + entering - leaving;
+        }
+    }
 }
 
 impl<const W: usize, const H: usize> Deref for Frame2d<W, H> {
@@ -656,129 +1971,806 @@ impl<const W: usize, const H: usize> From<Frame2d<W, H>> for [[RGB8; W]; H] {
     }
 }
 
+impl<const W: usize, const H: usize> PartialEq for Frame2d<W, H> {
+    fn eq(&self, other: &Self) -> bool {
+        self.equal_to(other)
+    }
+}
+
+impl<const W: usize, const H: usize> Eq for Frame2d<W, H> {}
+
 impl<const W: usize, const H: usize> Default for Frame2d<W, H> {
     fn default() -> Self {
         Self::new()
     }
 }
 
+/// Logs a one-line summary rather than every pixel, such as
+/// `Frame2d<12x4>: 3 of 48 lit, first at (2, 0) = (255, 0, 0), checksum 0x5d2e8a41`.
+///
+/// Positions are `(x, y)`. The checksum tells frames with the same lit count apart. Use
+/// [`verbose`](Frame2d::verbose) to log every pixel.
+impl<const W: usize, const H: usize> defmt::Format for Frame2d<W, H> {
+    fn format(&self, fmt: defmt::Formatter<'_>) {
+        let summary = FrameSummary::new(self.0.as_flattened());
+        defmt::write!(fmt, "Frame2d<{=usize}x{=usize}>: ", W, H);
+        match summary.first_lit {
+            None => defmt::write!(fmt, "all black"),
+            Some((pixel_index, color)) => defmt::write!(
+                fmt,
+                "{=usize} of {=usize} lit, first at ({=usize}, {=usize}) = ({=u8}, {=u8}, {=u8}), checksum {=u32:#010x}",
+                summary.lit_count,
+                W * H,
+                pixel_index % W,
+                pixel_index / W,
+                color.r,
+                color.g,
+                color.b,
+                summary.checksum
+            ),
+        }
+    }
+}
+
+impl<const W: usize, const H: usize> Frame2d<W, H> {
+    /// Wrap the frame so `defmt` logs every pixel, one row per line, instead of a summary.
+    ///
+    /// A large panel floods RTT, so use this for small panels or one-off debugging:
+    /// `defmt::info!("{}", frame.verbose())`.
+    #[must_use]
+    pub fn verbose(&self) -> impl defmt::Format + '_ {
+        VerboseFrame2d(self)
+    }
+}
+
+struct VerboseFrame2d<'a, const W: usize, const H: usize>(&'a Frame2d<W, H>);
+
+impl<const W: usize, const H: usize> defmt::Format for VerboseFrame2d<'_, W, H> {
+    fn format(&self, fmt: defmt::Formatter<'_>) {
+        defmt::write!(fmt, "Frame2d<{=usize}x{=usize}>", W, H);
+        for row in &self.0.0 {
+            defmt::write!(fmt, "\n  ");
+            write_colors(fmt, row);
+        }
+    }
+}
+
 impl<const W: usize, const H: usize> OriginDimensions for Frame2d<W, H> {
     fn size(&self) -> Size {
         Size::new(W as u32, H as u32)
     }
-}
+}
+
+impl<const W: usize, const H: usize> DrawTarget for Frame2d<W, H> {
+    type Color = Rgb888;
+    type Error = Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> core::result::Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for Pixel(coord, color) in pixels {
+            let x_index = coord.x;
+            let y_index = coord.y;
+            if x_index >= 0 && x_index < W as i32 && y_index >= 0 && y_index < H as i32 {
+                self.0[y_index as usize][x_index as usize] =
+                    RGB8::new(color.r(), color.g(), color.b());
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Panel corner that frame coordinate `(0, 0)` maps to, set with the `origin` field of
+/// [`led2d!`](macro@crate::led2d).
+///
+/// With the default [`TopLeft`](Self::TopLeft), x grows to the right and y grows downward.
+/// Other corners mirror the frame horizontally, vertically, or both before it reaches the
+/// LEDs, so drawing code can keep using top-left terms while the panel is viewed from
+/// another corner. The LED layout itself is unchanged.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, defmt::Format)]
+pub enum Corner {
+    /// `(0, 0)` is the top-left LED (default).
+    #[default]
+    TopLeft,
+    /// `(0, 0)` is the top-right LED; x grows to the left.
+    TopRight,
+    /// `(0, 0)` is the bottom-left LED; y grows upward.
+    BottomLeft,
+    /// `(0, 0)` is the bottom-right LED; x grows to the left and y grows upward.
+    BottomRight,
+}
+
+impl Corner {
+    const fn is_right(self) -> bool {
+        matches!(self, Self::TopRight | Self::BottomRight)
+    }
+
+    const fn is_bottom(self) -> bool {
+        matches!(self, Self::BottomLeft | Self::BottomRight)
+    }
+}
+
+/// How the blend between two keyframes progresses over a transition; see
+/// [`Frame2d::tween`].
+///
+/// The eased curves follow a sine shape, computed with integer math only.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, defmt::Format)]
+pub enum Easing {
+    /// Blend at a constant rate (default).
+    #[default]
+    Linear,
+    /// Start slowly, then speed up.
+    EaseIn,
+    /// Start quickly, then slow down.
+    EaseOut,
+    /// Start and end slowly.
+    EaseInOut,
+}
+
+impl Easing {
+    /// Map linear progress `amount` (0 to 255) onto this curve. 0 and 255 are unchanged.
+    #[must_use]
+    pub fn apply(self, amount: u8) -> u8 {
+        let amount = usize::from(amount);
+        let eased = match self {
+            Self::Linear => amount,
+            Self::EaseIn => 255 - quarter_sine(255 - amount),
+            Self::EaseOut => quarter_sine(amount),
+            Self::EaseInOut if amount < 128 => (255 - quarter_sine(255 - 2 * amount)) / 2,
+            Self::EaseInOut => usize::midpoint(255, quarter_sine(2 * amount - 255)),
+        };
+        u8::try_from(eased).unwrap_or(u8::MAX)
+    }
+}
+
+// Must be `pub` (not `pub(crate)`) because called by macro-generated code that expands at the call site in downstream crates.
+// This is an implementation detail, not part of the user-facing API.
+#[doc(hidden)]
+/// A device abstraction for rectangular NeoPixel-style (WS2812) LED matrix displays.
+///
+/// Supports any size display with arbitrary LED-index-to-coordinate mapping. The provided mapping
+/// is reversed during initialization into an internal (row, col) → LED index lookup so frame
+/// conversion stays fast.
+///
+/// Rows and columns are metadata used only for indexing - the core type is generic only over
+/// N (total LEDs) and MAX_FRAMES (animation capacity).
+///
+/// Most users should use the `led2d!` or `led2d_from_strip!` macros which generate
+/// a higher-level wrapper. See the [led2d](mod@crate::led2d) module docs for examples.
+pub struct Led2d<const N: usize, const MAX_FRAMES: usize> {
+    led_strip: &'static LedStrip<N, MAX_FRAMES>,
+    mapping_by_xy: [u16; N],
+    width: usize,
+    origin: Corner,
+}
+
+impl<const N: usize, const MAX_FRAMES: usize> Led2d<N, MAX_FRAMES> {
+    /// Create Led2d device handle.
+    ///
+    /// The `led_layout` defines how LED indices map to `(column, row)` coordinates. Entry `i`
+    /// provides the `(col, row)` destination for LED `i`. The layout is inverted via
+    /// [`LedLayout::xy_to_index`] so (row, col) queries are O(1) when converting frames.
+    ///
+    /// See the [Led2d struct example](Self) for usage.
+    #[must_use]
+    pub fn new<const W: usize, const H: usize>(
+        led_strip: &'static LedStrip<N, MAX_FRAMES>,
+        led_layout: &LedLayout<N, W, H>,
+    ) -> Self {
+        assert_eq!(
+            W.checked_mul(H).expect("width * height must fit in usize"),
+            N,
+            "width * height must equal N (total LEDs for led_layout reversal)"
+        );
+        Self {
+            led_strip,
+            mapping_by_xy: led_layout.xy_to_index(),
+            width: W,
+            origin: Corner::TopLeft,
+        }
+    }
+
+    /// Return this handle with frame coordinate `(0, 0)` mapped to the `origin` corner.
+    ///
+    /// See [`Corner`] for how each corner mirrors frames.
+    #[must_use]
+    pub const fn with_origin(mut self, origin: Corner) -> Self {
+        self.origin = origin;
+        self
+    }
+
+    /// Convert (column, row) coordinates to LED strip index using the stored LED layout.
+    #[must_use]
+    fn xy_to_index(&self, x_index: usize, y_index: usize) -> usize {
+        self.mapping_by_xy[y_index * self.width + x_index] as usize
+    }
+
+    /// Convert 2D frame to 1D array using the LED layout.
+    fn convert_frame<const W: usize, const H: usize>(
+        &self,
+        frame_2d: Frame2d<W, H>,
+    ) -> StripFrame<N> {
+        self.convert_region(&frame_2d, 0, 0)
+    }
+
+    /// Convert the panel-sized region of a (possibly larger) 2D frame whose top-left corner
+    /// is at (`x_offset`, `y_offset`) to a 1D array using the LED layout.
+    fn convert_region<const W: usize, const H: usize>(
+        &self,
+        frame_2d: &Frame2d<W, H>,
+        x_offset: usize,
+        y_offset: usize,
+    ) -> StripFrame<N> {
+        let mut frame_1d = [RGB8::new(0, 0, 0); N];
+        let height = N / self.width;
+        for y_index in 0..height {
+            let source_y = if self.origin.is_bottom() {
+                height - 1 - y_index
+            } else {
+                y_index
+            };
+            for x_index in 0..self.width {
+                let source_x = if self.origin.is_right() {
+                    self.width - 1 - x_index
+                } else {
+                    x_index
+                };
+                let led_index = self.xy_to_index(x_index, y_index);
+                frame_1d[led_index] = frame_2d[(x_offset + source_x, y_offset + source_y)];
+            }
+        }
+        StripFrame::from(frame_1d)
+    }
+
+    /// Render a fully defined frame to the panel.
+    ///
+    /// Frame2d is a 2D array in row-major order where `frame[(col, row)]` is the pixel at (col, row).
+    pub fn write_frame<const W: usize, const H: usize>(&self, frame: Frame2d<W, H>) -> Result<()> {
+        self.write_frame_ref(&frame)
+    }
+
+    /// Like [`write_frame`](Self::write_frame), but reads the frame in place instead of
+    /// taking a copy.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from the underlying strip.
+    pub fn write_frame_ref<const W: usize, const H: usize>(
+        &self,
+        frame: &Frame2d<W, H>,
+    ) -> Result<()> {
+        let strip_frame = self.convert_region(frame, 0, 0);
+        self.led_strip.write_frame(strip_frame)
+    }
+
+    /// Loop through a sequence of animation frames until interrupted by another command.
+    ///
+    /// Each frame is a tuple of `(Frame2d, Duration)`. Accepts arrays, `Vec`s, or any
+    /// iterator that produces `(Frame2d, Duration)` tuples. For best efficiency with large
+    /// frame sequences, pass an iterator to avoid intermediate allocations.
+    ///
+    /// Returns immediately; the animation runs in the background until interrupted
+    /// by a new `animate` call or `write_frame`.
+    pub fn animate<const W: usize, const H: usize, I>(&self, frames: I) -> Result<()>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<(Frame2d<W, H>, Duration)>,
+    {
+        self.led_strip.animate(frames.into_iter().map(|frame| {
+            let (frame, duration) = *frame.borrow();
+            (self.convert_frame(frame), duration)
+        }))
+    }
+
+    /// Like [`animate`](Self::animate), but shows every frame for the same
+    /// `frame_duration`.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from the underlying strip.
+    pub fn animate_uniform<const W: usize, const H: usize, I>(
+        &self,
+        frames: I,
+        frame_duration: Duration,
+    ) -> Result<()>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Frame2d<W, H>>,
+    {
+        self.led_strip.animate(
+            frames
+                .into_iter()
+                .map(|frame| (self.convert_frame(*frame.borrow()), frame_duration)),
+        )
+    }
+
+    /// Cross-fade from `from` to `to` over `steps` frames, each shown for `step_duration`.
+    ///
+    /// Frame `i` is [`from.lerp(to, t)`](Frame2d::lerp), with `t` rising in equal
+    /// increments from 0 (the first frame is `from`) to 255 (the last is `to`). Like every
+    /// animation, the fade loops; to stay on `to`, write it with
+    /// [`write_frame`](Self::write_frame) once the fade has played. `steps` must not exceed
+    /// `MAX_FRAMES`.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from the underlying strip.
+    pub fn animate_crossfade<const W: usize, const H: usize>(
+        &self,
+        from: Frame2d<W, H>,
+        to: Frame2d<W, H>,
+        steps: u8,
+        step_duration: Duration,
+    ) -> Result<()> {
+        debug_assert!(
+            usize::from(steps) <= MAX_FRAMES,
+            "crossfade steps must not exceed MAX_FRAMES"
+        );
+        let last_step = u16::from(steps.saturating_sub(1)).max(1);
+        self.animate_uniform(
+            (0..u16::from(steps)).map(|step| {
+                let amount = u8::try_from(step * 255 / last_step).unwrap_or(u8::MAX);
+                from.lerp(to, amount)
+            }),
+            step_duration,
+        )
+    }
+
+    /// Like [`animate`](Self::animate), but the animation starts only when `trigger` is
+    /// signaled. See
+    /// [`LedStripHandle::animate_on_signal`](crate::led_strip::LedStripHandle::animate_on_signal).
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from the underlying strip.
+    #[cfg(not(feature = "host"))]
+    pub fn animate_on_signal<const W: usize, const H: usize, I>(
+        &self,
+        frames: I,
+        trigger: &'static crate::led_strip::AnimationTrigger,
+    ) -> Result<()>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<(Frame2d<W, H>, Duration)>,
+    {
+        self.led_strip.animate_on_signal(
+            frames.into_iter().map(|frame| {
+                let (frame, duration) = *frame.borrow();
+                (self.convert_frame(frame), duration)
+            }),
+            trigger,
+        )
+    }
+
+    /// Display a strip-ordered frame from static memory without copying it.
+    ///
+    /// The frame must already apply the LED layout; build it with [`LedLayout::remap`].
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from the underlying strip.
+    pub fn write_static_frame(&self, frame: &'static StripFrame<N>) -> Result<()> {
+        self.led_strip.write_static_frame(frame)
+    }
+
+    /// Loop through strip-ordered frames from static memory without copying them.
+    ///
+    /// The frames must already apply the LED layout; build them with [`LedLayout::remap`].
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from the underlying strip.
+    pub fn animate_static_frames(
+        &self,
+        frames: &'static [(StripFrame<N>, Duration)],
+    ) -> Result<()> {
+        self.led_strip.animate_static_frames(frames)
+    }
+
+    /// Loop through `frame_count` strip-ordered frames computed on demand by `generate`.
+    ///
+    /// `generate` must already apply the LED layout; the macro-generated types wrap a
+    /// `Frame2d` generator with [`LedLayout::remap`].
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from the underlying strip.
+    pub fn animate_generated(
+        &self,
+        frame_count: usize,
+        frame_duration: Duration,
+        generate: fn(usize) -> StripFrame<N>,
+    ) -> Result<()> {
+        self.led_strip
+            .animate_generated(frame_count, frame_duration, generate)
+    }
+
+    /// Returns `true` while the panel is running an animation or finishing a command.
+    ///
+    /// See [`LedStripHandle::is_animating`](crate::led_strip::LedStripHandle::is_animating)
+    /// for details.
+    #[must_use]
+    pub fn is_animating(&self) -> bool {
+        self.led_strip.is_animating()
+    }
+
+    /// Wait until the panel shows a static frame, with no animation running and no
+    /// command pending.
+    ///
+    /// See [`LedStripHandle::wait_idle`](crate::led_strip::LedStripHandle::wait_idle) for
+    /// details.
+    pub async fn wait_idle(&self) {
+        self.led_strip.wait_idle().await;
+    }
+
+    /// Turn the panel off and stop its strip's background task. Later commands are
+    /// ignored.
+    pub fn shutdown(&self) {
+        self.led_strip.shutdown();
+    }
+}
+
+// Public so led2d! expansions in downstream crates can name it; hidden from docs.
+#[doc(hidden)]
+/// Holds the `Frame2d` generator for a generated panel type's `animate_generated`, so the
+/// strip's device task can reach it through a plain function pointer.
+#[cfg(not(feature = "host"))]
+pub struct Led2dGenerator<const W: usize, const H: usize>(
+    embassy_sync::blocking_mutex::Mutex<
+        embassy_sync::blocking_mutex::raw::CriticalSectionRawMutex,
+        core::cell::Cell<Option<fn(usize) -> Frame2d<W, H>>>,
+    >,
+);
+
+#[cfg(not(feature = "host"))]
+impl<const W: usize, const H: usize> Led2dGenerator<W, H> {
+    /// Create an empty generator slot.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(embassy_sync::blocking_mutex::Mutex::new(
+            core::cell::Cell::new(None),
+        ))
+    }
+
+    /// Store the generator used by subsequent frames.
+    pub fn set(&self, generate: fn(usize) -> Frame2d<W, H>) {
+        self.0.lock(|cell| cell.set(Some(generate)));
+    }
+
+    /// Compute frame `frame_index` with the stored generator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called before [`set`](Self::set).
+    #[must_use]
+    pub fn generate(&self, frame_index: usize) -> Frame2d<W, H> {
+        let generate = self
+            .0
+            .lock(core::cell::Cell::get)
+            .expect("generator is set before the animation starts");
+        generate(frame_index)
+    }
+}
+
+// Public so led2d! expansions in downstream crates can name it; hidden from docs.
+#[doc(hidden)]
+/// Holds the text and settings for a generated panel type's `scroll_rainbow_text`, so the
+/// strip's device task can render marquee frames through a plain function pointer.
+#[cfg(not(feature = "host"))]
+pub struct Led2dMarquee<const W: usize, const H: usize>(
+    embassy_sync::blocking_mutex::Mutex<
+        embassy_sync::blocking_mutex::raw::CriticalSectionRawMutex,
+        core::cell::Cell<Option<(&'static str, Led2dFont, u8)>>,
+    >,
+);
+
+#[cfg(not(feature = "host"))]
+impl<const W: usize, const H: usize> Led2dMarquee<W, H> {
+    /// Create an empty marquee slot.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(embassy_sync::blocking_mutex::Mutex::new(
+            core::cell::Cell::new(None),
+        ))
+    }
+
+    /// Store the text, font, and hue speed used by subsequent frames, and return the
+    /// number of frames in one pass.
+    pub fn set(&self, text: &'static str, font_variant: Led2dFont, hue_speed: u8) -> usize {
+        self.0
+            .lock(|cell| cell.set(Some((text, font_variant, hue_speed))));
+        marquee_frame_count(
+            &font_variant.to_font(),
+            text,
+            font_variant.spacing_reduction(),
+            W,
+        )
+    }
+
+    /// Render marquee frame `frame_index` with the stored settings.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called before [`set`](Self::set).
+    #[must_use]
+    pub fn generate(&self, frame_index: usize) -> Frame2d<W, H> {
+        let (text, font_variant, hue_speed) = self
+            .0
+            .lock(core::cell::Cell::get)
+            .expect("marquee is set before the animation starts");
+        let mut frame = Frame2d::new();
+        render_rainbow_marquee_frame(
+            &mut frame,
+            &font_variant.to_font(),
+            text,
+            font_variant.spacing_reduction(),
+            frame_index,
+            hue_speed,
+        );
+        frame
+    }
+}
+
+// Public so led2d! expansions in downstream crates can name it; hidden from docs.
+#[doc(hidden)]
+/// Holds the per-column flames and settings for a generated panel type's `fire`, so the
+/// strip's device task can render fire frames through a plain function pointer.
+#[cfg(not(feature = "host"))]
+pub struct Led2dFire<const W: usize, const H: usize>(
+    embassy_sync::blocking_mutex::Mutex<
+        embassy_sync::blocking_mutex::raw::CriticalSectionRawMutex,
+        core::cell::RefCell<([FireHeat<H>; W], u8, u8)>,
+    >,
+);
+
+#[cfg(not(feature = "host"))]
+impl<const W: usize, const H: usize> Led2dFire<W, H> {
+    /// Create a cold fire.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(embassy_sync::blocking_mutex::Mutex::new(
+            core::cell::RefCell::new(([FireHeat::new(0); W], 0, 0)),
+        ))
+    }
 
-impl<const W: usize, const H: usize> DrawTarget for Frame2d<W, H> {
-    type Color = Rgb888;
-    type Error = Infallible;
+    /// Restart the flames with fresh seeds and store the settings used by subsequent frames.
+    pub fn set(&self, cooling: u8, sparking: u8) {
+        let seed = crate::led_strip::fire_seed();
+        self.0.lock(|cell| {
+            let mut columns = [FireHeat::new(0); W];
+            for (seed_offset, column) in (0u32..).zip(columns.iter_mut()) {
+                *column = FireHeat::new(seed ^ seed_offset.wrapping_mul(0x9E37_79B9));
+            }
+            *cell.borrow_mut() = (columns, cooling, sparking);
+        });
+    }
 
-    fn draw_iter<I>(&mut self, pixels: I) -> core::result::Result<(), Self::Error>
-    where
-        I: IntoIterator<Item = Pixel<Self::Color>>,
-    {
-        for Pixel(coord, color) in pixels {
-            let x_index = coord.x;
-            let y_index = coord.y;
-            if x_index >= 0 && x_index < W as i32 && y_index >= 0 && y_index < H as i32 {
-                self.0[y_index as usize][x_index as usize] =
-                    RGB8::new(color.r(), color.g(), color.b());
+    /// Advance every flame by one frame and render the result.
+    #[must_use]
+    pub fn generate(&self, _frame_index: usize) -> Frame2d<W, H> {
+        self.0.lock(|cell| {
+            let (columns, cooling, sparking) = &mut *cell.borrow_mut();
+            for column in columns.iter_mut() {
+                column.step(*cooling, *sparking);
             }
-        }
-        Ok(())
+            let mut frame = Frame2d::new();
+            render_fire_frame(&mut frame, columns);
+            frame
+        })
     }
 }
 
-// Must be `pub` (not `pub(crate)`) because called by macro-generated code that expands at the call site in downstream crates.
-// This is an implementation detail, not part of the user-facing API.
+// Public so led2d! expansions in downstream crates can name it; hidden from docs.
 #[doc(hidden)]
-/// A device abstraction for rectangular NeoPixel-style (WS2812) LED matrix displays.
+/// The reusable frame a generated panel type's `with_frame` lends out for drawing.
+#[cfg(not(feature = "host"))]
+pub struct Led2dFrameBuffer<const W: usize, const H: usize>(
+    embassy_sync::blocking_mutex::Mutex<
+        embassy_sync::blocking_mutex::raw::CriticalSectionRawMutex,
+        core::cell::RefCell<Frame2d<W, H>>,
+    >,
+);
+
+#[cfg(not(feature = "host"))]
+impl<const W: usize, const H: usize> Led2dFrameBuffer<W, H> {
+    /// Create a buffer holding an all-black frame.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(embassy_sync::blocking_mutex::Mutex::new(
+            core::cell::RefCell::new(Frame2d::new()),
+        ))
+    }
+
+    /// Lend the frame to `draw`, then pass it to `write` without copying it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `draw` or `write` re-enters this buffer.
+    pub fn with_frame<R>(
+        &self,
+        draw: impl FnOnce(&mut Frame2d<W, H>),
+        write: impl FnOnce(&Frame2d<W, H>) -> R,
+    ) -> R {
+        self.0.lock(|frame| {
+            let mut frame = frame.borrow_mut();
+            draw(&mut frame);
+            write(&frame)
+        })
+    }
+}
+
+/// A device abstraction that presents a grid of identical LED panels, each on its own
+/// LED strip, as one large drawable surface.
 ///
-/// Supports any size display with arbitrary LED-index-to-coordinate mapping. The provided mapping
-/// is reversed during initialization into an internal (row, col) → LED index lookup so frame
-/// conversion stays fast.
+/// Every panel shares one [`LedLayout`]. `write_frame` and `animate` take a frame the size of
+/// the whole grid, split it into panel-sized tiles, and send each tile to its panel's strip.
 ///
-/// Rows and columns are metadata used only for indexing - the core type is generic only over
-/// N (total LEDs) and MAX_FRAMES (animation capacity).
+/// # Example
 ///
-/// Most users should use the `led2d!` or `led2d_from_strip!` macros which generate
-/// a higher-level wrapper. See the [led2d](mod@crate::led2d) module docs for examples.
-pub struct Led2d<const N: usize, const MAX_FRAMES: usize> {
-    led_strip: &'static LedStrip<N, MAX_FRAMES>,
-    mapping_by_xy: [u16; N],
-    width: usize,
+/// Four 8×8 panels in a 2×2 grid, each on its own pin, drawn as one 16×16 canvas:
+///
+/// ```rust,no_run
+/// # #![no_std]
+/// # #![no_main]
+/// # use panic_probe as _;
+/// # use core::convert::Infallible;
+/// # use core::future;
+/// # use embassy_executor::Spawner;
+/// use device_envoy::{Result, led2d::{Frame2d, Led2dTiled, LedLayout}, led_strip::{Current, ToRgb888, colors, led_strips}};
+/// use embedded_graphics::{prelude::*, primitives::{Circle, PrimitiveStyle}};
+///
+/// // How the LEDs are wired within each 8×8 panel.
+/// const PANEL_LAYOUT: LedLayout<64, 8, 8> = LedLayout::serpentine_row_major();
+///
+/// led_strips! {
+///     Panels {
+///         TopLeftPanel: { pin: PIN_0, len: 64, max_current: Current::Milliamps(100) },
+///         TopRightPanel: { pin: PIN_1, len: 64, max_current: Current::Milliamps(100) },
+///         BottomLeftPanel: { pin: PIN_2, len: 64, max_current: Current::Milliamps(100) },
+///         BottomRightPanel: { pin: PIN_3, len: 64, max_current: Current::Milliamps(100) },
+///     }
+/// }
+///
+/// # #[embassy_executor::main]
+/// # async fn main(spawner: Spawner) -> ! {
+/// #     let err = example(spawner).await.unwrap_err();
+/// #     core::panic!("{err}");
+/// # }
+/// async fn example(spawner: Spawner) -> Result<Infallible> {
+///     let p = embassy_rp::init(Default::default());
+///     let (top_left, top_right, bottom_left, bottom_right) = Panels::new(
+///         p.PIO0, p.PIN_0, p.DMA_CH0, p.PIN_1, p.DMA_CH1, p.PIN_2, p.DMA_CH2, p.PIN_3,
+///         p.DMA_CH3, spawner,
+///     )?;
+///
+///     // Strips are listed row by row, as they appear in the grid.
+///     let led16x16 = Led2dTiled::new(
+///         [[&**top_left, &**top_right], [&**bottom_left, &**bottom_right]],
+///         &PANEL_LAYOUT,
+///     );
+///
+///     // Draw a circle that spans all four panels.
+///     let mut frame = Frame2d::<16, 16>::new();
+///     Circle::new(Point::new(2, 2), 12)
+///         .into_styled(PrimitiveStyle::with_stroke(colors::CYAN.to_rgb888(), 1))
+///         .draw(&mut frame)
+///         .expect("circle draw must succeed");
+///     led16x16.write_frame(frame)?;
+///
+///     future::pending().await // run forever
+/// }
+/// ```
+pub struct Led2dTiled<
+    const N: usize,
+    const MAX_FRAMES: usize,
+    const COLUMNS: usize,
+    const ROWS: usize,
+> {
+    panels: [[Led2d<N, MAX_FRAMES>; COLUMNS]; ROWS],
+    panel_width: usize,
+    panel_height: usize,
 }
 
-impl<const N: usize, const MAX_FRAMES: usize> Led2d<N, MAX_FRAMES> {
-    /// Create Led2d device handle.
+impl<const N: usize, const MAX_FRAMES: usize, const COLUMNS: usize, const ROWS: usize>
+    Led2dTiled<N, MAX_FRAMES, COLUMNS, ROWS>
+{
+    /// Create a tiled display from a grid of LED strips that share one panel layout.
     ///
-    /// The `led_layout` defines how LED indices map to `(column, row)` coordinates. Entry `i`
-    /// provides the `(col, row)` destination for LED `i`. The layout is inverted via
-    /// [`LedLayout::xy_to_index`] so (row, col) queries are O(1) when converting frames.
+    /// `led_strips[row][column]` drives the panel at that grid position, with row 0 at the
+    /// top and column 0 at the left.
     ///
-    /// See the [Led2d struct example](Self) for usage.
+    /// See the [`Led2dTiled` struct example](Self) for usage.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `led_layout` does not describe exactly `N` LEDs.
     #[must_use]
-    pub fn new<const W: usize, const H: usize>(
-        led_strip: &'static LedStrip<N, MAX_FRAMES>,
-        led_layout: &LedLayout<N, W, H>,
+    pub fn new<const PANEL_W: usize, const PANEL_H: usize>(
+        led_strips: [[&'static LedStrip<N, MAX_FRAMES>; COLUMNS]; ROWS],
+        led_layout: &LedLayout<N, PANEL_W, PANEL_H>,
     ) -> Self {
-        assert_eq!(
-            W.checked_mul(H).expect("width * height must fit in usize"),
-            N,
-            "width * height must equal N (total LEDs for led_layout reversal)"
-        );
         Self {
-            led_strip,
-            mapping_by_xy: led_layout.xy_to_index(),
-            width: W,
+            panels: led_strips.map(|row| row.map(|led_strip| Led2d::new(led_strip, led_layout))),
+            panel_width: PANEL_W,
+            panel_height: PANEL_H,
         }
     }
 
-    /// Convert (column, row) coordinates to LED strip index using the stored LED layout.
-    #[must_use]
-    fn xy_to_index(&self, x_index: usize, y_index: usize) -> usize {
-        self.mapping_by_xy[y_index * self.width + x_index] as usize
-    }
-
-    /// Convert 2D frame to 1D array using the LED layout.
-    fn convert_frame<const W: usize, const H: usize>(
-        &self,
-        frame_2d: Frame2d<W, H>,
-    ) -> StripFrame<N> {
-        let mut frame_1d = [RGB8::new(0, 0, 0); N];
-        for y_index in 0..H {
-            for x_index in 0..W {
-                let led_index = self.xy_to_index(x_index, y_index);
-                frame_1d[led_index] = frame_2d[(x_index, y_index)];
+    /// Split a full-size frame into tiles and render each tile on its panel.
+    ///
+    /// See the [`Led2dTiled` struct example](Self) for usage.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any underlying strip rejects its frame.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the frame is not `COLUMNS` panels wide and `ROWS` panels high.
+    pub fn write_frame<const W: usize, const H: usize>(&self, frame: Frame2d<W, H>) -> Result<()> {
+        self.assert_frame_size::<W, H>();
+        for (row_index, panel_row) in self.panels.iter().enumerate() {
+            for (column_index, panel) in panel_row.iter().enumerate() {
+                let strip_frame = panel.convert_region(
+                    &frame,
+                    column_index * self.panel_width,
+                    row_index * self.panel_height,
+                );
+                panel.led_strip.write_frame(strip_frame)?;
             }
         }
-        StripFrame::from(frame_1d)
+        Ok(())
     }
 
-    /// Render a fully defined frame to the panel.
+    /// Loop through a sequence of full-size animation frames until interrupted by another
+    /// command.
     ///
-    /// Frame2d is a 2D array in row-major order where `frame[(col, row)]` is the pixel at (col, row).
-    pub fn write_frame<const W: usize, const H: usize>(&self, frame: Frame2d<W, H>) -> Result<()> {
-        let strip_frame = self.convert_frame(frame);
-        self.led_strip.write_frame(strip_frame)
-    }
-
-    /// Loop through a sequence of animation frames until interrupted by another command.
+    /// Each panel runs its share of the animation on its own strip, so the iterator must be
+    /// `Clone` to be walked once per panel.
     ///
-    /// Each frame is a tuple of `(Frame2d, Duration)`. Accepts arrays, `Vec`s, or any
-    /// iterator that produces `(Frame2d, Duration)` tuples. For best efficiency with large
-    /// frame sequences, pass an iterator to avoid intermediate allocations.
+    /// See the [`Led2dTiled` struct example](Self) for usage.
     ///
-    /// Returns immediately; the animation runs in the background until interrupted
-    /// by a new `animate` call or `write_frame`.
+    /// # Errors
+    ///
+    /// Returns an error if any underlying strip rejects the animation (for example, too many
+    /// frames).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the frames are not `COLUMNS` panels wide and `ROWS` panels high.
     pub fn animate<const W: usize, const H: usize, I>(&self, frames: I) -> Result<()>
     where
         I: IntoIterator,
+        I::IntoIter: Clone,
         I::Item: Borrow<(Frame2d<W, H>, Duration)>,
     {
-        self.led_strip.animate(frames.into_iter().map(|frame| {
-            let (frame, duration) = *frame.borrow();
-            (self.convert_frame(frame), duration)
-        }))
+        self.assert_frame_size::<W, H>();
+        let frames = frames.into_iter();
+        for (row_index, panel_row) in self.panels.iter().enumerate() {
+            for (column_index, panel) in panel_row.iter().enumerate() {
+                let x_offset = column_index * self.panel_width;
+                let y_offset = row_index * self.panel_height;
+                panel.led_strip.animate(frames.clone().map(|frame| {
+                    let (frame, duration) = frame.borrow();
+                    (panel.convert_region(frame, x_offset, y_offset), *duration)
+                }))?;
+            }
+        }
+        Ok(())
+    }
+
+    fn assert_frame_size<const W: usize, const H: usize>(&self) {
+        assert_eq!(
+            W,
+            self.panel_width * COLUMNS,
+            "frame width must equal panel width * COLUMNS"
+        );
+        assert_eq!(
+            H,
+            self.panel_height * ROWS,
+            "frame height must equal panel height * ROWS"
+        );
     }
 }
 
@@ -804,6 +2796,8 @@ impl<const N: usize, const MAX_FRAMES: usize> Led2d<N, MAX_FRAMES> {
 /// - `max_current` — Electrical current budget (default: 250 mA)
 /// - `gamma` — Color curve (default: `Gamma::Srgb`)
 /// - `max_frames` — Maximum number of animation frames for the generated strip (default: 16 frames)
+/// - `origin` — Panel corner that frame coordinate `(0, 0)` maps to (see [`Corner`];
+///   default: `Corner::TopLeft`)
 ///
 /// `max_frames = 0` disables animation and allocates no frame storage; `write_frame()` is still supported.
 ///
@@ -850,6 +2844,7 @@ macro_rules! __led2d_impl {
             gamma: $crate::led_strip::GAMMA_DEFAULT,
             max_frames: $crate::led_strip::MAX_FRAMES_DEFAULT,
             font: _UNSET_,
+            origin: $crate::led2d::Corner::TopLeft,
             fields: [ $($fields)* ]
         }
     };
@@ -872,6 +2867,7 @@ macro_rules! __led2d_impl {
             gamma: $crate::led_strip::GAMMA_DEFAULT,
             max_frames: $crate::led_strip::MAX_FRAMES_DEFAULT,
             font: _UNSET_,
+            origin: $crate::led2d::Corner::TopLeft,
             fields: [ $($fields)* ]
         }
     };
@@ -894,6 +2890,7 @@ macro_rules! __led2d_impl {
             gamma: $crate::led_strip::GAMMA_DEFAULT,
             max_frames: $crate::led_strip::MAX_FRAMES_DEFAULT,
             font: _UNSET_,
+            origin: $crate::led2d::Corner::TopLeft,
             fields: [ $($fields)* ]
         }
     };
@@ -910,6 +2907,7 @@ macro_rules! __led2d_impl {
         gamma: $gamma:expr,
         max_frames: $max_frames:expr,
         font: $font_variant:tt,
+        origin: $origin:expr,
         fields: [ pio: $new_pio:ident $(, $($rest:tt)* )? ]
     ) => {
         $crate::__led2d_impl! {
@@ -924,6 +2922,7 @@ macro_rules! __led2d_impl {
             gamma: $gamma,
             max_frames: $max_frames,
             font: $font_variant,
+            origin: $origin,
             fields: [ $($($rest)*)? ]
         }
     };
@@ -940,6 +2939,7 @@ macro_rules! __led2d_impl {
         gamma: $gamma:expr,
         max_frames: $max_frames:expr,
         font: $font_variant:tt,
+        origin: $origin:expr,
         fields: [ pin: $new_pin:ident $(, $($rest:tt)* )? ]
     ) => {
         $crate::__led2d_impl! {
@@ -954,6 +2954,7 @@ macro_rules! __led2d_impl {
             gamma: $gamma,
             max_frames: $max_frames,
             font: $font_variant,
+            origin: $origin,
             fields: [ $($($rest)*)? ]
         }
     };
@@ -970,6 +2971,7 @@ macro_rules! __led2d_impl {
         gamma: $gamma:expr,
         max_frames: $max_frames:expr,
         font: $font_variant:tt,
+        origin: $origin:expr,
         fields: [ dma: $new_dma:ident $(, $($rest:tt)* )? ]
     ) => {
         $crate::__led2d_impl! {
@@ -984,6 +2986,7 @@ macro_rules! __led2d_impl {
             gamma: $gamma,
             max_frames: $max_frames,
             font: $font_variant,
+            origin: $origin,
             fields: [ $($($rest)*)? ]
         }
     };
@@ -1000,6 +3003,7 @@ macro_rules! __led2d_impl {
         gamma: $gamma:expr,
         max_frames: $max_frames:expr,
         font: $font_variant:tt,
+        origin: $origin:expr,
         fields: [ led_layout: $new_led_layout:tt $(, $($rest:tt)* )? ]
     ) => {
         $crate::__led2d_impl! {
@@ -1014,6 +3018,7 @@ macro_rules! __led2d_impl {
             gamma: $gamma,
             max_frames: $max_frames,
             font: $font_variant,
+            origin: $origin,
             fields: [ $($($rest)*)? ]
         }
     };
@@ -1030,6 +3035,7 @@ macro_rules! __led2d_impl {
         gamma: $gamma:expr,
         max_frames: $max_frames:expr,
         font: $font_variant:tt,
+        origin: $origin:expr,
         fields: [ max_current: $new_max_current:expr $(, $($rest:tt)* )? ]
     ) => {
         $crate::__led2d_impl! {
@@ -1044,6 +3050,7 @@ macro_rules! __led2d_impl {
             gamma: $gamma,
             max_frames: $max_frames,
             font: $font_variant,
+            origin: $origin,
             fields: [ $($($rest)*)? ]
         }
     };
@@ -1060,6 +3067,7 @@ macro_rules! __led2d_impl {
         gamma: $gamma:expr,
         max_frames: $max_frames:expr,
         font: $font_variant:tt,
+        origin: $origin:expr,
         fields: [ gamma: $new_gamma:expr $(, $($rest:tt)* )? ]
     ) => {
         $crate::__led2d_impl! {
@@ -1074,6 +3082,7 @@ macro_rules! __led2d_impl {
             gamma: $new_gamma,
             max_frames: $max_frames,
             font: $font_variant,
+            origin: $origin,
             fields: [ $($($rest)*)? ]
         }
     };
@@ -1090,6 +3099,7 @@ macro_rules! __led2d_impl {
         gamma: $gamma:expr,
         max_frames: $max_frames:expr,
         font: $font_variant:tt,
+        origin: $origin:expr,
         fields: [ max_frames: $new_max_frames:expr $(, $($rest:tt)* )? ]
     ) => {
         $crate::__led2d_impl! {
@@ -1104,6 +3114,7 @@ macro_rules! __led2d_impl {
             gamma: $gamma,
             max_frames: $new_max_frames,
             font: $font_variant,
+            origin: $origin,
             fields: [ $($($rest)*)? ]
         }
     };
@@ -1120,6 +3131,7 @@ macro_rules! __led2d_impl {
         gamma: $gamma:expr,
         max_frames: $max_frames:expr,
         font: $font_variant:tt,
+        origin: $origin:expr,
         fields: [ font: $new_font_variant:expr $(, $($rest:tt)* )? ]
     ) => {
         $crate::__led2d_impl! {
@@ -1134,6 +3146,39 @@ macro_rules! __led2d_impl {
             gamma: $gamma,
             max_frames: $max_frames,
             font: $new_font_variant,
+            origin: $origin,
+            fields: [ $($($rest)*)? ]
+        }
+    };
+
+    // Fill defaults: origin
+    (@__fill_defaults
+        vis: $vis:vis,
+        name: $name:ident,
+        pio: $pio:ident,
+        pin: $pin:tt,
+        dma: $dma:ident,
+        led_layout: $led_layout:tt,
+        max_current: $max_current:tt,
+        gamma: $gamma:expr,
+        max_frames: $max_frames:expr,
+        font: $font_variant:tt,
+        origin: $origin:expr,
+        fields: [ origin: $new_origin:expr $(, $($rest:tt)* )? ]
+    ) => {
+        $crate::__led2d_impl! {
+            @__fill_defaults
+            vis: $vis,
+            name: $name,
+            pio: $pio,
+            pin: $pin,
+            dma: $dma,
+            led_layout: $led_layout,
+            max_current: $max_current,
+            gamma: $gamma,
+            max_frames: $max_frames,
+            font: $font_variant,
+            origin: $new_origin,
             fields: [ $($($rest)*)? ]
         }
     };
@@ -1150,6 +3195,7 @@ macro_rules! __led2d_impl {
         gamma: $gamma:expr,
         max_frames: $max_frames:expr,
         font: $font_variant:tt,
+        origin: $origin:expr,
         fields: [ ]
     ) => {
         $crate::__led2d_impl! {
@@ -1164,6 +3210,7 @@ macro_rules! __led2d_impl {
             gamma: $gamma,
             max_frames: $max_frames,
             font: $font_variant,
+            origin: $origin,
             fields: [ ]
         }
     };
@@ -1180,6 +3227,7 @@ macro_rules! __led2d_impl {
         gamma: $gamma:expr,
         max_frames: $max_frames:expr,
         font: $font_variant:expr,
+        origin: $origin:expr,
         fields: [ ]
     ) => {
         $crate::__led2d_impl! {
@@ -1193,7 +3241,8 @@ macro_rules! __led2d_impl {
             max_current: $max_current,
             gamma: $gamma,
             max_frames: $max_frames,
-            font: $font_variant
+            font: $font_variant,
+            origin: $origin
         }
     };
 
@@ -1208,7 +3257,8 @@ macro_rules! __led2d_impl {
         max_current: $max_current:expr,
         gamma: $gamma:expr,
         max_frames: $max_frames:expr,
-        font: $font_variant:expr
+        font: $font_variant:expr,
+        origin: $origin:expr
     ) => {
         $crate::led2d::paste::paste! {
             const [<$name:upper _LAYOUT>]: $crate::led2d::LedLayout<
@@ -1246,6 +3296,7 @@ macro_rules! __led2d_impl {
                 strip_type: [<$name LedStrip>],
                 led_layout_const: [<$name:upper _LAYOUT>],
                 font: $font_variant,
+                origin: $origin,
                 max_frames_const: [<$name:upper _MAX_FRAMES>],
             }
 
@@ -1314,6 +3365,7 @@ macro_rules! led2d_from_strip {
             $crate::led2d::led2d_from_strip!(
                 @common $vis, $name, $strip_type, [<$name:upper _LED_LAYOUT>],
                 $font_variant,
+                $crate::led2d::Corner::TopLeft,
                 [<$name:upper _MAX_FRAMES>]
             );
         }
@@ -1337,6 +3389,7 @@ macro_rules! led2d_from_strip {
             $crate::led2d::led2d_from_strip!(
                 @common $vis, $name, $strip_type, [<$name:upper _LED_LAYOUT>],
                 $font_variant,
+                $crate::led2d::Corner::TopLeft,
                 [<$name:upper _MAX_FRAMES>]
             );
         }
@@ -1348,11 +3401,13 @@ macro_rules! led2d_from_strip {
         strip_type: $strip_type:ident,
         led_layout_const: $led_layout_const:ident,
         font: $font_variant:expr,
+        origin: $origin:expr,
         max_frames_const: $max_frames_const:ident $(,)?
     ) => {
         $crate::led2d::led2d_from_strip!(
             @common $vis, $name, $strip_type, $led_layout_const,
             $font_variant,
+            $origin,
             $max_frames_const
         );
     };
@@ -1363,6 +3418,7 @@ macro_rules! led2d_from_strip {
         $strip_type:ident,
         $led_layout_const:ident,
         $font_variant:expr,
+        $origin:expr,
         $max_frames_const:ident
     ) => {
         $crate::led2d::paste::paste! {
@@ -1371,6 +3427,7 @@ macro_rules! led2d_from_strip {
                 led2d: $crate::led2d::Led2d<{ $led_layout_const.len() }, $max_frames_const>,
                 font: embedded_graphics::mono_font::MonoFont<'static>,
                 font_variant: $crate::led2d::Led2dFont,
+                frame_buffer: $crate::led2d::Led2dFrameBuffer<{ $led_layout_const.width() }, { $led_layout_const.height() }>,
             }
 
             #[allow(non_snake_case, dead_code)]
@@ -1402,13 +3459,15 @@ macro_rules! led2d_from_strip {
                     let led2d = $crate::led2d::Led2d::new(
                         led_strip.as_ref(),
                         &$led_layout_const,
-                    );
+                    )
+                    .with_origin($origin);
 
                     defmt::info!("Led2d::new: device created successfully");
                     Ok(Self {
                         led2d,
                 font: $font_variant.to_font(),
                 font_variant: $font_variant,
+                frame_buffer: $crate::led2d::Led2dFrameBuffer::new(),
             })
         }
 
@@ -1420,17 +3479,162 @@ macro_rules! led2d_from_strip {
                     self.led2d.write_frame(frame)
                 }
 
-                /// Loop through a sequence of animation frames. Pass arrays by value or Vecs/iters.
-                $vis fn animate(
+                /// Draw into the panel's reusable frame buffer with `draw`, then display it.
+                ///
+                /// The buffer keeps its contents between calls; clear it in `draw` if needed.
+                $vis fn with_frame(
+                    &self,
+                    draw: impl FnOnce(&mut $crate::led2d::Frame2d<{ $led_layout_const.width() }, { $led_layout_const.height() }>),
+                ) -> $crate::Result<()> {
+                    self.frame_buffer
+                        .with_frame(draw, |frame| self.led2d.write_frame_ref(frame))
+                }
+
+                /// Loop through a sequence of animation frames. Pass arrays by value or by
+                /// reference (to avoid copying a `const` table), or Vecs/iters.
+                $vis fn animate<I>(&self, frames: I) -> $crate::Result<()>
+                where
+                    I: IntoIterator,
+                    I::Item: ::core::borrow::Borrow<(
+                        $crate::led2d::Frame2d<{ $led_layout_const.width() }, { $led_layout_const.height() }>,
+                        ::embassy_time::Duration,
+                    )>,
+                {
+                    self.led2d.animate(frames)
+                }
+
+                /// Loop through a sequence of animation frames, showing each for the same `frame_duration`.
+                $vis fn animate_uniform<I>(
                     &self,
-                    frames: impl IntoIterator<
-                        Item = (
-                            $crate::led2d::Frame2d<{ $led_layout_const.width() }, { $led_layout_const.height() }>,
-                            ::embassy_time::Duration,
-                        ),
+                    frames: I,
+                    frame_duration: ::embassy_time::Duration,
+                ) -> $crate::Result<()>
+                where
+                    I: IntoIterator,
+                    I::Item: ::core::borrow::Borrow<
+                        $crate::led2d::Frame2d<{ $led_layout_const.width() }, { $led_layout_const.height() }>,
                     >,
+                {
+                    self.led2d.animate_uniform(frames, frame_duration)
+                }
+
+                /// Cross-fade from `from` to `to` over `steps` frames, each shown for `step_duration`.
+                $vis fn animate_crossfade(
+                    &self,
+                    from: $crate::led2d::Frame2d<{ $led_layout_const.width() }, { $led_layout_const.height() }>,
+                    to: $crate::led2d::Frame2d<{ $led_layout_const.width() }, { $led_layout_const.height() }>,
+                    steps: u8,
+                    step_duration: ::embassy_time::Duration,
                 ) -> $crate::Result<()> {
-                    self.led2d.animate(frames)
+                    self.led2d.animate_crossfade(from, to, steps, step_duration)
+                }
+
+                /// Like `animate`, but the animation starts only when `trigger` is signaled.
+                $vis fn animate_on_signal<I>(
+                    &self,
+                    frames: I,
+                    trigger: &'static $crate::led_strip::AnimationTrigger,
+                ) -> $crate::Result<()>
+                where
+                    I: IntoIterator,
+                    I::Item: ::core::borrow::Borrow<(
+                        $crate::led2d::Frame2d<{ $led_layout_const.width() }, { $led_layout_const.height() }>,
+                        ::embassy_time::Duration,
+                    )>,
+                {
+                    self.led2d.animate_on_signal(frames, trigger)
+                }
+
+                /// Display a frame from static memory, already in strip order (see `LedLayout::remap`), without copying it.
+                $vis fn write_static_frame(
+                    &self,
+                    frame: &'static $crate::led_strip::Frame1d<{ $led_layout_const.len() }>,
+                ) -> $crate::Result<()> {
+                    self.led2d.write_static_frame(frame)
+                }
+
+                /// Loop through frames from static memory, already in strip order (see `LedLayout::remap`), without copying them.
+                $vis fn animate_static_frames(
+                    &self,
+                    frames: &'static [($crate::led_strip::Frame1d<{ $led_layout_const.len() }>, ::embassy_time::Duration)],
+                ) -> $crate::Result<()> {
+                    self.led2d.animate_static_frames(frames)
+                }
+
+                /// Loop through `frame_count` frames computed on demand by `generate`, showing each for `frame_duration`.
+                $vis fn animate_generated(
+                    &self,
+                    frame_count: usize,
+                    frame_duration: ::embassy_time::Duration,
+                    generate: fn(usize) -> $crate::led2d::Frame2d<{ $led_layout_const.width() }, { $led_layout_const.height() }>,
+                ) -> $crate::Result<()> {
+                    static GENERATOR: $crate::led2d::Led2dGenerator<{ $led_layout_const.width() }, { $led_layout_const.height() }> =
+                        $crate::led2d::Led2dGenerator::new();
+
+                    fn generate_strip_frame(frame_index: usize) -> $crate::led_strip::Frame1d<{ $led_layout_const.len() }> {
+                        $led_layout_const.remap(&GENERATOR.generate(frame_index))
+                    }
+
+                    GENERATOR.set(generate);
+                    self.led2d.animate_generated(frame_count, frame_duration, generate_strip_frame)
+                }
+
+                /// Scroll `text` right to left in a rainbow that shifts across the panel and
+                /// over time, looping until interrupted by another command.
+                ///
+                /// The text moves one column per `speed` and its hue advances `hue_speed`
+                /// steps (of 256 per turn) each column.
+                $vis fn scroll_rainbow_text(
+                    &self,
+                    text: &'static str,
+                    speed: ::embassy_time::Duration,
+                    hue_speed: u8,
+                ) -> $crate::Result<()> {
+                    static MARQUEE: $crate::led2d::Led2dMarquee<{ $led_layout_const.width() }, { $led_layout_const.height() }> =
+                        $crate::led2d::Led2dMarquee::new();
+
+                    fn generate_strip_frame(frame_index: usize) -> $crate::led_strip::Frame1d<{ $led_layout_const.len() }> {
+                        $led_layout_const.remap(&MARQUEE.generate(frame_index))
+                    }
+
+                    let frame_count = MARQUEE.set(text, self.font_variant, hue_speed);
+                    self.led2d.animate_generated(frame_count, speed, generate_strip_frame)
+                }
+
+                /// Show a flickering fire that rises up every column from the bottom row,
+                /// computing a new frame every `speed`. It runs until another command replaces it.
+                $vis fn fire(
+                    &self,
+                    cooling: u8,
+                    sparking: u8,
+                    speed: ::embassy_time::Duration,
+                ) -> $crate::Result<()> {
+                    static FIRE: $crate::led2d::Led2dFire<{ $led_layout_const.width() }, { $led_layout_const.height() }> =
+                        $crate::led2d::Led2dFire::new();
+
+                    fn generate_strip_frame(frame_index: usize) -> $crate::led_strip::Frame1d<{ $led_layout_const.len() }> {
+                        $led_layout_const.remap(&FIRE.generate(frame_index))
+                    }
+
+                    FIRE.set(cooling, sparking);
+                    self.led2d.animate_generated(usize::MAX, speed, generate_strip_frame)
+                }
+
+                /// Returns `true` while the panel is running an animation or finishing a command.
+                #[must_use]
+                $vis fn is_animating(&self) -> bool {
+                    self.led2d.is_animating()
+                }
+
+                /// Wait until the panel shows a static frame, with no animation running and
+                /// no command pending.
+                $vis async fn wait_idle(&self) {
+                    self.led2d.wait_idle().await;
+                }
+
+                /// Turn the panel off and stop its background task. Later commands are ignored.
+                $vis fn shutdown(&self) {
+                    self.led2d.shutdown();
                 }
 
                 /// Render text into a frame using the configured font and spacing.
@@ -1440,7 +3644,53 @@ macro_rules! led2d_from_strip {
                     colors: &[smart_leds::RGB8],
                     frame: &mut $crate::led2d::Frame2d<{ $led_layout_const.width() }, { $led_layout_const.height() }>,
                 ) -> $crate::Result<()> {
-                    $crate::led2d::render_text_to_frame(frame, &self.font, text, colors, self.font_variant.spacing_reduction())
+                    $crate::led2d::render_text_to_frame(frame, &self.font, text, colors, self.font_variant.spacing_reduction(), false)
+                }
+
+                /// Render text into a frame like `write_text_to_frame`, but advance each
+                /// character by its inked width plus one pixel, packing more text onto the
+                /// panel.
+                pub fn write_text_to_frame_proportional(
+                    &self,
+                    text: &str,
+                    colors: &[smart_leds::RGB8],
+                    frame: &mut $crate::led2d::Frame2d<{ $led_layout_const.width() }, { $led_layout_const.height() }>,
+                ) -> $crate::Result<()> {
+                    $crate::led2d::render_text_to_frame(frame, &self.font, text, colors, self.font_variant.spacing_reduction(), true)
+                }
+
+                /// Render text into a frame starting at `position` and return where the next
+                /// character would go, so differently colored runs can be chained on one line.
+                pub fn write_text_to_frame_at(
+                    &self,
+                    text: &str,
+                    colors: &[smart_leds::RGB8],
+                    position: $crate::led2d::Point,
+                    frame: &mut $crate::led2d::Frame2d<{ $led_layout_const.width() }, { $led_layout_const.height() }>,
+                ) -> $crate::Result<$crate::led2d::Point> {
+                    $crate::led2d::render_text_to_frame_at(frame, &self.font, text, colors, self.font_variant.spacing_reduction(), position)
+                }
+
+                /// Render text into a frame and return the smallest rectangle enclosing the
+                /// pixels it lit, or `None` if no visible characters were drawn.
+                pub fn write_text_to_frame_with_bounds(
+                    &self,
+                    text: &str,
+                    colors: &[smart_leds::RGB8],
+                    frame: &mut $crate::led2d::Frame2d<{ $led_layout_const.width() }, { $led_layout_const.height() }>,
+                ) -> $crate::Result<Option<$crate::led2d::Rectangle>> {
+                    $crate::led2d::render_text_to_frame_with_bounds(frame, &self.font, text, colors, self.font_variant.spacing_reduction())
+                }
+
+                /// Render text rotated 90° clockwise into a frame, one character per row of
+                /// the sideways panel, reading top-to-bottom.
+                pub fn write_text_to_frame_vertical(
+                    &self,
+                    text: &str,
+                    colors: &[smart_leds::RGB8],
+                    frame: &mut $crate::led2d::Frame2d<{ $led_layout_const.width() }, { $led_layout_const.height() }>,
+                ) -> $crate::Result<()> {
+                    $crate::led2d::render_text_to_frame_vertical(frame, &self.font, text, colors, self.font_variant.spacing_reduction())
                 }
 
                 /// Render text and display it on the LED matrix.
@@ -1449,6 +3699,48 @@ macro_rules! led2d_from_strip {
                     self.write_text_to_frame(text, colors, &mut frame)?;
                     self.write_frame(frame)
                 }
+
+                /// Render text rotated 90° clockwise and display it on the LED matrix.
+                pub async fn write_text_vertical(&self, text: &str, colors: &[smart_leds::RGB8]) -> $crate::Result<()> {
+                    let mut frame = $crate::led2d::Frame2d::<{ $led_layout_const.width() }, { $led_layout_const.height() }>::new();
+                    self.write_text_to_frame_vertical(text, colors, &mut frame)?;
+                    self.write_frame(frame)
+                }
+            }
+
+            impl $crate::led2d::LedPanel for [<$name>] {
+                type Frame = $crate::led2d::Frame2d<{ $led_layout_const.width() }, { $led_layout_const.height() }>;
+
+                fn device_info(&self) -> $crate::led2d::DeviceInfo {
+                    $crate::led2d::DeviceInfo {
+                        len: Self::N,
+                        width: Self::WIDTH,
+                        height: Self::HEIGHT,
+                        max_frames: Self::MAX_FRAMES,
+                    }
+                }
+
+                fn write_frame(&self, frame: Self::Frame) -> $crate::Result<()> {
+                    self.led2d.write_frame(frame)
+                }
+
+                fn animate<I>(&self, frames: I) -> $crate::Result<()>
+                where
+                    I: IntoIterator,
+                    I::Item: ::core::borrow::Borrow<(Self::Frame, ::embassy_time::Duration)>,
+                {
+                    self.led2d.animate(frames)
+                }
+            }
+
+            impl $crate::led2d::LedDisplay for [<$name>] {
+                fn fill(&self, color: smart_leds::RGB8) -> $crate::Result<()> {
+                    self.led2d.write_frame($crate::led2d::Frame2d::filled(color))
+                }
+
+                fn len(&self) -> usize {
+                    Self::N
+                }
             }
         }
     };
@@ -3,6 +3,9 @@
 //!
 //! See [`LedLayout`] for details and examples.
 
+use crate::led_strip::Frame1d;
+use crate::led2d::Frame2d;
+
 /// Compile-time description of panel geometry and wiring, including dimensions (with examples).
 ///
 /// `LedLayout` defines how a rectangular `(x, y)` panel of LEDs maps to the linear
@@ -30,6 +33,7 @@
 /// Prefer the built-in constructors when possible:
 /// - [`serpentine_row_major`](Self::serpentine_row_major)
 /// - [`serpentine_column_major`](Self::serpentine_column_major)
+/// - [`zigzag_column_major_from_bottom`](Self::zigzag_column_major_from_bottom)
 /// - [`linear_h`](Self::linear_h) / [`linear_v`](Self::linear_v)
 ///
 /// For unusual wiring, you can construct a layout directly with [`LedLayout::new`]
@@ -43,6 +47,7 @@
 /// You can adapt a layout without rewriting it:
 /// - rotate: [`rotate_cw`](Self::rotate_cw), [`rotate_ccw`](Self::rotate_ccw), [`rotate_180`](Self::rotate_180)
 /// - flip: [`flip_h`](Self::flip_h), [`flip_v`](Self::flip_v)
+/// - reflect: [`reflect_x`](Self::reflect_x), [`reflect_y`](Self::reflect_y)
 /// - combine: [`combine_h`](Self::combine_h), [`combine_v`](Self::combine_v)  (join two layouts into a larger one)
 ///
 /// ## Validation
@@ -51,7 +56,8 @@
 /// - coordinates must be in-bounds
 /// - every `(x, y)` cell must appear exactly once
 ///
-/// If you want the final mapping, use [`index_to_xy`](Self::index_to_xy).
+/// If you want the final mapping, use [`index_to_xy`](Self::index_to_xy). To apply it to a
+/// frame without a device, use [`remap`](Self::remap).
 ///
 /// # Example
 ///
@@ -108,6 +114,79 @@ impl<const N: usize, const W: usize, const H: usize> LedLayout<N, W, H> {
         N
     }
 
+    /// Convert a [`Frame2d`] into the strip's wiring order.
+    ///
+    /// This is the same mapping the LED panel devices apply before writing a frame, so
+    /// you can precompute frames (for example, into flash) or check a layout against
+    /// known-good data on the host, without a live device.
+    ///
+    /// ```rust,no_run
+    /// # #![no_std]
+    /// # #![no_main]
+    /// # #[panic_handler]
+    /// # fn panic(_: &core::panic::PanicInfo) -> ! { loop {} }
+    /// use device_envoy::led2d::{Frame2d, layout::LedLayout};
+    /// use device_envoy::led_strip::{Frame1d, colors};
+    ///
+    /// const LAYOUT: LedLayout<6, 3, 2> = LedLayout::serpentine_column_major();
+    ///
+    /// let mut frame = Frame2d::<3, 2>::new();
+    /// frame[(1, 0)] = colors::RED; // LED3 in serpentine order
+    /// let strip_frame: Frame1d<6> = LAYOUT.remap(&frame);
+    /// assert_eq!(strip_frame[3], colors::RED);
+    /// ```
+    ///
+    /// ```text
+    /// Serpentine 3×2:
+    ///   LED0  LED3  LED4
+    ///   LED1  LED2  LED5
+    /// ```
+    #[must_use]
+    pub const fn remap(&self, frame: &Frame2d<W, H>) -> Frame1d<N> {
+        let mut strip_frame = Frame1d::new();
+        let mut led_index = 0;
+        while led_index < N {
+            let (col, row) = self.map[led_index];
+            strip_frame.0[led_index] = frame.0[row as usize][col as usize];
+            led_index += 1;
+        }
+        strip_frame
+    }
+
+    /// Returns `true` if LED `i` is at `(i % W, i / W)`: rows wired left to right, each
+    /// starting again at the left edge, top row first.
+    ///
+    /// For such a layout, [`Frame2d::as_frame1d_row_major`] gives the same result as
+    /// [`remap`](Self::remap) without the per-pixel lookup. Assert this at compile time
+    /// before relying on it.
+    ///
+    /// ```rust,no_run
+    /// # #![no_std]
+    /// # #![no_main]
+    /// # #[panic_handler]
+    /// # fn panic(_: &core::panic::PanicInfo) -> ! { loop {} }
+    /// use device_envoy::led2d::layout::LedLayout;
+    ///
+    /// const ROW_MAJOR: LedLayout<6, 3, 2> =
+    ///     LedLayout::new([(0, 0), (1, 0), (2, 0), (0, 1), (1, 1), (2, 1)]);
+    /// const SERPENTINE: LedLayout<6, 3, 2> = LedLayout::serpentine_row_major();
+    ///
+    /// const _: () = assert!(ROW_MAJOR.is_row_major());
+    /// const _: () = assert!(!SERPENTINE.is_row_major());
+    /// ```
+    #[must_use]
+    pub const fn is_row_major(&self) -> bool {
+        let mut led_index = 0;
+        while led_index < N {
+            let (col, row) = self.map[led_index];
+            if col as usize != led_index % W || row as usize != led_index / W {
+                return false;
+            }
+            led_index += 1;
+        }
+        true
+    }
+
     #[must_use]
     pub(crate) const fn xy_to_index(&self) -> [u16; N] {
         assert!(
@@ -366,6 +445,36 @@ impl<const N: usize, const W: usize, const H: usize> LedLayout<N, W, H> {
         Self::new(mapping)
     }
 
+    /// Column-major zigzag that starts at the bottom-left: up the first column, down the
+    /// second, up the third, and so on.
+    ///
+    /// Many commercial panels are wired this way. It equals
+    /// [`serpentine_column_major`](Self::serpentine_column_major) flipped vertically.
+    ///
+    /// ```rust,no_run
+    /// # #![no_std]
+    /// # #![no_main]
+    /// # #[panic_handler]
+    /// # fn panic(_: &core::panic::PanicInfo) -> ! { loop {} }
+    /// use device_envoy::led2d::layout::LedLayout;
+    ///
+    /// const MAP: LedLayout<6, 3, 2> = LedLayout::zigzag_column_major_from_bottom();
+    /// const EXPECTED: LedLayout<6, 3, 2> =
+    ///     LedLayout::new([(0, 1), (0, 0), (1, 0), (1, 1), (2, 1), (2, 0)]);
+    /// const _: () = assert!(MAP.equals(&EXPECTED));
+    /// ```
+    ///
+    /// ```text
+    /// Strip snakes up and down columns from the bottom-left (3×3 example):
+    ///   LED2  LED3  LED8
+    ///   LED1  LED4  LED7
+    ///   LED0  LED5  LED6
+    /// ```
+    #[must_use]
+    pub const fn zigzag_column_major_from_bottom() -> Self {
+        Self::serpentine_column_major().flip_v()
+    }
+
     /// Serpentine row-major mapping (alternating left-to-right and right-to-left across rows).
     ///
     /// ```rust,no_run
@@ -553,6 +662,18 @@ impl<const N: usize, const W: usize, const H: usize> LedLayout<N, W, H> {
         self.rotate_cw().flip_h().rotate_ccw()
     }
 
+    /// Alias for [`flip_h`](Self::flip_h): reflect across the vertical axis.
+    #[must_use]
+    pub const fn reflect_x(self) -> Self {
+        self.flip_h()
+    }
+
+    /// Alias for [`flip_v`](Self::flip_v): reflect across the horizontal axis.
+    #[must_use]
+    pub const fn reflect_y(self) -> Self {
+        self.flip_v()
+    }
+
     /// Concatenate horizontally with another mapping sharing the same rows.
     ///
     /// ```rust,no_run
@@ -0,0 +1,92 @@
+//! Host-only CSV export and import of [`LedLayout`] wiring, for checking layouts in a
+//! spreadsheet.
+//!
+//! [`to_csv`] writes `H` lines of `W` comma-separated LED indexes, where the cell at
+//! `(x, y)` holds the index of the LED wired there; [`from_csv`] reads it back.
+//!
+//! # Example
+//!
+//! ```rust
+//! use device_envoy::led2d::{layout::LedLayout, layout_csv};
+//!
+//! const SERPENTINE: LedLayout<6, 3, 2> = LedLayout::serpentine_row_major();
+//! let csv = layout_csv::to_csv(&SERPENTINE);
+//! assert_eq!(csv, "0,1,2\n5,4,3\n");
+//! let parsed = layout_csv::from_csv::<6, 3, 2>(&csv).expect("to_csv output parses");
+//! assert!(parsed.equals(&SERPENTINE));
+//! ```
+
+use crate::led2d::layout::LedLayout;
+use crate::{Error, Result};
+
+/// Format `led_layout` as `H` lines of `W` comma-separated LED indexes, where the cell at
+/// `(x, y)` holds the index of the LED wired there.
+///
+/// Paste the result into a spreadsheet to check a layout built from transforms such as
+/// [`combine_v`](LedLayout::combine_v) and [`rotate_cw`](LedLayout::rotate_cw).
+/// [`from_csv`] reads it back.
+#[must_use]
+pub fn to_csv<const N: usize, const W: usize, const H: usize>(
+    led_layout: &LedLayout<N, W, H>,
+) -> String {
+    let mut grid = vec![[0_usize; W]; H];
+    for (led_index, &(x_index, y_index)) in led_layout.index_to_xy().iter().enumerate() {
+        grid[usize::from(y_index)][usize::from(x_index)] = led_index;
+    }
+    grid.iter()
+        .map(|row| {
+            let cells: Vec<String> = row.iter().map(ToString::to_string).collect();
+            cells.join(",") + "\n"
+        })
+        .collect()
+}
+
+/// Parse a layout in the format written by [`to_csv`]: `H` lines of `W` comma-separated
+/// LED indexes, with each index from 0 to `N - 1` appearing once. Spaces around cells and
+/// trailing blank lines are ignored.
+///
+/// # Errors
+///
+/// Returns [`Error::LayoutCsv`] if the text does not have `H` rows of `W` cells, a cell
+/// is not an index below `N`, or an index appears twice.
+pub fn from_csv<const N: usize, const W: usize, const H: usize>(
+    csv: &str,
+) -> Result<LedLayout<N, W, H>> {
+    let mut map: [Option<(u16, u16)>; N] = [None; N];
+    let mut row_count = 0;
+    for (y_index, line) in csv.trim_end().lines().enumerate() {
+        if y_index >= H {
+            return Err(Error::LayoutCsv("too many rows"));
+        }
+        let mut column_count = 0;
+        for (x_index, cell) in line.split(',').enumerate() {
+            if x_index >= W {
+                return Err(Error::LayoutCsv("too many cells in a row"));
+            }
+            let led_index: usize = cell
+                .trim()
+                .parse()
+                .map_err(|_| Error::LayoutCsv("cell is not an LED index"))?;
+            let slot = map
+                .get_mut(led_index)
+                .ok_or(Error::LayoutCsv("LED index is out of range"))?;
+            if slot.is_some() {
+                return Err(Error::LayoutCsv("LED index appears twice"));
+            }
+            let x_index =
+                u16::try_from(x_index).map_err(|_| Error::LayoutCsv("row is too long"))?;
+            let y_index = u16::try_from(y_index).map_err(|_| Error::LayoutCsv("too many rows"))?;
+            *slot = Some((x_index, y_index));
+            column_count += 1;
+        }
+        if column_count != W {
+            return Err(Error::LayoutCsv("too few cells in a row"));
+        }
+        row_count += 1;
+    }
+    if row_count != H {
+        return Err(Error::LayoutCsv("too few rows"));
+    }
+    // Every index is in range and unique, and there are `W * H` cells, so all are set.
+    Ok(LedLayout::new(map.map(Option::unwrap_or_default)))
+}
@@ -9,11 +9,12 @@
 use core::borrow::Borrow;
 
 use embassy_executor::Spawner;
-use embassy_futures::select::{Either, select};
+use embassy_futures::select::{Either, Either3, select, select3};
 use embassy_sync::{blocking_mutex::raw::CriticalSectionRawMutex, signal::Signal};
 use embassy_time::{Duration, Timer};
 use heapless::Vec;
 
+use crate::bit_matrix_led4::BitMatrixLed4;
 use crate::{Error, Result};
 
 #[cfg(feature = "display-trace")]
@@ -79,7 +80,7 @@ pub enum BlinkState {
 pub(crate) enum Led4Command {
     Text {
         blink_state: BlinkState,
-        text: [char; CELL_COUNT],
+        bit_matrix: BitMatrixLed4,
     },
     Animation(Vec<AnimationFrame, ANIMATION_MAX_FRAMES>),
 }
@@ -160,14 +161,18 @@ impl AnimationFrame {
 /// Beyond simple text, the driver can loop animations via [`Led4::animate_text`].
 /// The struct owns the background task and signal wiring; create it once with
 /// [`Led4::new`] and use the returned handle for all display updates.
-pub struct Led4<'a>(&'a Led4OuterStatic);
+pub struct Led4<'a>(&'a Led4OuterStatic, &'a Led4ColonStatic);
 
 /// Signal for sending display commands to the [`Led4`] device.
 pub(crate) type Led4OuterStatic = Signal<CriticalSectionRawMutex, Led4Command>;
 
+/// Signal for turning the [`Led4`] colon on or off, independent of the text.
+pub(crate) type Led4ColonStatic = Signal<CriticalSectionRawMutex, bool>;
+
 /// Static for the [`Led4`] device.
 pub struct Led4Static {
     outer: Led4OuterStatic,
+    colon: Led4ColonStatic,
     display: Led4SimpleStatic,
 }
 
@@ -176,12 +181,13 @@ impl Led4Static {
     pub(crate) const fn new() -> Self {
         Self {
             outer: Signal::new(),
+            colon: Signal::new(),
             display: Led4Simple::new_static(),
         }
     }
 
-    fn split(&self) -> (&Led4OuterStatic, &Led4SimpleStatic) {
-        (&self.outer, &self.display)
+    fn split(&self) -> (&Led4OuterStatic, &Led4ColonStatic, &Led4SimpleStatic) {
+        (&self.outer, &self.colon, &self.display)
     }
 }
 
@@ -194,11 +200,11 @@ impl Led4<'_> {
         segment_pins: OutputArray<'static, SEGMENT_COUNT>,
         spawner: Spawner,
     ) -> Result<Self> {
-        let (outer_static, display_static) = led4_static.split();
+        let (outer_static, colon_static, display_static) = led4_static.split();
         let display = Led4Simple::new(display_static, cell_pins, segment_pins, spawner)?;
-        let token = device_loop(outer_static, display);
+        let token = device_loop(outer_static, colon_static, display);
         spawner.spawn(token).map_err(Error::TaskSpawn)?;
-        Ok(Self(outer_static))
+        Ok(Self(outer_static, colon_static))
     }
 
     /// Creates static channel resources for [`Led4::new`]; see [`Led4`] docs.
@@ -213,7 +219,40 @@ impl Led4<'_> {
     pub fn write_text(&self, text: [char; CELL_COUNT], blink_state: BlinkState) {
         #[cfg(feature = "display-trace")]
         info!("blink_state: {:?}, text: {:?}", blink_state, text);
-        self.0.signal(Led4Command::Text { blink_state, text });
+        self.0.signal(Led4Command::Text {
+            blink_state,
+            bit_matrix: BitMatrixLed4::from_text(&text),
+        });
+    }
+
+    /// Shows a number with up to `max_decimals` digits after the decimal point.
+    ///
+    /// The decimal point goes wherever it keeps the most precision within the 4 digits,
+    /// so `show_float(23.4, 2)` shows `23.40` and `show_float(123.4, 2)` shows `123.4`.
+    /// A negative value uses one digit for its minus sign. Values that don't fit, such as
+    /// `12345.0` or `-1000.0`, show `----`.
+    ///
+    /// See the main [`Led4`] example for setting up the display.
+    pub fn show_float(&self, value: f32, max_decimals: u8) {
+        #[cfg(feature = "display-trace")]
+        info!("value: {}, max_decimals: {}", value, max_decimals);
+        self.0.signal(Led4Command::Text {
+            blink_state: BlinkState::Solid,
+            bit_matrix: BitMatrixLed4::from_float(value, max_decimals),
+        });
+    }
+
+    /// Turns the colon between the second and third digits on or off.
+    ///
+    /// The colon stays as set while the text, number, or animation changes, and blinks
+    /// along with blinking text. It is the decimal point after the second digit, which
+    /// clock-style displays wire to the colon.
+    ///
+    /// See the main [`Led4`] example for setting up the display.
+    pub fn set_colon(&self, is_on: bool) {
+        #[cfg(feature = "display-trace")]
+        info!("colon: {}", is_on);
+        self.1.signal(is_on);
     }
 
     /// Plays a looped text animation using the provided frames.
@@ -275,19 +314,42 @@ impl Led4<'_> {
 }
 
 #[embassy_executor::task]
-async fn device_loop(outer_static: &'static Led4OuterStatic, display: Led4Simple<'static>) -> ! {
+async fn device_loop(
+    outer_static: &'static Led4OuterStatic,
+    colon_static: &'static Led4ColonStatic,
+    display: Led4Simple<'static>,
+) -> ! {
     let mut command = Led4Command::Text {
         blink_state: BlinkState::default(),
-        text: [' '; CELL_COUNT],
+        bit_matrix: BitMatrixLed4::default(),
     };
+    let mut is_colon_on = false;
 
     loop {
         command = match command {
-            Led4Command::Text { blink_state, text } => {
-                run_text_loop(blink_state, text, outer_static, &display).await
+            Led4Command::Text {
+                blink_state,
+                bit_matrix,
+            } => {
+                run_text_loop(
+                    blink_state,
+                    bit_matrix,
+                    &mut is_colon_on,
+                    outer_static,
+                    colon_static,
+                    &display,
+                )
+                .await
             }
             Led4Command::Animation(animation) => {
-                run_animation_loop(animation, outer_static, &display).await
+                run_animation_loop(
+                    animation,
+                    &mut is_colon_on,
+                    outer_static,
+                    colon_static,
+                    &display,
+                )
+                .await
             }
         };
     }
@@ -295,28 +357,47 @@ async fn device_loop(outer_static: &'static Led4OuterStatic, display: Led4Simple
 
 async fn run_text_loop(
     mut blink_state: BlinkState,
-    text: [char; CELL_COUNT],
+    bit_matrix: BitMatrixLed4,
+    is_colon_on: &mut bool,
     outer_static: &'static Led4OuterStatic,
+    colon_static: &'static Led4ColonStatic,
     display: &Led4Simple<'_>,
 ) -> Led4Command {
     loop {
         match blink_state {
             BlinkState::Solid => {
-                display.write_text(text);
-                return outer_static.wait().await;
+                display.write_bit_matrix(bit_matrix.with_colon(*is_colon_on));
+                match select(outer_static.wait(), colon_static.wait()).await {
+                    Either::First(command) => return command,
+                    Either::Second(is_on) => *is_colon_on = is_on,
+                }
             }
             BlinkState::BlinkingAndOn => {
-                display.write_text(text);
-                match select(outer_static.wait(), Timer::after(BLINK_ON_DELAY)).await {
-                    Either::First(command) => return command,
-                    Either::Second(()) => blink_state = BlinkState::BlinkingButOff,
+                display.write_bit_matrix(bit_matrix.with_colon(*is_colon_on));
+                match select3(
+                    outer_static.wait(),
+                    colon_static.wait(),
+                    Timer::after(BLINK_ON_DELAY),
+                )
+                .await
+                {
+                    Either3::First(command) => return command,
+                    Either3::Second(is_on) => *is_colon_on = is_on,
+                    Either3::Third(()) => blink_state = BlinkState::BlinkingButOff,
                 }
             }
             BlinkState::BlinkingButOff => {
-                display.write_text([' '; CELL_COUNT]);
-                match select(outer_static.wait(), Timer::after(BLINK_OFF_DELAY)).await {
-                    Either::First(command) => return command,
-                    Either::Second(()) => blink_state = BlinkState::BlinkingAndOn,
+                display.write_bit_matrix(BitMatrixLed4::default());
+                match select3(
+                    outer_static.wait(),
+                    colon_static.wait(),
+                    Timer::after(BLINK_OFF_DELAY),
+                )
+                .await
+                {
+                    Either3::First(command) => return command,
+                    Either3::Second(is_on) => *is_colon_on = is_on,
+                    Either3::Third(()) => blink_state = BlinkState::BlinkingAndOn,
                 }
             }
         }
@@ -325,7 +406,9 @@ async fn run_text_loop(
 
 async fn run_animation_loop(
     animation: Vec<AnimationFrame, ANIMATION_MAX_FRAMES>,
+    is_colon_on: &mut bool,
     outer_static: &'static Led4OuterStatic,
+    colon_static: &'static Led4ColonStatic,
     display: &Led4Simple<'_>,
 ) -> Led4Command {
     if animation.is_empty() {
@@ -338,10 +421,18 @@ async fn run_animation_loop(
 
     loop {
         let frame = frames[index];
-        display.write_text(frame.text);
-        match select(outer_static.wait(), Timer::after(frame.duration)).await {
-            Either::First(command) => return command,
-            Either::Second(()) => {
+        display.write_bit_matrix(BitMatrixLed4::from_text(&frame.text).with_colon(*is_colon_on));
+        match select3(
+            outer_static.wait(),
+            colon_static.wait(),
+            Timer::after(frame.duration),
+        )
+        .await
+        {
+            Either3::First(command) => return command,
+            // Redraw the same frame with the new colon; its full duration restarts.
+            Either3::Second(is_on) => *is_colon_on = is_on,
+            Either3::Third(()) => {
                 index = (index + 1) % len;
             }
         }
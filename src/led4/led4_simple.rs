@@ -65,11 +65,9 @@ impl Led4Simple<'_> {
         Ok(Self(led4_simple_static))
     }
 
-    /// Sends text to the display.
-    pub fn write_text(&self, text: [char; CELL_COUNT]) {
-        #[cfg(feature = "display-trace")]
-        info!("write_chars: {:?}", text);
-        self.0.signal(BitMatrixLed4::from_text(&text));
+    /// Sends raw segment bits to the display.
+    pub(crate) fn write_bit_matrix(&self, bit_matrix: BitMatrixLed4) {
+        self.0.signal(bit_matrix);
     }
 }
 
@@ -28,6 +28,7 @@
 //! - [`led_strip!`](macro@crate::led_strip) — Macro to generate an LED strip struct type (includes syntax details). See [`LedStripGenerated`](led_strip_generated::LedStripGenerated) for a sample of a generated type.
 //! - [`LedStripGenerated`](led_strip_generated::LedStripGenerated) — Sample struct type showing all methods and associated constants.
 //! - [`Frame1d`] — 1D pixel array used to describe LED strip patterns.
+//! - [`LedStripHandle`] — Copyable handle for writing to one strip from several tasks.
 //! - [`led_strips!`](crate::led_strips) — Alternative macro to share a PIO resource with other strips or panels (includes examples).
 //!
 //! # Example: Write a Single 1-Dimensional Frame
@@ -155,6 +156,9 @@ pub use smart_leds::RGB8;
 /// All examples in this crate use `smart_leds::colors::*` as the single source
 /// of named colors; when an embedded-graphics API needs [`Rgb888`], convert with
 /// [`ToRgb888::to_rgb888`].
+///
+/// For signaling and lighting names that CSS lacks (`AMBER`, `WARM_WHITE`, ...), see
+/// [`extended_colors`].
 #[doc(inline)]
 pub use smart_leds::colors;
 
@@ -280,6 +284,11 @@ pub enum Gamma {
     Srgb,
     /// Compatibility with the historical `smart_leds::gamma()` curve (2.8).
     SmartLeds,
+    /// A custom lookup table, where `table[value]` is the corrected value.
+    ///
+    /// Generate one for any exponent with `led_strip::host::generate_gamma_table_f64`
+    /// in a build script.
+    Custom(&'static [u8; 256]),
 }
 
 impl Default for Gamma {
@@ -357,6 +366,7 @@ pub const fn generate_combo_table(gamma: Gamma, max_brightness: u8) -> [u8; 256]
         Gamma::Linear => &LINEAR_TABLE,
         Gamma::Srgb => &GAMMA_SRGB_TABLE,
         Gamma::SmartLeds => &GAMMA_SMARTLEDS_TABLE,
+        Gamma::Custom(table) => table,
     };
     let mut result = [0u8; 256];
     let mut index = 0;
@@ -371,10 +381,12 @@ pub const fn generate_combo_table(gamma: Gamma, max_brightness: u8) -> [u8; 256]
 }
 
 #[cfg(not(feature = "host"))]
-use core::cell::RefCell;
+use core::cell::{Cell, RefCell};
 #[cfg(not(feature = "host"))]
 use embassy_futures::select::{Either, select};
 #[cfg(not(feature = "host"))]
+use embassy_rp::gpio::Pin as _;
+#[cfg(not(feature = "host"))]
 use embassy_rp::pio::{Common, Instance};
 #[cfg(not(feature = "host"))]
 use embassy_rp::pio_programs::ws2812::{PioWs2812, PioWs2812Program};
@@ -383,6 +395,8 @@ use embassy_sync::blocking_mutex::Mutex;
 #[cfg(not(feature = "host"))]
 use embassy_sync::blocking_mutex::raw::CriticalSectionRawMutex;
 #[cfg(not(feature = "host"))]
+use embassy_sync::channel::DynamicReceiver;
+#[cfg(not(feature = "host"))]
 #[cfg(not(feature = "host"))]
 use embassy_sync::once_lock::OnceLock;
 #[cfg(not(feature = "host"))]
@@ -391,6 +405,8 @@ use embassy_sync::signal::Signal;
 use embassy_time::{Duration, Timer};
 #[cfg(not(feature = "host"))]
 use heapless::Vec;
+#[cfg(not(feature = "host"))]
+use portable_atomic::{AtomicBool, AtomicU8, Ordering};
 
 #[cfg(not(feature = "host"))]
 use crate::Result;
@@ -399,6 +415,12 @@ use crate::Result;
 // Submodules
 // ============================================================================
 
+#[cfg(not(feature = "host"))]
+pub mod color_order;
+pub mod extended_colors;
+pub mod flash_animation;
+#[cfg(feature = "host")]
+pub mod host;
 pub mod led_strip_generated;
 
 /// 1D pixel array used to describe LED strip patterns.
@@ -428,6 +450,71 @@ impl<const N: usize> Frame1d<N> {
     pub const fn filled(color: RGB8) -> Self {
         Self([color; N])
     }
+
+    /// Create a frame by repeating a shorter `tile` frame end to end.
+    ///
+    /// For example, a 4-pixel gradient repeated 6 times fills a 24-LED strip.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `M` is 0 or does not divide `N` exactly.
+    ///
+    /// See the [`led_strip` module documentation](mod@crate::led_strip) for usage examples.
+    #[must_use]
+    pub const fn repeat<const M: usize>(tile: Frame1d<M>) -> Self {
+        Self::repeat_with_offset(tile, 0)
+    }
+
+    /// Create a frame by repeating `tile`, shifted `offset` pixels toward the end of the strip.
+    ///
+    /// Pixels shifted off the end wrap around to the start, so incrementing `offset`
+    /// once per animation frame slides the pattern along the strip.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `M` is 0 or does not divide `N` exactly.
+    ///
+    /// See the [`led_strip` module documentation](mod@crate::led_strip) for usage examples.
+    #[must_use]
+    pub const fn repeat_with_offset<const M: usize>(tile: Frame1d<M>, offset: usize) -> Self {
+        assert!(M > 0, "tile must not be empty");
+        assert!(
+            N.is_multiple_of(M),
+            "tile length must divide frame length exactly"
+        );
+        let shift = M - offset % M;
+        let mut frame = Self::new();
+        let mut led_index = 0;
+        while led_index < N {
+            frame.0[led_index] = tile.0[(led_index + shift) % M];
+            led_index += 1;
+        }
+        frame
+    }
+
+    /// Reshape the frame into a `W`×`H` panel frame, filling row by row, top row first.
+    ///
+    /// This is the inverse of
+    /// [`Frame2d::as_frame1d_row_major`](crate::led2d::Frame2d::as_frame1d_row_major):
+    /// use it to draw with the [`Frame2d`](crate::led2d::Frame2d) API on a strip that is
+    /// wired row-major, then flatten the result back.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is not `W * H`. In a const context, this is a compile error.
+    #[must_use]
+    pub const fn as_frame2d_row_major<const W: usize, const H: usize>(
+        &self,
+    ) -> crate::led2d::Frame2d<W, H> {
+        assert!(N == W * H, "N must equal W * H");
+        let mut frame = crate::led2d::Frame2d::new();
+        let mut led_index = 0;
+        while led_index < N {
+            frame.0[led_index / W][led_index % W] = self.0[led_index];
+            led_index += 1;
+        }
+        frame
+    }
 }
 
 impl<const N: usize> Deref for Frame1d<N> {
@@ -462,6 +549,266 @@ impl<const N: usize> Default for Frame1d<N> {
     }
 }
 
+/// Logs a one-line summary rather than every pixel, such as
+/// `Frame1d<60>: 3 of 60 lit, first at 2 = (255, 0, 0), checksum 0x5d2e8a41`.
+///
+/// The checksum tells frames with the same lit count apart. Use
+/// [`verbose`](Frame1d::verbose) to log every pixel.
+impl<const N: usize> defmt::Format for Frame1d<N> {
+    fn format(&self, fmt: defmt::Formatter<'_>) {
+        let summary = FrameSummary::new(&self.0);
+        defmt::write!(fmt, "Frame1d<{=usize}>: ", N);
+        match summary.first_lit {
+            None => defmt::write!(fmt, "all black"),
+            Some((led_index, color)) => defmt::write!(
+                fmt,
+                "{=usize} of {=usize} lit, first at {=usize} = ({=u8}, {=u8}, {=u8}), checksum {=u32:#010x}",
+                summary.lit_count,
+                N,
+                led_index,
+                color.r,
+                color.g,
+                color.b,
+                summary.checksum
+            ),
+        }
+    }
+}
+
+impl<const N: usize> Frame1d<N> {
+    /// Wrap the frame so `defmt` logs every pixel instead of a summary.
+    ///
+    /// A long strip floods RTT, so use this for short strips or one-off debugging:
+    /// `defmt::info!("{}", frame.verbose())`.
+    #[must_use]
+    pub fn verbose(&self) -> impl defmt::Format + '_ {
+        VerboseFrame1d(self)
+    }
+}
+
+struct VerboseFrame1d<'a, const N: usize>(&'a Frame1d<N>);
+
+impl<const N: usize> defmt::Format for VerboseFrame1d<'_, N> {
+    fn format(&self, fmt: defmt::Formatter<'_>) {
+        defmt::write!(fmt, "Frame1d<{=usize}> [", N);
+        write_colors(fmt, &self.0.0);
+        defmt::write!(fmt, "]");
+    }
+}
+
+// Lit-pixel count, first lit pixel, and an FNV-1a checksum of a frame's pixels, for the
+// compact `defmt::Format` output of `Frame1d` and `Frame2d`.
+pub(crate) struct FrameSummary {
+    pub(crate) lit_count: usize,
+    pub(crate) first_lit: Option<(usize, RGB8)>,
+    pub(crate) checksum: u32,
+}
+
+impl FrameSummary {
+    pub(crate) fn new(pixels: &[RGB8]) -> Self {
+        let mut summary = Self {
+            lit_count: 0,
+            first_lit: None,
+            checksum: 0x811c_9dc5,
+        };
+        for (led_index, &color) in pixels.iter().enumerate() {
+            if color != RGB8::default() {
+                summary.lit_count = summary.lit_count.saturating_add(1);
+                summary.first_lit.get_or_insert((led_index, color));
+            }
+            for byte in [color.r, color.g, color.b] {
+                summary.checksum = (summary.checksum ^ u32::from(byte)).wrapping_mul(0x0100_0193);
+            }
+        }
+        summary
+    }
+}
+
+// Writes `(r, g, b)` for each pixel, separated by commas.
+pub(crate) fn write_colors(fmt: defmt::Formatter<'_>, pixels: &[RGB8]) {
+    for (led_index, color) in pixels.iter().enumerate() {
+        if led_index > 0 {
+            defmt::write!(fmt, ", ");
+        }
+        defmt::write!(fmt, "({=u8}, {=u8}, {=u8})", color.r, color.g, color.b);
+    }
+}
+
+/// Host-only hook for observing the frames an LED strip would send to hardware.
+///
+/// Under `feature = "host"`, LED strips drive no hardware. Implement this trait to
+/// collect the frames passed to `write_frame` and `animate` so tests can assert on them.
+/// The unit type `()` is the default, no-op observer.
+///
+/// Frames are reported before gamma correction and current limiting. Animations are
+/// reported once, in order; durations are ignored.
+#[cfg(feature = "host")]
+pub trait FrameObserver<const N: usize>: Sync {
+    /// Called once for each frame the strip would display.
+    fn on_frame(&self, frame: &Frame1d<N>);
+}
+
+#[cfg(feature = "host")]
+impl<const N: usize> FrameObserver<N> for () {
+    fn on_frame(&self, _frame: &Frame1d<N>) {}
+}
+
+/// Host-only source of time for the stub LED strip's animation timing.
+///
+/// On hardware, animations advance with `embassy_time` timers. Under `feature = "host"`,
+/// the stub strip reads the time from this trait instead, so tests can control it.
+/// The unit type `()` is the default clock, frozen at time zero. Use [`VirtualClock`]
+/// to advance time by hand.
+#[cfg(feature = "host")]
+pub trait AnimationClock: Sync {
+    /// Returns the current time.
+    fn now(&self) -> embassy_time::Instant;
+}
+
+#[cfg(feature = "host")]
+impl AnimationClock for () {
+    fn now(&self) -> embassy_time::Instant {
+        embassy_time::Instant::from_ticks(0)
+    }
+}
+
+/// Host-only clock that starts at zero and moves only when [`advance`](Self::advance)
+/// is called.
+///
+/// Pass it to `LedStrip::new_with_clock` to test animation timing deterministically,
+/// for example "after 300 ms the second frame is displayed".
+#[cfg(feature = "host")]
+pub struct VirtualClock {
+    ticks: core::sync::atomic::AtomicU64,
+}
+
+#[cfg(feature = "host")]
+impl VirtualClock {
+    /// Creates a clock at time zero.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            ticks: core::sync::atomic::AtomicU64::new(0),
+        }
+    }
+
+    /// Moves the clock forward by `duration`.
+    pub fn advance(&self, duration: embassy_time::Duration) {
+        self.ticks
+            .fetch_add(duration.as_ticks(), core::sync::atomic::Ordering::Relaxed);
+    }
+}
+
+#[cfg(feature = "host")]
+impl Default for VirtualClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "host")]
+impl AnimationClock for VirtualClock {
+    fn now(&self) -> embassy_time::Instant {
+        embassy_time::Instant::from_ticks(self.ticks.load(core::sync::atomic::Ordering::Relaxed))
+    }
+}
+
+// ============================================================================
+// Fire Effect
+// ============================================================================
+
+#[doc(hidden)] // Shared by strip and panel `fire` methods; not intended as surface API
+/// Heat state for the classic heat-diffusion fire effect along one line of cells.
+///
+/// Index 0 is the base of the flame. Each [`step`](Self::step) cools every cell, lets
+/// heat drift away from the base, and may ignite a spark near the base.
+#[derive(Clone, Copy)]
+pub struct FireHeat<const N: usize> {
+    heat: [u8; N],
+    rng_state: u32,
+}
+
+impl<const N: usize> FireHeat<N> {
+    /// Creates a cold flame whose sparks are drawn from a xorshift generator seeded with
+    /// `seed` (a zero seed is replaced, since xorshift would stay at zero).
+    #[must_use]
+    pub const fn new(seed: u32) -> Self {
+        Self {
+            heat: [0; N],
+            rng_state: if seed == 0 { 0x9E37_79B9 } else { seed },
+        }
+    }
+
+    /// Advances the flame by one frame.
+    ///
+    /// `cooling` sets how quickly heat fades as it rises (larger values give shorter
+    /// flames); `sparking` is the chance, out of 255, that a new spark ignites this frame.
+    pub fn step(&mut self, cooling: u8, sparking: u8) {
+        if N == 0 {
+            return;
+        }
+
+        let max_cooling = u32::from(cooling) * 10 / u32::try_from(N).unwrap_or(u32::MAX) + 2;
+        for cell_index in 0..N {
+            let cool = u8::try_from(self.next_u32() % max_cooling).unwrap_or(u8::MAX);
+            self.heat[cell_index] = self.heat[cell_index].saturating_sub(cool);
+        }
+
+        for cell_index in (2..N).rev() {
+            let sum =
+                u16::from(self.heat[cell_index - 1]) + 2 * u16::from(self.heat[cell_index - 2]);
+            self.heat[cell_index] = u8::try_from(sum / 3).unwrap_or(u8::MAX);
+        }
+
+        if self.next_u8() < sparking {
+            let spark_index = usize::from(self.next_u8()) % N.min(7);
+            let spark_heat = 160 + self.next_u8() % 96;
+            self.heat[spark_index] = self.heat[spark_index].saturating_add(spark_heat);
+        }
+    }
+
+    /// Returns the current heat of each cell, base first.
+    #[must_use]
+    pub const fn heat(&self) -> &[u8; N] {
+        &self.heat
+    }
+
+    /// Returns the flame as a frame, with index 0 as the base.
+    #[must_use]
+    pub fn to_frame(&self) -> Frame1d<N> {
+        Frame1d(self.heat.map(heat_to_color))
+    }
+
+    const fn next_u32(&mut self) -> u32 {
+        let mut state = self.rng_state;
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        self.rng_state = state;
+        state
+    }
+
+    const fn next_u8(&mut self) -> u8 {
+        self.next_u32().to_le_bytes()[0]
+    }
+}
+
+#[doc(hidden)] // Shared by strip and panel `fire` methods; not intended as surface API
+/// Maps heat to the fire palette: black, then red, then yellow, then white.
+#[must_use]
+pub const fn heat_to_color(heat: u8) -> RGB8 {
+    // Scale to 0..=189 so the palette splits into three 64-step ramps.
+    let scaled = heat / 4 * 3;
+    let ramp = (scaled & 0x3F) << 2;
+    if scaled & 0x80 != 0 {
+        RGB8::new(255, 255, ramp)
+    } else if scaled & 0x40 != 0 {
+        RGB8::new(255, ramp, 0)
+    } else {
+        RGB8::new(ramp, 0, 0)
+    }
+}
+
 // ============================================================================
 // PIO Bus - Shared PIO resource for multiple LED strips
 // ============================================================================
@@ -568,8 +915,122 @@ impl<'d, PIO: Instance> PioBus<'d, PIO> {
 #[cfg(not(feature = "host"))]
 #[cfg(not(feature = "host"))]
 #[doc(hidden)] // Required pub for macro expansion in downstream crates
-pub type LedStripCommandSignal<const N: usize, const MAX_FRAMES: usize> =
-    Signal<CriticalSectionRawMutex, Command<N, MAX_FRAMES>>;
+/// Commands from strip handles to the strip's device task, plus whether the task is idle
+/// (showing a static frame with no command pending) and the runtime brightness.
+pub struct LedStripCommandSignal<const N: usize, const MAX_FRAMES: usize> {
+    command: Signal<CriticalSectionRawMutex, Command<N, MAX_FRAMES>>,
+    // Updated together with `command` in one critical section, so `signal` and
+    // `mark_idle` cannot interleave.
+    idle: Mutex<CriticalSectionRawMutex, Cell<bool>>,
+    became_idle: Signal<CriticalSectionRawMutex, ()>,
+    shut_down: AtomicBool,
+    brightness: AtomicU8,
+    brightness_changed: Signal<CriticalSectionRawMutex, ()>,
+}
+
+#[cfg(not(feature = "host"))]
+impl<const N: usize, const MAX_FRAMES: usize> LedStripCommandSignal<N, MAX_FRAMES> {
+    const fn new() -> Self {
+        Self {
+            command: Signal::new(),
+            idle: Mutex::new(Cell::new(true)),
+            became_idle: Signal::new(),
+            shut_down: AtomicBool::new(false),
+            brightness: AtomicU8::new(u8::MAX),
+            brightness_changed: Signal::new(),
+        }
+    }
+
+    // Commands sent after shutdown are dropped, so the strip stays idle.
+    fn signal(&self, command: Command<N, MAX_FRAMES>) {
+        if self.shut_down.load(Ordering::Acquire) {
+            return;
+        }
+        self.idle.lock(|idle| {
+            idle.set(false);
+            self.command.signal(command);
+        });
+    }
+
+    // Shutdown sets the flag before signaling, so a command that replaces it in the
+    // latest-wins signal still stops the task.
+    fn shutdown(&self) {
+        self.shut_down.store(true, Ordering::Release);
+        self.idle.lock(|idle| {
+            idle.set(false);
+            self.command.signal(Command::Shutdown);
+        });
+    }
+
+    async fn wait(&self) -> Command<N, MAX_FRAMES> {
+        let command = self.command.wait().await;
+        if self.shut_down.load(Ordering::Acquire) {
+            Command::Shutdown
+        } else {
+            command
+        }
+    }
+
+    fn reset(&self) {
+        self.command.reset();
+    }
+
+    // Called by the device task after it shows a static frame. A command that arrived
+    // meanwhile keeps the strip busy.
+    fn mark_idle(&self) {
+        self.idle.lock(|idle| {
+            if !self.command.signaled() {
+                idle.set(true);
+                self.became_idle.signal(());
+            }
+        });
+    }
+
+    fn mark_shut_down(&self) {
+        self.idle.lock(|idle| {
+            self.command.reset();
+            idle.set(true);
+        });
+        self.became_idle.signal(());
+    }
+
+    fn is_idle(&self) -> bool {
+        self.idle.lock(Cell::get)
+    }
+
+    async fn wait_idle(&self) {
+        while !self.is_idle() {
+            self.became_idle.wait().await;
+        }
+    }
+
+    fn set_brightness(&self, brightness: u8) {
+        if self.brightness.swap(brightness, Ordering::AcqRel) != brightness {
+            self.brightness_changed.signal(());
+        }
+    }
+
+    fn brightness(&self) -> u8 {
+        self.brightness.load(Ordering::Acquire)
+    }
+
+    async fn wait_brightness_changed(&self) {
+        self.brightness_changed.wait().await;
+    }
+}
+
+/// Signal that starts an animation armed with `animate_on_signal`. Signal it from the
+/// task that sees the external event, such as a button press or a sensor threshold.
+pub type AnimationTrigger =
+    embassy_sync::signal::Signal<embassy_sync::blocking_mutex::raw::CriticalSectionRawMutex, ()>;
+
+/// Queue of up to `DEPTH` frames played in order by `stream`. Senders wait while it is
+/// full, so no frame is dropped.
+pub type FrameChannel<const N: usize, const DEPTH: usize> = embassy_sync::channel::Channel<
+    embassy_sync::blocking_mutex::raw::CriticalSectionRawMutex,
+    Frame1d<N>,
+    DEPTH,
+>;
 
 #[cfg(not(feature = "host"))]
 #[cfg(not(feature = "host"))]
@@ -578,7 +1039,28 @@ pub type LedStripCommandSignal<const N: usize, const MAX_FRAMES: usize> =
 #[derive(Clone)]
 pub enum Command<const N: usize, const MAX_FRAMES: usize> {
     DisplayStatic(Frame1d<N>),
-    Animate(Vec<(Frame1d<N>, Duration), MAX_FRAMES>),
+    DisplayBorrowed(&'static Frame1d<N>),
+    Animate(Vec<(Frame1d<N>, Duration), MAX_FRAMES>, Option<fn(usize)>),
+    AnimateBorrowed(&'static [(Frame1d<N>, Duration)]),
+    AnimateOnTrigger(
+        Vec<(Frame1d<N>, Duration), MAX_FRAMES>,
+        &'static AnimationTrigger,
+    ),
+    AnimateGenerated {
+        frame_count: usize,
+        frame_duration: Duration,
+        generate: fn(usize) -> Frame1d<N>,
+    },
+    Fire {
+        cooling: u8,
+        sparking: u8,
+        frame_duration: Duration,
+    },
+    Stream {
+        frames: DynamicReceiver<'static, Frame1d<N>>,
+        frame_duration: Duration,
+    },
+    Shutdown,
 }
 
 /// Static used to construct LED strip instances with animation support.
@@ -586,8 +1068,14 @@ pub enum Command<const N: usize, const MAX_FRAMES: usize> {
 #[doc(hidden)] // Must be pub for method signatures and macro expansion in downstream crates
 pub struct LedStripStatic<const N: usize, const MAX_FRAMES: usize> {
     command_signal: LedStripCommandSignal<N, MAX_FRAMES>,
+    current_frame: CurrentFrame<N>,
 }
 
+// The last frame written with `write_frame`, `write_static_frame`, or `write_segment`,
+// which `write_segment` merges into.
+#[cfg(not(feature = "host"))]
+type CurrentFrame<const N: usize> = Mutex<CriticalSectionRawMutex, RefCell<Frame1d<N>>>;
+
 #[cfg(not(feature = "host"))]
 impl<const N: usize, const MAX_FRAMES: usize> LedStripStatic<N, MAX_FRAMES> {
     /// Creates static resources.
@@ -595,7 +1083,8 @@ impl<const N: usize, const MAX_FRAMES: usize> LedStripStatic<N, MAX_FRAMES> {
     #[doc(hidden)]
     pub const fn new_static() -> Self {
         Self {
-            command_signal: Signal::new(),
+            command_signal: LedStripCommandSignal::new(),
+            current_frame: Mutex::new(RefCell::new(Frame1d::new())),
         }
     }
 
@@ -614,6 +1103,7 @@ impl<const N: usize, const MAX_FRAMES: usize> LedStripStatic<N, MAX_FRAMES> {
 /// See [`led_strip!`] macro documentation for usage.
 pub struct LedStrip<const N: usize, const MAX_FRAMES: usize> {
     command_signal: &'static LedStripCommandSignal<N, MAX_FRAMES>,
+    current_frame: &'static CurrentFrame<N>,
 }
 
 #[cfg(not(feature = "host"))]
@@ -625,33 +1115,579 @@ impl<const N: usize, const MAX_FRAMES: usize> LedStrip<N, MAX_FRAMES> {
         LedStripStatic::new_static()
     }
 
-    /// Creates a new LED strip controller bound to the given static resources.
-    pub fn new(led_strip_static: &'static LedStripStatic<N, MAX_FRAMES>) -> Result<Self> {
-        Ok(Self {
-            command_signal: led_strip_static.command_signal(),
-        })
+    /// Creates a new LED strip controller bound to the given static resources.
+    pub fn new(led_strip_static: &'static LedStripStatic<N, MAX_FRAMES>) -> Result<Self> {
+        Ok(Self {
+            command_signal: led_strip_static.command_signal(),
+            current_frame: &led_strip_static.current_frame,
+        })
+    }
+
+    /// Returns a copyable handle that can write to this strip from other tasks.
+    ///
+    /// See [`LedStripHandle`] for details.
+    #[must_use]
+    pub const fn handle(&self) -> LedStripHandle<N, MAX_FRAMES> {
+        LedStripHandle {
+            command_signal: self.command_signal,
+            current_frame: self.current_frame,
+        }
+    }
+
+    /// Writes a full frame to the LED strip. It remains displayed until another command
+    /// replaces it.
+    ///
+    /// See the [led_strip module documentation](mod@crate::led_strip) for example usage.
+    pub fn write_frame(&self, frame: Frame1d<N>) -> Result<()> {
+        self.handle().write_frame(frame)
+    }
+
+    /// Like [`write_frame`](Self::write_frame), but passes only a reference to a frame
+    /// in static memory.
+    ///
+    /// See [`LedStripHandle::write_static_frame`] for details.
+    pub fn write_static_frame(&self, frame: &'static Frame1d<N>) -> Result<()> {
+        self.handle().write_static_frame(frame)
+    }
+
+    /// Overwrite the LEDs starting at `start_led` with `pixels`, leaving the rest of the
+    /// last written frame unchanged.
+    ///
+    /// See [`LedStripHandle::write_segment`] for details.
+    pub fn write_segment(&self, pixels: &[RGB8], start_led: usize) -> Result<()> {
+        self.handle().write_segment(pixels, start_led)
+    }
+
+    /// Loop forever through a sequence of animation frames.
+    /// They remain displayed until another command replaces them.
+    ///
+    /// Each frame is a tuple of `(Frame1d, Duration)`. Accepts arrays, `Vec`s, or any
+    /// iterator that produces `(Frame1d, Duration)` tuples or references to them, so a
+    /// `const` or `static` sequence can be passed by reference (`animate(&FRAMES)`) and
+    /// reused without being consumed.
+    ///
+    /// Returns immediately; the animation runs in the background until interrupted
+    /// by a new `animate` call or `write_frame`.
+    ///
+    /// See the [led_strip module documentation](mod@crate::led_strip) for example usage.
+    pub fn animate<I>(&self, frames: I) -> Result<()>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<(Frame1d<N>, Duration)>,
+    {
+        self.handle().animate(frames)
+    }
+
+    /// Like [`animate`](Self::animate), but calls `callback(frame_index)` each time a frame
+    /// has been written to the hardware.
+    ///
+    /// See [`LedStripHandle::animate_with_callback`] for details.
+    pub fn animate_with_callback<I>(&self, frames: I, callback: fn(usize)) -> Result<()>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<(Frame1d<N>, Duration)>,
+    {
+        self.handle().animate_with_callback(frames, callback)
+    }
+
+    /// Like [`animate`](Self::animate), but shows every frame for the same
+    /// `frame_duration`.
+    ///
+    /// See [`LedStripHandle::animate_uniform`] for details.
+    pub fn animate_uniform<I>(&self, frames: I, frame_duration: Duration) -> Result<()>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Frame1d<N>>,
+    {
+        self.handle().animate_uniform(frames, frame_duration)
+    }
+
+    /// Like [`animate`](Self::animate), but passes only a reference to frames in static
+    /// memory.
+    ///
+    /// See [`LedStripHandle::animate_static_frames`] for details.
+    pub fn animate_static_frames(&self, frames: &'static [(Frame1d<N>, Duration)]) -> Result<()> {
+        self.handle().animate_static_frames(frames)
+    }
+
+    /// Like [`animate`](Self::animate), but the animation starts only when `trigger` is
+    /// signaled.
+    ///
+    /// See [`LedStripHandle::animate_on_signal`] for details.
+    pub fn animate_on_signal<I>(&self, frames: I, trigger: &'static AnimationTrigger) -> Result<()>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<(Frame1d<N>, Duration)>,
+    {
+        self.handle().animate_on_signal(frames, trigger)
+    }
+
+    /// Loop forever through `frame_count` frames that `generate(frame_index)` computes on
+    /// demand, showing each for `frame_duration`.
+    ///
+    /// See [`LedStripHandle::animate_generated`] for details.
+    pub fn animate_generated(
+        &self,
+        frame_count: usize,
+        frame_duration: Duration,
+        generate: fn(usize) -> Frame1d<N>,
+    ) -> Result<()> {
+        self.handle()
+            .animate_generated(frame_count, frame_duration, generate)
+    }
+
+    /// Play frames from `frames` in order as they arrive, showing each for
+    /// `frame_duration`.
+    ///
+    /// See [`LedStripHandle::stream`] for details.
+    pub fn stream<const DEPTH: usize>(
+        &self,
+        frames: &'static FrameChannel<N, DEPTH>,
+        frame_duration: Duration,
+    ) -> Result<()> {
+        self.handle().stream(frames, frame_duration)
+    }
+
+    /// Show a flickering fire that rises from LED 0, computing a new frame every `speed`.
+    ///
+    /// See [`LedStripHandle::fire`] for details.
+    pub fn fire(&self, cooling: u8, sparking: u8, speed: Duration) -> Result<()> {
+        self.handle().fire(cooling, sparking, speed)
+    }
+
+    /// Returns `true` while the strip is running an animation or finishing a command.
+    ///
+    /// See [`LedStripHandle::is_animating`] for details.
+    #[must_use]
+    pub fn is_animating(&self) -> bool {
+        self.handle().is_animating()
+    }
+
+    /// Wait until the strip shows a static frame, with no animation running and no
+    /// command pending.
+    ///
+    /// See [`LedStripHandle::wait_idle`] for details.
+    pub async fn wait_idle(&self) {
+        self.handle().wait_idle().await;
+    }
+
+    /// Scale the strip's output by `brightness` out of 255, on top of the `max_current`
+    /// limit.
+    ///
+    /// See [`LedStripHandle::set_brightness`] for details.
+    pub fn set_brightness(&self, brightness: u8) {
+        self.handle().set_brightness(brightness);
+    }
+
+    /// The brightness last set with [`set_brightness`](Self::set_brightness).
+    #[must_use]
+    pub fn brightness(&self) -> u8 {
+        self.handle().brightness()
+    }
+
+    /// Follow ambient light, setting the brightness from `sensor` every `poll_interval`.
+    /// Never returns.
+    ///
+    /// See [`LedStripHandle::ambient_mode`] for details.
+    pub async fn ambient_mode(&self, sensor: fn() -> u8, poll_interval: Duration) -> ! {
+        self.handle().ambient_mode(sensor, poll_interval).await
+    }
+
+    /// Turn the LEDs off and stop the strip's background task.
+    ///
+    /// See [`LedStripHandle::shutdown`] for details.
+    pub fn shutdown(&self) {
+        self.handle().shutdown();
+    }
+}
+
+/// A copyable handle for writing to one LED strip from several tasks.
+///
+/// Get a handle with the generated strip type's `handle()` method. A handle holds only
+/// references to the strip's statics, so copying it is cheap. Writes from different
+/// handles serialize naturally: the last write wins. To give each task its own range of
+/// LEDs instead, use [`write_segment`](Self::write_segment).
+///
+/// See the [led_strip module documentation](mod@crate::led_strip) for example usage of
+/// `write_frame` and `animate`.
+#[cfg(not(feature = "host"))]
+#[derive(Clone, Copy)]
+pub struct LedStripHandle<const N: usize, const MAX_FRAMES: usize> {
+    command_signal: &'static LedStripCommandSignal<N, MAX_FRAMES>,
+    current_frame: &'static CurrentFrame<N>,
+}
+
+#[cfg(not(feature = "host"))]
+impl<const N: usize, const MAX_FRAMES: usize> LedStripHandle<N, MAX_FRAMES> {
+    /// Writes a full frame to the LED strip. It remains displayed until another command
+    /// replaces it.
+    ///
+    /// Never blocks. Commands are not queued: the strip holds one pending command, and a
+    /// newer command replaces it. If frames arrive faster than the strip can show them
+    /// (for example, live video), the device task skips stale frames and always shows the
+    /// newest one, so the display never falls behind.
+    ///
+    /// See the [led_strip module documentation](mod@crate::led_strip) for example usage.
+    pub fn write_frame(&self, frame: Frame1d<N>) -> Result<()> {
+        self.current_frame.lock(|current_frame| {
+            *current_frame.borrow_mut() = frame;
+            self.command_signal.signal(Command::DisplayStatic(frame));
+        });
+        Ok(())
+    }
+
+    /// Like [`write_frame`](Self::write_frame), but passes only a reference to a frame
+    /// in static memory.
+    ///
+    /// The frame is never copied onto the caller's stack; the strip's device task copies it
+    /// when it is displayed. Use this for long strips and large panels, where each frame is
+    /// 3 bytes per LED. See the [`led2d` module documentation](mod@crate::led2d) for the
+    /// stack cost of frames and an example.
+    pub fn write_static_frame(&self, frame: &'static Frame1d<N>) -> Result<()> {
+        self.current_frame.lock(|current_frame| {
+            *current_frame.borrow_mut() = *frame;
+            self.command_signal.signal(Command::DisplayBorrowed(frame));
+        });
+        Ok(())
+    }
+
+    /// Overwrite the LEDs starting at `start_led` with `pixels`, leaving the rest of the
+    /// last written frame unchanged.
+    ///
+    /// This lets several tasks share one strip, each owning its own range of LEDs: for
+    /// example, a status indicator on LEDs 0–3 and a gauge on LEDs 4–47. The strip keeps
+    /// a copy of the last frame written with `write_frame`, `write_static_frame`, or
+    /// `write_segment` (all black at first); each segment is merged into that copy and the
+    /// result is written as a whole frame. The merge and the write happen together, so
+    /// segments written at the same time from different tasks are never lost. Animation
+    /// frames do not update the copy, so a segment written during an animation stops it and
+    /// shows the last written frame with the new segment.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::IndexOutOfBounds`](crate::Error::IndexOutOfBounds) if the segment
+    /// extends past the end of the strip.
+    pub fn write_segment(&self, pixels: &[RGB8], start_led: usize) -> Result<()> {
+        self.current_frame.lock(|current_frame| {
+            let mut current_frame = current_frame.borrow_mut();
+            start_led
+                .checked_add(pixels.len())
+                .and_then(|end_led| current_frame.get_mut(start_led..end_led))
+                .ok_or(crate::Error::IndexOutOfBounds)?
+                .copy_from_slice(pixels);
+            self.command_signal
+                .signal(Command::DisplayStatic(*current_frame));
+            Ok(())
+        })
+    }
+
+    /// Loop forever through a sequence of animation frames.
+    /// They remain displayed until another command replaces them.
+    ///
+    /// Each frame is a tuple of `(Frame1d, Duration)`. Accepts arrays, `Vec`s, or any
+    /// iterator that produces `(Frame1d, Duration)` tuples or references to them, so a
+    /// `const` or `static` sequence can be passed by reference (`animate(&FRAMES)`) and
+    /// reused without being consumed.
+    ///
+    /// Returns immediately; the animation runs in the background until interrupted
+    /// by a new `animate` call or `write_frame`. The interruption takes effect at once,
+    /// even partway through a long frame: the device task waits for each frame's duration
+    /// and for the next command together.
+    ///
+    /// See the [led_strip module documentation](mod@crate::led_strip) for example usage.
+    pub fn animate<I>(&self, frames: I) -> Result<()>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<(Frame1d<N>, Duration)>,
+    {
+        self.animate_frames(frames, None)
+    }
+
+    /// Like [`animate`](Self::animate), but calls `callback(frame_index)` each time a frame
+    /// has been written to the hardware.
+    ///
+    /// The callback runs in the strip's device task, right after the write and before the
+    /// frame's duration starts, so it must return quickly and must not block. It is a plain
+    /// function pointer (no captures) because the animation is handed to the device task
+    /// without allocation; reach shared state through statics such as atomics or signals.
+    ///
+    /// See the [led_strip module documentation](mod@crate::led_strip) for example usage of
+    /// `animate`.
+    pub fn animate_with_callback<I>(&self, frames: I, callback: fn(usize)) -> Result<()>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<(Frame1d<N>, Duration)>,
+    {
+        self.animate_frames(frames, Some(callback))
+    }
+
+    /// Like [`animate`](Self::animate), but shows every frame for the same
+    /// `frame_duration`, so the frames need not be paired with durations.
+    ///
+    /// Accepts arrays, `Vec`s, or any iterator that produces `Frame1d` values.
+    ///
+    /// See the [led_strip module documentation](mod@crate::led_strip) for example usage of
+    /// `animate`.
+    pub fn animate_uniform<I>(&self, frames: I, frame_duration: Duration) -> Result<()>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Frame1d<N>>,
+    {
+        self.animate(
+            frames
+                .into_iter()
+                .map(|frame| (*frame.borrow(), frame_duration)),
+        )
+    }
+
+    /// Like [`animate`](Self::animate), but passes only a reference to frames in static
+    /// memory.
+    ///
+    /// Unlike [`animate`](Self::animate), the frames are not gathered on the caller's stack
+    /// or buffered, so the sequence is not limited by `MAX_FRAMES` and works even with
+    /// `max_frames = 0`. The strip's device task copies one frame at a time as it
+    /// displays it. See the [`led2d` module documentation](mod@crate::led2d) for the stack
+    /// cost of frames and an example.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `frames` is empty or a duration is zero.
+    pub fn animate_static_frames(&self, frames: &'static [(Frame1d<N>, Duration)]) -> Result<()> {
+        assert!(!frames.is_empty(), "animation requires at least one frame");
+        assert!(
+            frames.iter().all(|(_, duration)| duration.as_micros() > 0),
+            "animation frame duration must be positive"
+        );
+        self.command_signal.signal(Command::AnimateBorrowed(frames));
+        Ok(())
+    }
+
+    /// Like [`animate`](Self::animate), but the animation starts only when `trigger` is
+    /// signaled.
+    ///
+    /// The frames are handed to the strip's device task right away, and the task itself
+    /// waits for `trigger`, so the first frame is written as soon as the external event
+    /// fires rather than after the waiting code wakes up and dispatches a command. Until
+    /// then the strip keeps showing what it showed before. Any earlier signal on `trigger`
+    /// is cleared when the animation is armed, and the task consumes the signal that starts
+    /// it, so the same trigger can arm the next animation. A new command sent before the
+    /// trigger fires replaces the armed animation.
+    ///
+    /// Declare the trigger as `static FLASH_TRIGGER: AnimationTrigger =
+    /// AnimationTrigger::new();` and call `FLASH_TRIGGER.signal(())` from the task that
+    /// detects the event. See the [led_strip module documentation](mod@crate::led_strip)
+    /// for example usage of `animate`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::AnimationDisabled`](crate::Error::AnimationDisabled) if
+    /// `MAX_FRAMES` is 0.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `frames` is empty, has more than `MAX_FRAMES` frames, or has a zero
+    /// duration.
+    pub fn animate_on_signal<I>(&self, frames: I, trigger: &'static AnimationTrigger) -> Result<()>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<(Frame1d<N>, Duration)>,
+    {
+        let sequence = Self::collect_frames(frames)?;
+        trigger.reset();
+        self.command_signal
+            .signal(Command::AnimateOnTrigger(sequence, trigger));
+        Ok(())
+    }
+
+    /// Loop forever through `frame_count` frames that `generate(frame_index)` computes on
+    /// demand, showing each for `frame_duration`. They remain displayed until another
+    /// command replaces them.
+    ///
+    /// Unlike [`animate`](Self::animate), no frames are buffered: the strip's device task
+    /// calls `generate` just before each frame is shown. This trades CPU time for RAM, so
+    /// long procedural animations (plasma, fire) are not limited by `MAX_FRAMES`, and work
+    /// even with `max_frames = 0`. `generate` must return well within `frame_duration`.
+    ///
+    /// `generate` is a plain function pointer (no captures) for the same reason as the
+    /// [`animate_with_callback`](Self::animate_with_callback) callback; derive each frame
+    /// from `frame_index` or reach shared state through statics.
+    ///
+    /// For an endless procedural effect that never repeats, pass `usize::MAX` as
+    /// `frame_count`: `frame_index` then counts up for as long as the animation runs, with
+    /// no per-frame calls from your code.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `frame_count` is 0 or `frame_duration` is zero.
+    ///
+    /// See the [led_strip module documentation](mod@crate::led_strip) for example usage of
+    /// `animate`.
+    pub fn animate_generated(
+        &self,
+        frame_count: usize,
+        frame_duration: Duration,
+        generate: fn(usize) -> Frame1d<N>,
+    ) -> Result<()> {
+        assert!(frame_count > 0, "animation requires at least one frame");
+        assert!(
+            frame_duration.as_micros() > 0,
+            "animation frame duration must be positive"
+        );
+        self.command_signal.signal(Command::AnimateGenerated {
+            frame_count,
+            frame_duration,
+            generate,
+        });
+        Ok(())
+    }
+
+    /// Show a flickering fire that rises from LED 0, computing a new frame every `speed`.
+    /// It runs until another command replaces it.
+    ///
+    /// This is the classic heat-diffusion fire: each frame, every LED cools a little, heat
+    /// drifts away from LED 0, and a spark may ignite among the first few LEDs. Heat is
+    /// shown on a black, red, yellow, white palette. `cooling` sets how quickly the flame
+    /// fades as it rises (around 55 suits a 30-LED strip; larger values give shorter
+    /// flames), and `sparking` is the chance, out of 255, of a new spark each frame (around
+    /// 120). Frames are computed by the strip's device task, so the effect needs no
+    /// animation buffer and works even with `max_frames = 0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `speed` is zero.
+    pub fn fire(&self, cooling: u8, sparking: u8, speed: Duration) -> Result<()> {
+        assert!(
+            speed.as_micros() > 0,
+            "animation frame duration must be positive"
+        );
+        self.command_signal.signal(Command::Fire {
+            cooling,
+            sparking,
+            frame_duration: speed,
+        });
+        Ok(())
+    }
+
+    /// Play frames from `frames` in order as they arrive, showing each for
+    /// `frame_duration`. Streaming continues until another command replaces it.
+    ///
+    /// Unlike [`write_frame`](Self::write_frame), which keeps only the newest frame, the
+    /// channel buffers up to `DEPTH` frames and the strip takes them first in, first out.
+    /// A task sending with `frames.send(frame).await` waits while the channel is full, so
+    /// no frame is dropped; this suits playing back stored or precomputed sequences. When
+    /// the channel runs empty, the last frame stays lit until the next one arrives.
+    ///
+    /// Declare the channel as `static FRAMES: FrameChannel<N, DEPTH> = FrameChannel::new();`.
+    /// Frames still queued when another command arrives stay in the channel.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `frame_duration` is zero.
+    pub fn stream<const DEPTH: usize>(
+        &self,
+        frames: &'static FrameChannel<N, DEPTH>,
+        frame_duration: Duration,
+    ) -> Result<()> {
+        assert!(
+            frame_duration.as_micros() > 0,
+            "animation frame duration must be positive"
+        );
+        self.command_signal.signal(Command::Stream {
+            frames: frames.dyn_receiver(),
+            frame_duration,
+        });
+        Ok(())
+    }
+
+    /// Returns `true` while the strip is busy: running an animation, or with a command
+    /// it has not finished yet.
+    ///
+    /// Returns `false` once the strip shows a static frame (from `write_frame`,
+    /// `write_static_frame`, or `write_segment`) with nothing pending, before the first
+    /// command, and after [`shutdown`](Self::shutdown). Animations loop until replaced, so
+    /// the strip stays busy until another task writes a static frame.
+    #[must_use]
+    pub fn is_animating(&self) -> bool {
+        !self.command_signal.is_idle()
+    }
+
+    /// Wait until the strip is idle: showing a static frame, with no animation running and
+    /// no command pending. Resolves immediately if it already is.
+    ///
+    /// Use it to sequence displays without guessing durations: write a splash frame,
+    /// `wait_idle().await` until it is on the LEDs, then hold it with a timer. Because
+    /// animations loop until replaced, waiting during one resolves only after another
+    /// task writes a static frame. Wait from one task at a time.
+    ///
+    /// See [`is_animating`](Self::is_animating) for what counts as busy.
+    pub async fn wait_idle(&self) {
+        self.command_signal.wait_idle().await;
     }
 
-    /// Writes a full frame to the LED strip. It remains displayed until another command
-    /// replaces it.
+    /// Scale the strip's output by `brightness` out of 255, on top of the `max_current`
+    /// limit. Starts at 255 (full brightness within the limit).
     ///
-    /// See the [led_strip module documentation](mod@crate::led_strip) for example usage.
-    pub fn write_frame(&self, frame: Frame1d<N>) -> Result<()> {
-        self.command_signal.signal(Command::DisplayStatic(frame));
-        Ok(())
+    /// Takes effect without resending anything: a static frame is rewritten at the new
+    /// brightness, and a running animation uses it from its next frame. Because it only
+    /// scales the limited output down, no brightness can exceed the current budget.
+    pub fn set_brightness(&self, brightness: u8) {
+        self.command_signal.set_brightness(brightness);
     }
 
-    /// Loop forever through a sequence of animation frames.
-    /// They remain displayed until another command replaces them.
+    /// The brightness last set with [`set_brightness`](Self::set_brightness).
+    #[must_use]
+    pub fn brightness(&self) -> u8 {
+        self.command_signal.brightness()
+    }
+
+    /// Follow ambient light: every `poll_interval`, call `sensor` and pass its reading to
+    /// [`set_brightness`](Self::set_brightness). Never returns.
     ///
-    /// Each frame is a tuple of `(Frame1d, Duration)`. Accepts arrays, `Vec`s, or any
-    /// iterator that produces `(Frame1d, Duration)` tuples.
+    /// `sensor` returns the brightness to use, 0 to 255; read a light sensor there (for
+    /// example, an ADC reading scaled to `u8`) and apply any curve or minimum you want. It
+    /// is a plain function pointer (no captures), like the
+    /// [`animate_with_callback`](Self::animate_with_callback) callback; reach the sensor
+    /// through statics. The strip is redrawn only when the reading changes.
     ///
-    /// Returns immediately; the animation runs in the background until interrupted
-    /// by a new `animate` call or `write_frame`.
+    /// Run it from a task of its own. Frames and animations written meanwhile are shown
+    /// as usual, at the current ambient brightness.
     ///
-    /// See the [led_strip module documentation](mod@crate::led_strip) for example usage.
-    pub fn animate<I>(&self, frames: I) -> Result<()>
+    /// # Panics
+    ///
+    /// Panics if `poll_interval` is zero.
+    pub async fn ambient_mode(&self, sensor: fn() -> u8, poll_interval: Duration) -> ! {
+        assert!(
+            poll_interval.as_micros() > 0,
+            "ambient poll interval must be positive"
+        );
+        loop {
+            self.set_brightness(sensor());
+            Timer::after(poll_interval).await;
+        }
+    }
+
+    /// Turn the LEDs off and stop the strip's background task.
+    ///
+    /// The task writes one all-off frame and then returns, dropping its PIO state machine
+    /// and DMA channel so the hardware is quiesced. The strip's statics cannot be
+    /// reclaimed, so the strip cannot be created again; later commands through this or
+    /// any other handle are ignored.
+    pub fn shutdown(&self) {
+        self.command_signal.shutdown();
+    }
+
+    fn animate_frames<I>(&self, frames: I, callback: Option<fn(usize)>) -> Result<()>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<(Frame1d<N>, Duration)>,
+    {
+        let sequence = Self::collect_frames(frames)?;
+        self.command_signal
+            .signal(Command::Animate(sequence, callback));
+        Ok(())
+    }
+
+    fn collect_frames<I>(frames: I) -> Result<Vec<(Frame1d<N>, Duration), MAX_FRAMES>>
     where
         I: IntoIterator,
         I::Item: Borrow<(Frame1d<N>, Duration)>,
@@ -670,23 +1706,42 @@ impl<const N: usize, const MAX_FRAMES: usize> LedStrip<N, MAX_FRAMES> {
                 .push((frame, duration))
                 .expect("animation sequence fits within MAX_FRAMES");
         }
-        self.animate_frames(sequence)
-    }
-
-    pub(crate) fn animate_frames(
-        &self,
-        sequence: Vec<(Frame1d<N>, Duration), MAX_FRAMES>,
-    ) -> Result<()> {
-        if MAX_FRAMES == 0 {
-            return Err(crate::Error::AnimationDisabled(MAX_FRAMES));
-        }
         assert!(
             !sequence.is_empty(),
             "animation requires at least one frame"
         );
-        self.command_signal.signal(Command::Animate(sequence));
-        Ok(())
+        Ok(sequence)
+    }
+}
+
+#[cfg(not(feature = "host"))]
+#[doc(hidden)] // Required pub for macro expansion in downstream crates
+/// Create the WS2812 driver for a strip with the given color byte order, optionally
+/// inverting the data pin's output to compensate for an inverting level shifter.
+pub fn new_ws2812_driver<PIO, const SM: usize, const N: usize, ORDER>(
+    common: &mut Common<'static, PIO>,
+    sm: embassy_rp::pio::StateMachine<'static, PIO, SM>,
+    dma: embassy_rp::Peri<'static, impl embassy_rp::dma::Channel>,
+    pin: embassy_rp::Peri<'static, impl embassy_rp::pio::PioPin>,
+    program: &PioWs2812Program<'static, PIO>,
+    invert_output: bool,
+) -> PioWs2812<'static, PIO, SM, N, ORDER>
+where
+    PIO: Instance,
+    ORDER: embassy_rp::pio_programs::ws2812::RgbColorOrder,
+{
+    let pin_number = usize::from(pin.pin());
+    let driver = PioWs2812::with_color_order(common, sm, dma, pin, program);
+    if invert_output {
+        // `PioWs2812` sets the pin up with `Common::make_pio_pin`, which overwrites the
+        // GPIO control register and so clears any output override. Set inversion afterwards,
+        // on the pin the driver now owns.
+        embassy_rp::pac::IO_BANK0
+            .gpio(pin_number)
+            .ctrl()
+            .modify(|w| w.set_outover(embassy_rp::pac::io::vals::Outover::INVERT));
     }
+    driver
 }
 
 #[cfg(not(feature = "host"))]
@@ -701,25 +1756,127 @@ pub async fn led_strip_device_loop<
     mut driver: PioWs2812<'static, PIO, SM, N, ORDER>,
     command_signal: &'static LedStripCommandSignal<N, MAX_FRAMES>,
     combo_table: &'static [u8; 256],
-) -> !
-where
+) where
     PIO: Instance,
     ORDER: embassy_rp::pio_programs::ws2812::RgbColorOrder,
 {
+    // The last static frame, uncorrected, so a brightness change can rewrite it.
+    let mut static_frame = Frame1d::<N>::new();
     loop {
-        let mut command = command_signal.wait().await;
+        let mut command = match select(
+            command_signal.wait(),
+            command_signal.wait_brightness_changed(),
+        )
+        .await
+        {
+            Either::First(command) => command,
+            Either::Second(()) => {
+                let mut frame = static_frame;
+                apply_correction(&mut frame, combo_table, command_signal.brightness());
+                driver.write(&frame).await;
+                continue;
+            }
+        };
         command_signal.reset();
 
         loop {
             match command {
-                Command::DisplayStatic(mut frame) => {
-                    apply_correction(&mut frame, combo_table);
+                Command::DisplayStatic(frame) => {
+                    static_frame = frame;
+                    let mut frame = frame;
+                    apply_correction(&mut frame, combo_table, command_signal.brightness());
                     driver.write(&frame).await;
+                    command_signal.mark_idle();
                     break;
                 }
-                Command::Animate(frames) => {
+                Command::DisplayBorrowed(frame) => {
+                    static_frame = *frame;
+                    let mut frame = *frame;
+                    apply_correction(&mut frame, combo_table, command_signal.brightness());
+                    driver.write(&frame).await;
+                    command_signal.mark_idle();
+                    break;
+                }
+                Command::AnimateBorrowed(frames) => {
                     command =
-                        run_frame_animation(&mut driver, frames, command_signal, combo_table).await;
+                        run_borrowed_animation(&mut driver, frames, command_signal, combo_table)
+                            .await;
+                }
+                Command::Animate(frames, callback) => {
+                    command = run_frame_animation(
+                        &mut driver,
+                        frames,
+                        callback,
+                        command_signal,
+                        combo_table,
+                    )
+                    .await;
+                }
+                Command::AnimateOnTrigger(frames, trigger) => {
+                    command = match select(command_signal.wait(), trigger.wait()).await {
+                        Either::First(new_command) => {
+                            command_signal.reset();
+                            new_command
+                        }
+                        Either::Second(()) => {
+                            run_frame_animation(
+                                &mut driver,
+                                frames,
+                                None,
+                                command_signal,
+                                combo_table,
+                            )
+                            .await
+                        }
+                    };
+                }
+                Command::AnimateGenerated {
+                    frame_count,
+                    frame_duration,
+                    generate,
+                } => {
+                    command = run_generated_animation(
+                        &mut driver,
+                        frame_count,
+                        frame_duration,
+                        generate,
+                        command_signal,
+                        combo_table,
+                    )
+                    .await;
+                }
+                Command::Fire {
+                    cooling,
+                    sparking,
+                    frame_duration,
+                } => {
+                    command = run_fire_animation(
+                        &mut driver,
+                        cooling,
+                        sparking,
+                        frame_duration,
+                        command_signal,
+                        combo_table,
+                    )
+                    .await;
+                }
+                Command::Stream {
+                    frames,
+                    frame_duration,
+                } => {
+                    command = run_stream(
+                        &mut driver,
+                        frames,
+                        frame_duration,
+                        command_signal,
+                        combo_table,
+                    )
+                    .await;
+                }
+                Command::Shutdown => {
+                    driver.write(&Frame1d::new()).await;
+                    command_signal.mark_shut_down();
+                    return;
                 }
             }
         }
@@ -729,7 +1886,8 @@ where
 #[cfg(not(feature = "host"))]
 async fn run_frame_animation<PIO, const SM: usize, const N: usize, const MAX_FRAMES: usize, ORDER>(
     driver: &mut PioWs2812<'static, PIO, SM, N, ORDER>,
-    mut frames: Vec<(Frame1d<N>, Duration), MAX_FRAMES>,
+    frames: Vec<(Frame1d<N>, Duration), MAX_FRAMES>,
+    callback: Option<fn(usize)>,
     command_signal: &'static LedStripCommandSignal<N, MAX_FRAMES>,
     combo_table: &'static [u8; 256],
 ) -> Command<N, MAX_FRAMES>
@@ -737,13 +1895,50 @@ where
     PIO: Instance,
     ORDER: embassy_rp::pio_programs::ws2812::RgbColorOrder,
 {
-    frames
-        .iter_mut()
-        .for_each(|(frame, _)| apply_correction(frame, combo_table));
+    loop {
+        for (frame_index, (frame, duration)) in frames.iter().enumerate() {
+            // Correct a copy, so a brightness change applies from the next frame.
+            let mut frame = *frame;
+            apply_correction(&mut frame, combo_table, command_signal.brightness());
+            driver.write(&frame).await;
+            if let Some(callback) = callback {
+                callback(frame_index);
+            }
+
+            match select(command_signal.wait(), Timer::after(*duration)).await {
+                Either::First(new_command) => {
+                    command_signal.reset();
+                    return new_command;
+                }
+                Either::Second(()) => continue,
+            }
+        }
+    }
+}
 
+#[cfg(not(feature = "host"))]
+async fn run_borrowed_animation<
+    PIO,
+    const SM: usize,
+    const N: usize,
+    const MAX_FRAMES: usize,
+    ORDER,
+>(
+    driver: &mut PioWs2812<'static, PIO, SM, N, ORDER>,
+    frames: &'static [(Frame1d<N>, Duration)],
+    command_signal: &'static LedStripCommandSignal<N, MAX_FRAMES>,
+    combo_table: &'static [u8; 256],
+) -> Command<N, MAX_FRAMES>
+where
+    PIO: Instance,
+    ORDER: embassy_rp::pio_programs::ws2812::RgbColorOrder,
+{
     loop {
-        for (frame, duration) in &frames {
-            driver.write(frame).await;
+        for (frame, duration) in frames {
+            // Correct a copy; the caller's frames may live in flash.
+            let mut frame = *frame;
+            apply_correction(&mut frame, combo_table, command_signal.brightness());
+            driver.write(&frame).await;
 
             match select(command_signal.wait(), Timer::after(*duration)).await {
                 Either::First(new_command) => {
@@ -757,11 +1952,129 @@ where
 }
 
 #[cfg(not(feature = "host"))]
-fn apply_correction<const N: usize>(frame: &mut Frame1d<N>, combo_table: &[u8; 256]) {
+async fn run_generated_animation<
+    PIO,
+    const SM: usize,
+    const N: usize,
+    const MAX_FRAMES: usize,
+    ORDER,
+>(
+    driver: &mut PioWs2812<'static, PIO, SM, N, ORDER>,
+    frame_count: usize,
+    frame_duration: Duration,
+    generate: fn(usize) -> Frame1d<N>,
+    command_signal: &'static LedStripCommandSignal<N, MAX_FRAMES>,
+    combo_table: &'static [u8; 256],
+) -> Command<N, MAX_FRAMES>
+where
+    PIO: Instance,
+    ORDER: embassy_rp::pio_programs::ws2812::RgbColorOrder,
+{
+    loop {
+        for frame_index in 0..frame_count {
+            let mut frame = generate(frame_index);
+            apply_correction(&mut frame, combo_table, command_signal.brightness());
+            driver.write(&frame).await;
+
+            match select(command_signal.wait(), Timer::after(frame_duration)).await {
+                Either::First(new_command) => {
+                    command_signal.reset();
+                    return new_command;
+                }
+                Either::Second(()) => continue,
+            }
+        }
+    }
+}
+
+#[cfg(not(feature = "host"))]
+async fn run_fire_animation<PIO, const SM: usize, const N: usize, const MAX_FRAMES: usize, ORDER>(
+    driver: &mut PioWs2812<'static, PIO, SM, N, ORDER>,
+    cooling: u8,
+    sparking: u8,
+    frame_duration: Duration,
+    command_signal: &'static LedStripCommandSignal<N, MAX_FRAMES>,
+    combo_table: &'static [u8; 256],
+) -> Command<N, MAX_FRAMES>
+where
+    PIO: Instance,
+    ORDER: embassy_rp::pio_programs::ws2812::RgbColorOrder,
+{
+    let mut fire = FireHeat::<N>::new(fire_seed());
+    loop {
+        fire.step(cooling, sparking);
+        let mut frame = fire.to_frame();
+        apply_correction(&mut frame, combo_table, command_signal.brightness());
+        driver.write(&frame).await;
+
+        match select(command_signal.wait(), Timer::after(frame_duration)).await {
+            Either::First(new_command) => {
+                command_signal.reset();
+                return new_command;
+            }
+            Either::Second(()) => continue,
+        }
+    }
+}
+
+#[cfg(not(feature = "host"))]
+async fn run_stream<PIO, const SM: usize, const N: usize, const MAX_FRAMES: usize, ORDER>(
+    driver: &mut PioWs2812<'static, PIO, SM, N, ORDER>,
+    frames: DynamicReceiver<'static, Frame1d<N>>,
+    frame_duration: Duration,
+    command_signal: &'static LedStripCommandSignal<N, MAX_FRAMES>,
+    combo_table: &'static [u8; 256],
+) -> Command<N, MAX_FRAMES>
+where
+    PIO: Instance,
+    ORDER: embassy_rp::pio_programs::ws2812::RgbColorOrder,
+{
+    loop {
+        // Wait for the next frame, keeping the last one lit meanwhile.
+        let mut frame = match select(command_signal.wait(), frames.receive()).await {
+            Either::First(new_command) => {
+                command_signal.reset();
+                return new_command;
+            }
+            Either::Second(frame) => frame,
+        };
+        apply_correction(&mut frame, combo_table, command_signal.brightness());
+        driver.write(&frame).await;
+
+        match select(command_signal.wait(), Timer::after(frame_duration)).await {
+            Either::First(new_command) => {
+                command_signal.reset();
+                return new_command;
+            }
+            Either::Second(()) => continue,
+        }
+    }
+}
+
+#[cfg(not(feature = "host"))]
+#[doc(hidden)] // Required pub for macro expansion in downstream crates
+/// Seed for a fire's spark generator, taken from the current time so each run differs.
+#[must_use]
+pub fn fire_seed() -> u32 {
+    let ticks = embassy_time::Instant::now().as_ticks().to_le_bytes();
+    u32::from_le_bytes([ticks[0], ticks[1], ticks[2], ticks[3]])
+}
+
+#[cfg(not(feature = "host"))]
+fn apply_correction<const N: usize>(
+    frame: &mut Frame1d<N>,
+    combo_table: &[u8; 256],
+    brightness: u8,
+) {
+    // Brightness scales the already limited value, so it can only lower the current.
+    let scale = |value: u8| {
+        let corrected = u16::from(combo_table[value as usize]);
+        u8::try_from((corrected * u16::from(brightness) + 127) / 255).unwrap_or(u8::MAX)
+    };
     frame.iter_mut().for_each(|pixel| {
-        pixel.r = combo_table[pixel.r as usize];
-        pixel.g = combo_table[pixel.g as usize];
-        pixel.b = combo_table[pixel.b as usize];
+        pixel.r = scale(pixel.r);
+        pixel.g = scale(pixel.g);
+        pixel.b = scale(pixel.b);
     });
 }
 /// Macro to generate multiple LED strip and panel struct types that share a single
@@ -1222,6 +2535,41 @@ macro_rules! __led_strips_impl {
                 }
             }
 
+            impl $crate::led2d::LedPanel for $label {
+                type Frame = $crate::led_strip::Frame1d<{ $len }>;
+
+                fn device_info(&self) -> $crate::led2d::DeviceInfo {
+                    $crate::led2d::DeviceInfo {
+                        len: $len,
+                        width: $len,
+                        height: 1,
+                        max_frames: $max_frames,
+                    }
+                }
+
+                fn write_frame(&self, frame: Self::Frame) -> $crate::Result<()> {
+                    self.strip.write_frame(frame)
+                }
+
+                fn animate<I>(&self, frames: I) -> $crate::Result<()>
+                where
+                    I: IntoIterator,
+                    I::Item: ::core::borrow::Borrow<(Self::Frame, ::embassy_time::Duration)>,
+                {
+                    self.strip.animate(frames)
+                }
+            }
+
+            impl $crate::led2d::LedDisplay for $label {
+                fn fill(&self, color: $crate::led_strip::RGB8) -> $crate::Result<()> {
+                    self.strip.write_frame($crate::led_strip::Frame1d::filled(color))
+                }
+
+                fn len(&self) -> usize {
+                    $len
+                }
+            }
+
             impl ::core::ops::Deref for $label {
                 type Target = $crate::led_strip::LedStrip<{ $len }, { $max_frames }>;
 
@@ -1244,7 +2592,7 @@ macro_rules! __led_strips_impl {
                 dma: ::embassy_rp::Peri<'static, ::embassy_rp::peripherals::$dma>,
                 pin: ::embassy_rp::Peri<'static, ::embassy_rp::peripherals::$pin>,
                 command_signal: &'static $crate::led_strip::LedStripCommandSignal<{ $len }, { $max_frames }>,
-            ) -> ! {
+            ) {
                 let program = bus.get_program();
                 let driver = bus.with_common(|common| {
                     ::embassy_rp::pio_programs::ws2812::PioWs2812::<
@@ -1362,7 +2710,7 @@ macro_rules! __led_strips_impl {
                 dma: ::embassy_rp::Peri<'static, ::embassy_rp::peripherals::$dma>,
                 pin: ::embassy_rp::Peri<'static, ::embassy_rp::peripherals::$pin>,
                 command_signal: &'static $crate::led_strip::LedStripCommandSignal<{ $len }, { $max_frames }>,
-            ) -> ! {
+            ) {
                 let program = bus.get_program();
                 let driver = bus.with_common(|common| {
                     ::embassy_rp::pio_programs::ws2812::PioWs2812::<
@@ -2646,11 +3994,28 @@ macro_rules! __led_strips_impl {
 /// - `max_current` — Electrical current budget (default: 250 mA)
 /// - `gamma` — Color curve (default: `Gamma::Srgb`)
 /// - `max_frames` — Maximum number of animation frames (default: 16 frames)
+/// - `invert_output` — Invert the data pin's output, for an inverting level shifter between
+///   the Pico and the strip (default: `false`)
+/// - `order` — Color byte order the LEDs expect: `GRB`, `RGB`, or `BGR` (default: `GRB`,
+///   the WS2812B standard). See [`color_order`](crate::led_strip::color_order) for details.
 ///
 /// `max_frames = 0` disables animation and allocates no frame storage; `write_frame()` is still supported.
 ///
+/// There is no command queue to size. Each strip holds at most one pending command, and a new
+/// `write_frame` or `animate` call replaces any command the device task has not yet picked up.
+/// Because an animation loops until it is replaced, queued commands would only ever be stale.
+///
 #[doc = include_str!("docs/current_limiting_and_gamma.md")]
 ///
+/// # Why a Macro?
+///
+/// Embassy tasks cannot be generic, so each strip needs its own concrete device task for its
+/// `len`, PIO resource, and pin. The macro generates that task along with a struct type whose
+/// constants (`LEN`, `MAX_BRIGHTNESS`, etc.) are checked at compile time. For the same reason,
+/// `len` and `pin` must be known at compile time. We deliberately do not offer a builder
+/// alternative; see the generated type's [`new`](led_strip_generated::LedStripGenerated::new)
+/// for the direct constructor.
+///
 /// # Related Macros
 ///
 /// - [`led_strips!`](crate::led_strips) — Alternative macro to share a PIO resource with other strips or panels (includes examples)
@@ -2685,6 +4050,8 @@ macro_rules! __led_strip_impl {
             max_current: _UNSET_,
             gamma: $crate::led_strip::GAMMA_DEFAULT,
             max_frames: $crate::led_strip::MAX_FRAMES_DEFAULT,
+            invert_output: false,
+            order: GRB,
             fields: [ $($fields)* ]
         }
     };
@@ -2706,6 +4073,8 @@ macro_rules! __led_strip_impl {
             max_current: _UNSET_,
             gamma: $crate::led_strip::GAMMA_DEFAULT,
             max_frames: $crate::led_strip::MAX_FRAMES_DEFAULT,
+            invert_output: false,
+            order: GRB,
             fields: [ $($fields)* ]
         }
     };
@@ -2721,6 +4090,8 @@ macro_rules! __led_strip_impl {
         max_current: $max_current:tt,
         gamma: $gamma:expr,
         max_frames: $max_frames:expr,
+        invert_output: $invert_output:expr,
+        order: $order:ident,
         fields: [ pio: $new_pio:ident $(, $($rest:tt)* )? ]
     ) => {
         $crate::__led_strip_impl! {
@@ -2734,6 +4105,8 @@ macro_rules! __led_strip_impl {
             max_current: $max_current,
             gamma: $gamma,
             max_frames: $max_frames,
+            invert_output: $invert_output,
+            order: $order,
             fields: [ $($($rest)*)? ]
         }
     };
@@ -2749,6 +4122,8 @@ macro_rules! __led_strip_impl {
         max_current: $max_current:tt,
         gamma: $gamma:expr,
         max_frames: $max_frames:expr,
+        invert_output: $invert_output:expr,
+        order: $order:ident,
         fields: [ pin: $new_pin:ident $(, $($rest:tt)* )? ]
     ) => {
         $crate::__led_strip_impl! {
@@ -2762,6 +4137,8 @@ macro_rules! __led_strip_impl {
             max_current: $max_current,
             gamma: $gamma,
             max_frames: $max_frames,
+            invert_output: $invert_output,
+            order: $order,
             fields: [ $($($rest)*)? ]
         }
     };
@@ -2777,6 +4154,8 @@ macro_rules! __led_strip_impl {
         max_current: $max_current:tt,
         gamma: $gamma:expr,
         max_frames: $max_frames:expr,
+        invert_output: $invert_output:expr,
+        order: $order:ident,
         fields: [ dma: $new_dma:ident $(, $($rest:tt)* )? ]
     ) => {
         $crate::__led_strip_impl! {
@@ -2790,6 +4169,8 @@ macro_rules! __led_strip_impl {
             max_current: $max_current,
             gamma: $gamma,
             max_frames: $max_frames,
+            invert_output: $invert_output,
+            order: $order,
             fields: [ $($($rest)*)? ]
         }
     };
@@ -2805,6 +4186,8 @@ macro_rules! __led_strip_impl {
         max_current: $max_current:tt,
         gamma: $gamma:expr,
         max_frames: $max_frames:expr,
+        invert_output: $invert_output:expr,
+        order: $order:ident,
         fields: [ len: { $new_len:expr } $(, $($rest:tt)* )? ]
     ) => {
         $crate::__led_strip_impl! {
@@ -2818,6 +4201,8 @@ macro_rules! __led_strip_impl {
             max_current: $max_current,
             gamma: $gamma,
             max_frames: $max_frames,
+            invert_output: $invert_output,
+            order: $order,
             fields: [ $($($rest)*)? ]
         }
     };
@@ -2833,6 +4218,8 @@ macro_rules! __led_strip_impl {
         max_current: $max_current:tt,
         gamma: $gamma:expr,
         max_frames: $max_frames:expr,
+        invert_output: $invert_output:expr,
+        order: $order:ident,
         fields: [ len: $new_len:expr $(, $($rest:tt)* )? ]
     ) => {
         $crate::__led_strip_impl! {
@@ -2846,6 +4233,8 @@ macro_rules! __led_strip_impl {
             max_current: $max_current,
             gamma: $gamma,
             max_frames: $max_frames,
+            invert_output: $invert_output,
+            order: $order,
             fields: [ $($($rest)*)? ]
         }
     };
@@ -2861,6 +4250,8 @@ macro_rules! __led_strip_impl {
         max_current: $max_current:tt,
         gamma: $gamma:expr,
         max_frames: $max_frames:expr,
+        invert_output: $invert_output:expr,
+        order: $order:ident,
         fields: [ max_current: $new_max_current:expr $(, $($rest:tt)* )? ]
     ) => {
         $crate::__led_strip_impl! {
@@ -2874,6 +4265,8 @@ macro_rules! __led_strip_impl {
             max_current: $new_max_current,
             gamma: $gamma,
             max_frames: $max_frames,
+            invert_output: $invert_output,
+            order: $order,
             fields: [ $($($rest)*)? ]
         }
     };
@@ -2889,6 +4282,8 @@ macro_rules! __led_strip_impl {
         max_current: $max_current:tt,
         gamma: $gamma:expr,
         max_frames: $max_frames:expr,
+        invert_output: $invert_output:expr,
+        order: $order:ident,
         fields: [ gamma: $new_gamma:expr $(, $($rest:tt)* )? ]
     ) => {
         $crate::__led_strip_impl! {
@@ -2902,6 +4297,8 @@ macro_rules! __led_strip_impl {
             max_current: $max_current,
             gamma: $new_gamma,
             max_frames: $max_frames,
+            invert_output: $invert_output,
+            order: $order,
             fields: [ $($($rest)*)? ]
         }
     };
@@ -2917,6 +4314,8 @@ macro_rules! __led_strip_impl {
         max_current: $max_current:tt,
         gamma: $gamma:expr,
         max_frames: $max_frames:expr,
+        invert_output: $invert_output:expr,
+        order: $order:ident,
         fields: [ max_frames: $new_max_frames:expr $(, $($rest:tt)* )? ]
     ) => {
         $crate::__led_strip_impl! {
@@ -2930,6 +4329,72 @@ macro_rules! __led_strip_impl {
             max_current: $max_current,
             gamma: $gamma,
             max_frames: $new_max_frames,
+            invert_output: $invert_output,
+            order: $order,
+            fields: [ $($($rest)*)? ]
+        }
+    };
+
+    // Fill defaults: invert_output
+    (@__fill_defaults
+        vis: $vis:vis,
+        pio: $pio:ident,
+        name: $name:ident,
+        pin: $pin:tt,
+        dma: $dma:ident,
+        len: $len:tt,
+        max_current: $max_current:tt,
+        gamma: $gamma:expr,
+        max_frames: $max_frames:expr,
+        invert_output: $invert_output:expr,
+        order: $order:ident,
+        fields: [ invert_output: $new_invert_output:expr $(, $($rest:tt)* )? ]
+    ) => {
+        $crate::__led_strip_impl! {
+            @__fill_defaults
+            vis: $vis,
+            pio: $pio,
+            name: $name,
+            pin: $pin,
+            dma: $dma,
+            len: $len,
+            max_current: $max_current,
+            gamma: $gamma,
+            max_frames: $max_frames,
+            invert_output: $new_invert_output,
+            order: $order,
+            fields: [ $($($rest)*)? ]
+        }
+    };
+
+    // Fill defaults: order
+    (@__fill_defaults
+        vis: $vis:vis,
+        pio: $pio:ident,
+        name: $name:ident,
+        pin: $pin:tt,
+        dma: $dma:ident,
+        len: $len:tt,
+        max_current: $max_current:tt,
+        gamma: $gamma:expr,
+        max_frames: $max_frames:expr,
+        invert_output: $invert_output:expr,
+        order: $order:ident,
+        fields: [ order: $new_order:ident $(, $($rest:tt)* )? ]
+    ) => {
+        $crate::__led_strip_impl! {
+            @__fill_defaults
+            vis: $vis,
+            pio: $pio,
+            name: $name,
+            pin: $pin,
+            dma: $dma,
+            len: $len,
+            max_current: $max_current,
+            gamma: $gamma,
+            max_frames: $max_frames,
+            invert_output: $invert_output,
+            order: $new_order,
             fields: [ $($($rest)*)? ]
         }
     };
@@ -2945,6 +4410,8 @@ macro_rules! __led_strip_impl {
         max_current: _UNSET_,
         gamma: $gamma:expr,
         max_frames: $max_frames:expr,
+        invert_output: $invert_output:expr,
+        order: $order:ident,
         fields: []
     ) => {
         $crate::__led_strip_impl! {
@@ -2958,6 +4425,8 @@ macro_rules! __led_strip_impl {
             max_current: $crate::led_strip::MAX_CURRENT_DEFAULT,
             gamma: $gamma,
             max_frames: $max_frames,
+            invert_output: $invert_output,
+            order: $order,
             fields: []
         }
     };
@@ -2973,6 +4442,8 @@ macro_rules! __led_strip_impl {
         max_current: $max_current:expr,
         gamma: $gamma:expr,
         max_frames: $max_frames:expr,
+        invert_output: $invert_output:expr,
+        order: $order:ident,
         fields: []
     ) => {
         ::paste::paste! {
@@ -3070,6 +4541,41 @@ macro_rules! __led_strip_impl {
                 }
             }
 
+            impl $crate::led2d::LedPanel for $name {
+                type Frame = $crate::led_strip::Frame1d<{ $len }>;
+
+                fn device_info(&self) -> $crate::led2d::DeviceInfo {
+                    $crate::led2d::DeviceInfo {
+                        len: $len,
+                        width: $len,
+                        height: 1,
+                        max_frames: $max_frames,
+                    }
+                }
+
+                fn write_frame(&self, frame: Self::Frame) -> $crate::Result<()> {
+                    self.strip.write_frame(frame)
+                }
+
+                fn animate<I>(&self, frames: I) -> $crate::Result<()>
+                where
+                    I: IntoIterator,
+                    I::Item: ::core::borrow::Borrow<(Self::Frame, ::embassy_time::Duration)>,
+                {
+                    self.strip.animate(frames)
+                }
+            }
+
+            impl $crate::led2d::LedDisplay for $name {
+                fn fill(&self, color: $crate::led_strip::RGB8) -> $crate::Result<()> {
+                    self.strip.write_frame($crate::led_strip::Frame1d::filled(color))
+                }
+
+                fn len(&self) -> usize {
+                    $len
+                }
+            }
+
             impl ::core::ops::Deref for $name {
                 type Target = $crate::led_strip::LedStrip<{ $len }, { $max_frames }>;
 
@@ -3092,15 +4598,15 @@ macro_rules! __led_strip_impl {
                 dma: ::embassy_rp::Peri<'static, ::embassy_rp::peripherals::$dma>,
                 pin: ::embassy_rp::Peri<'static, ::embassy_rp::peripherals::$pin>,
                 command_signal: &'static $crate::led_strip::LedStripCommandSignal<{ $len }, { $max_frames }>,
-            ) -> ! {
+            ) {
                 let program = bus.get_program();
                 let driver = bus.with_common(|common| {
-                    ::embassy_rp::pio_programs::ws2812::PioWs2812::<
+                    $crate::led_strip::new_ws2812_driver::<
                         ::embassy_rp::peripherals::$pio,
                         0,
                         { $len },
-                        _
-                    >::new(common, sm, dma, pin, program)
+                        $crate::led_strip::color_order::$order,
+                    >(common, sm, dma, pin, program, $invert_output)
                 });
                 $crate::led_strip::led_strip_device_loop
                 ::<
@@ -3215,6 +4721,12 @@ impl Current {
     /// Calculate maximum brightness based on electrical current budget and worst-case electrical current draw.
     ///
     /// Returns 255 (full brightness) for Unlimited, or a scaled value for Milliamps.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the budget is so small that the brightness would round to 0. The
+    /// generated `MAX_BRIGHTNESS` constant is evaluated at compile time, so in macro
+    /// expansions this is a compile error rather than a strip that never lights.
     #[doc(hidden)] // Called by macro-generated code; not part of public API
     #[must_use]
     pub const fn max_brightness(self, worst_case_ma: u32) -> u8 {
@@ -3222,6 +4734,10 @@ impl Current {
         match self {
             Self::Milliamps(ma) => {
                 let scale = (ma as u32 * 255) / worst_case_ma;
+                assert!(
+                    scale > 0,
+                    "max_current is too low for this many LEDs: MAX_BRIGHTNESS would be 0 (each LED may draw 60 mA); raise max_current or reduce len"
+                );
                 if scale > 255 { 255 } else { scale as u8 }
             }
             Self::Unlimited => 255,
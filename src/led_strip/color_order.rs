@@ -0,0 +1,61 @@
+//! Color byte orders for the [`led_strip!`](macro@crate::led_strip) `order` field.
+//!
+//! WS2812B LEDs expect each color as green, red, blue ([`GRB`], the default). Some clones
+//! expect [`RGB`] or [`BGR`] instead; on those, colors come out wrong (for example, red
+//! shows as green) until the strip's `order` field names the right order:
+//!
+//! ```rust,no_run
+//! # #![no_std]
+//! # #![no_main]
+//! # use panic_probe as _;
+//! use device_envoy::led_strip;
+//!
+//! led_strip! {
+//!     CloneLedStrip {
+//!         pin: PIN_0,
+//!         len: 8,
+//!         order: RGB,
+//!     }
+//! }
+//! ```
+
+#![expect(
+    clippy::upper_case_acronyms,
+    reason = "the names match the `order: GRB` macro field syntax, like `pio: PIO0`"
+)]
+
+use embassy_rp::pio_programs::ws2812::RgbColorOrder;
+
+use crate::led_strip::RGB8;
+
+/// Green, red, blue: the standard WS2812B order (default).
+pub struct GRB;
+
+impl RgbColorOrder for GRB {
+    fn pack(color: RGB8) -> u32 {
+        pack_bytes(color.g, color.r, color.b)
+    }
+}
+
+/// Red, green, blue, used by some WS2812B clones.
+pub struct RGB;
+
+impl RgbColorOrder for RGB {
+    fn pack(color: RGB8) -> u32 {
+        pack_bytes(color.r, color.g, color.b)
+    }
+}
+
+/// Blue, green, red, used by some WS2812B clones.
+pub struct BGR;
+
+impl RgbColorOrder for BGR {
+    fn pack(color: RGB8) -> u32 {
+        pack_bytes(color.b, color.g, color.r)
+    }
+}
+
+// The driver shifts out the top 24 bits of each word, first byte first.
+fn pack_bytes(first: u8, second: u8, third: u8) -> u32 {
+    u32::from_be_bytes([first, second, third, 0])
+}
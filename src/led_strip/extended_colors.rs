@@ -0,0 +1,157 @@
+//! Predefined [`RGB8`] color constants for embedded and Internet-of-Things projects.
+//!
+//! These complement the CSS names in [`colors`](crate::led_strip::colors) with names from
+//! signaling, safety, and lighting. Like `colors`, the values are sRGB triplets; the strip
+//! still applies its gamma curve and current limit when displaying them.
+//!
+//! The white points are correlated color temperatures converted with Tanner Helland's
+//! blackbody approximation ("How to Convert Temperature (K) to RGB", 2012). Real LEDs
+//! vary, so treat them as starting points. For other temperatures, use
+//! [`rgb8_from_kelvin`].
+
+use crate::led_strip::RGB8;
+
+/// Signal amber, `(255, 191, 0)` / `#FFBF00`, as used for traffic lights and turn signals
+/// (SAE/ECE amber).
+pub const AMBER: RGB8 = RGB8::new(255, 191, 0);
+
+/// Safety orange, `(255, 121, 0)` / `#FF7900`, the ANSI Z535.1 high-visibility orange.
+pub const SAFETY_ORANGE: RGB8 = RGB8::new(255, 121, 0);
+
+/// Ultraviolet/blacklight purple, `(143, 0, 255)` / `#8F00FF` ("electric violet"), the
+/// visible glow of a UV-A lamp.
+pub const UV_PURPLE: RGB8 = RGB8::new(143, 0, 255);
+
+/// Warm white, `(255, 167, 87)`, approximating 2700 K (incandescent).
+pub const WARM_WHITE: RGB8 = RGB8::new(255, 167, 87);
+
+/// Neutral white, `(255, 206, 166)`, approximating 4000 K.
+pub const NEUTRAL_WHITE: RGB8 = RGB8::new(255, 206, 166);
+
+/// Cool white, `(255, 254, 250)`, approximating 6500 K (daylight).
+pub const COOL_WHITE: RGB8 = RGB8::new(255, 254, 250);
+
+/// Convert a correlated color temperature in kelvin to an sRGB color, using the same
+/// blackbody approximation as the white points above.
+///
+/// Low temperatures are candle-orange (1000 K is `(255, 68, 0)`), 6600 K is pure white,
+/// and higher temperatures turn blue. `kelvin` is clamped to 1000–40000 K. Results are
+/// exact at multiples of 100 K and interpolated in between, from tables precomputed to
+/// avoid floating-point math.
+///
+/// Pair it with the strip's brightness to make a WS2812 strip a tunable white light.
+#[must_use]
+pub const fn rgb8_from_kelvin(kelvin: u16) -> RGB8 {
+    let kelvin = if kelvin < KELVIN_MIN {
+        KELVIN_MIN
+    } else if kelvin > KELVIN_MAX {
+        KELVIN_MAX
+    } else {
+        kelvin
+    };
+    let offset = (kelvin - KELVIN_MIN) as usize;
+    let index = offset / KELVIN_STEP;
+    let fraction = offset % KELVIN_STEP;
+    RGB8::new(
+        interpolate(&KELVIN_RED_TABLE, index, fraction),
+        interpolate(&KELVIN_GREEN_TABLE, index, fraction),
+        interpolate(&KELVIN_BLUE_TABLE, index, fraction),
+    )
+}
+
+const KELVIN_MIN: u16 = 1000;
+const KELVIN_MAX: u16 = 40000;
+const KELVIN_STEP: usize = 100;
+
+// Blend table entries `index` and `index + 1`, `fraction` hundredths of the way.
+#[expect(
+    clippy::cast_possible_truncation,
+    reason = "a blend of two u8 values fits in u8"
+)]
+const fn interpolate(table: &[u8; KELVIN_TABLE_LEN], index: usize, fraction: usize) -> u8 {
+    if fraction == 0 {
+        return table[index];
+    }
+    let start = table[index] as usize;
+    let end = table[index + 1] as usize;
+    let blended =
+        (start * (KELVIN_STEP - fraction) + end * fraction + KELVIN_STEP / 2) / KELVIN_STEP;
+    blended as u8
+}
+
+const KELVIN_TABLE_LEN: usize = 391;
+
+/// Red channel of Tanner Helland's approximation at 1000, 1100, ..., 40000 K.
+const KELVIN_RED_TABLE: [u8; KELVIN_TABLE_LEN] = [
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    254, 250, 246, 243, 240, 237, 234, 232, 230, 228, 226, 224, 223, 221, 220, 218, 217, 216, 215,
+    214, 213, 212, 211, 210, 209, 208, 207, 206, 205, 205, 204, 203, 202, 202, 201, 200, 200, 199,
+    199, 198, 197, 197, 196, 196, 195, 195, 194, 194, 193, 193, 192, 192, 192, 191, 191, 190, 190,
+    189, 189, 189, 188, 188, 188, 187, 187, 187, 186, 186, 186, 185, 185, 185, 184, 184, 184, 183,
+    183, 183, 182, 182, 182, 182, 181, 181, 181, 181, 180, 180, 180, 180, 179, 179, 179, 179, 178,
+    178, 178, 178, 177, 177, 177, 177, 176, 176, 176, 176, 176, 175, 175, 175, 175, 175, 174, 174,
+    174, 174, 174, 173, 173, 173, 173, 173, 173, 172, 172, 172, 172, 172, 172, 171, 171, 171, 171,
+    171, 171, 170, 170, 170, 170, 170, 170, 169, 169, 169, 169, 169, 169, 169, 168, 168, 168, 168,
+    168, 168, 168, 167, 167, 167, 167, 167, 167, 167, 166, 166, 166, 166, 166, 166, 166, 166, 165,
+    165, 165, 165, 165, 165, 165, 165, 164, 164, 164, 164, 164, 164, 164, 164, 164, 163, 163, 163,
+    163, 163, 163, 163, 163, 163, 162, 162, 162, 162, 162, 162, 162, 162, 162, 162, 161, 161, 161,
+    161, 161, 161, 161, 161, 161, 161, 160, 160, 160, 160, 160, 160, 160, 160, 160, 160, 160, 159,
+    159, 159, 159, 159, 159, 159, 159, 159, 159, 159, 158, 158, 158, 158, 158, 158, 158, 158, 158,
+    158, 158, 158, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 157, 156, 156, 156, 156,
+    156, 156, 156, 156, 156, 156, 156, 156, 156, 156, 155, 155, 155, 155, 155, 155, 155, 155, 155,
+    155, 155, 155, 155, 155, 154, 154, 154, 154, 154, 154, 154, 154, 154, 154, 154, 154, 154, 154,
+    153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 153, 152, 152, 152,
+    152, 152, 152, 152, 152, 152, 152, 152, 152, 152, 152,
+];
+
+/// Green channel of Tanner Helland's approximation at 1000, 1100, ..., 40000 K.
+const KELVIN_GREEN_TABLE: [u8; KELVIN_TABLE_LEN] = [
+    68, 77, 86, 94, 101, 108, 115, 121, 126, 132, 137, 142, 146, 151, 155, 159, 163, 167, 170, 174,
+    177, 180, 184, 187, 190, 193, 195, 198, 201, 203, 206, 208, 211, 213, 215, 218, 220, 222, 224,
+    226, 228, 230, 232, 234, 236, 237, 239, 241, 243, 244, 246, 248, 249, 251, 253, 254, 255, 249,
+    246, 244, 242, 240, 239, 237, 236, 235, 234, 233, 232, 231, 230, 229, 228, 227, 227, 226, 225,
+    225, 224, 223, 223, 222, 222, 221, 221, 220, 220, 219, 219, 218, 218, 218, 217, 217, 217, 216,
+    216, 215, 215, 215, 214, 214, 214, 213, 213, 213, 213, 212, 212, 212, 211, 211, 211, 211, 210,
+    210, 210, 210, 210, 209, 209, 209, 209, 208, 208, 208, 208, 208, 207, 207, 207, 207, 207, 206,
+    206, 206, 206, 206, 205, 205, 205, 205, 205, 205, 204, 204, 204, 204, 204, 204, 203, 203, 203,
+    203, 203, 203, 203, 202, 202, 202, 202, 202, 202, 202, 201, 201, 201, 201, 201, 201, 201, 201,
+    200, 200, 200, 200, 200, 200, 200, 200, 199, 199, 199, 199, 199, 199, 199, 199, 199, 198, 198,
+    198, 198, 198, 198, 198, 198, 198, 198, 197, 197, 197, 197, 197, 197, 197, 197, 197, 197, 196,
+    196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 195, 195, 195, 195, 195, 195, 195, 195, 195,
+    195, 195, 195, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 193, 193, 193,
+    193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 192, 192, 192, 192, 192, 192, 192, 192,
+    192, 192, 192, 192, 192, 192, 192, 191, 191, 191, 191, 191, 191, 191, 191, 191, 191, 191, 191,
+    191, 191, 191, 191, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190,
+    190, 190, 189, 189, 189, 189, 189, 189, 189, 189, 189, 189, 189, 189, 189, 189, 189, 189, 189,
+    189, 189, 188, 188, 188, 188, 188, 188, 188, 188, 188, 188, 188, 188, 188, 188, 188, 188, 188,
+    188, 188, 188, 187, 187, 187, 187, 187, 187, 187, 187, 187, 187, 187, 187, 187, 187, 187, 187,
+    187, 187, 187, 187, 187, 187, 186, 186, 186, 186, 186, 186, 186, 186, 186, 186, 186, 186, 186,
+    186, 186, 186, 186, 186, 186, 186, 186, 186, 186,
+];
+
+/// Blue channel of Tanner Helland's approximation at 1000, 1100, ..., 40000 K.
+const KELVIN_BLUE_TABLE: [u8; KELVIN_TABLE_LEN] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 27, 39, 50, 61, 70, 79, 87, 95, 103, 110, 117, 123, 129, 135,
+    141, 146, 151, 157, 161, 166, 171, 175, 179, 183, 187, 191, 195, 199, 202, 206, 209, 213, 216,
+    219, 222, 225, 228, 231, 234, 237, 240, 242, 245, 248, 250, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255,
+];
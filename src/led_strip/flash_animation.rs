@@ -0,0 +1,194 @@
+//! Store animation sequences in flash so a device can replay them after a reboot.
+//!
+//! [`store_animation`] writes a sequence of `(Frame1d, Duration)` pairs to a
+//! [`FlashBlock`](crate::flash_array::FlashBlock), and [`load_animation`] reads it back,
+//! ready to pass to a strip's `animate` method. The sequence is kept in a compact binary
+//! format (see [`encode_animation`]): 3 bytes per LED plus 4 bytes of duration per frame,
+//! after a 5-byte header. One flash block holds up to [`MAX_ENCODED_LEN`] bytes, for
+//! example 38 frames of a 32-LED strip.
+//!
+//! The header records the strip length, so a sequence saved for one strip is never
+//! replayed on a strip of another length.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! # #![no_std]
+//! # #![no_main]
+//! # use panic_probe as _;
+//! use device_envoy::{
+//!     Result,
+//!     flash_array::FlashBlock,
+//!     led_strip::{
+//!         Frame1d, colors,
+//!         flash_animation::{load_animation, store_animation},
+//!     },
+//! };
+//! use embassy_time::Duration;
+//!
+//! fn remember_and_reload(animation_flash: &mut FlashBlock) -> Result<()> {
+//!     // A user-designed two-frame blink for an 8-LED strip.
+//!     let frames = [
+//!         (Frame1d::<8>::filled(colors::ORANGE), Duration::from_millis(300)),
+//!         (Frame1d::<8>::new(), Duration::from_millis(200)),
+//!     ];
+//!     store_animation(animation_flash, &frames)?;
+//!
+//!     // After a reboot, load it back (None if nothing was stored) and animate it
+//!     // with the strip's `animate` method.
+//!     if let Some(loaded) = load_animation::<8, 16>(animation_flash)? {
+//!         defmt::info!("Loaded {} frames", loaded.len());
+//!     }
+//!     Ok(())
+//! }
+//! ```
+
+use embassy_time::Duration;
+use heapless::Vec;
+
+use crate::led_strip::{Frame1d, RGB8};
+use crate::{Error, Result};
+
+/// Largest encoded animation, in bytes, that fits in one flash block.
+pub const MAX_ENCODED_LEN: usize = 3896;
+
+const FORMAT_VERSION: u8 = 1;
+const HEADER_LEN: usize = 5;
+const DURATION_LEN: usize = 4;
+
+/// Number of bytes [`encode_animation`] writes for `frame_count` frames of an `N`-LED
+/// strip.
+#[must_use]
+pub const fn encoded_len<const N: usize>(frame_count: usize) -> usize {
+    HEADER_LEN + frame_count * (DURATION_LEN + 3 * N)
+}
+
+/// Encode `frames` into `buffer` and return the number of bytes written.
+///
+/// The format is little-endian: a version byte, the LED count (`u16`), and the frame
+/// count (`u16`), then for each frame its duration in microseconds (`u32`) followed by
+/// the `r, g, b` bytes of each LED.
+///
+/// # Errors
+///
+/// Returns [`Error::FormatError`] if `buffer` is too small, if `N` or the number of
+/// frames exceeds `u16::MAX`, or if a duration exceeds `u32::MAX` microseconds (about 71
+/// minutes).
+pub fn encode_animation<const N: usize>(
+    frames: &[(Frame1d<N>, Duration)],
+    buffer: &mut [u8],
+) -> Result<usize> {
+    let led_count = u16::try_from(N).map_err(|_| Error::FormatError)?;
+    let frame_count = u16::try_from(frames.len()).map_err(|_| Error::FormatError)?;
+    let total_len = encoded_len::<N>(frames.len());
+    let buffer = buffer.get_mut(..total_len).ok_or(Error::FormatError)?;
+
+    buffer[0] = FORMAT_VERSION;
+    buffer[1..3].copy_from_slice(&led_count.to_le_bytes());
+    buffer[3..HEADER_LEN].copy_from_slice(&frame_count.to_le_bytes());
+    let frame_chunks = buffer[HEADER_LEN..].chunks_exact_mut(DURATION_LEN + 3 * N);
+    for ((frame, duration), chunk) in frames.iter().zip(frame_chunks) {
+        let duration_micros =
+            u32::try_from(duration.as_micros()).map_err(|_| Error::FormatError)?;
+        chunk[..DURATION_LEN].copy_from_slice(&duration_micros.to_le_bytes());
+        for (pixel, bytes) in frame
+            .iter()
+            .zip(chunk[DURATION_LEN..].as_chunks_mut::<3>().0)
+        {
+            *bytes = [pixel.r, pixel.g, pixel.b];
+        }
+    }
+    Ok(total_len)
+}
+
+/// Decode an animation written by [`encode_animation`].
+///
+/// # Errors
+///
+/// Returns [`Error::AnimationLengthMismatch`] if the animation was encoded for a strip
+/// with a different number of LEDs than `N`, [`Error::FormatError`] if it has more than
+/// `MAX_FRAMES` frames, and [`Error::StorageCorrupted`] if the bytes are not a valid
+/// encoding.
+pub fn decode_animation<const N: usize, const MAX_FRAMES: usize>(
+    bytes: &[u8],
+) -> Result<Vec<(Frame1d<N>, Duration), MAX_FRAMES>> {
+    let header = bytes.get(..HEADER_LEN).ok_or(Error::StorageCorrupted)?;
+    if header[0] != FORMAT_VERSION {
+        return Err(Error::StorageCorrupted);
+    }
+    let led_count = usize::from(u16::from_le_bytes([header[1], header[2]]));
+    if led_count != N {
+        return Err(Error::AnimationLengthMismatch {
+            stored: led_count,
+            expected: N,
+        });
+    }
+    let frame_count = usize::from(u16::from_le_bytes([header[3], header[4]]));
+    if bytes.len() != encoded_len::<N>(frame_count) {
+        return Err(Error::StorageCorrupted);
+    }
+    if frame_count > MAX_FRAMES {
+        return Err(Error::FormatError);
+    }
+
+    let mut frames = Vec::new();
+    for chunk in bytes[HEADER_LEN..].chunks_exact(DURATION_LEN + 3 * N) {
+        let (duration_bytes, pixel_bytes) = chunk.split_at(DURATION_LEN);
+        let duration_micros = u32::from_le_bytes(
+            duration_bytes
+                .try_into()
+                .map_err(|_| Error::StorageCorrupted)?,
+        );
+        let mut frame = Frame1d::<N>::new();
+        for (pixel, &[r, g, b]) in frame.iter_mut().zip(pixel_bytes.as_chunks::<3>().0) {
+            *pixel = RGB8::new(r, g, b);
+        }
+        frames
+            .push((frame, Duration::from_micros(u64::from(duration_micros))))
+            .map_err(|_| Error::FormatError)?;
+    }
+    Ok(frames)
+}
+
+/// Save `frames` to `block`, replacing whatever the block held.
+///
+/// # Errors
+///
+/// Returns [`Error::FormatError`] if the encoded animation is longer than
+/// [`MAX_ENCODED_LEN`] bytes or cannot be encoded (see [`encode_animation`]), and
+/// [`Error::Flash`] if the flash write fails.
+///
+/// See the [module example](self) for usage.
+#[cfg(target_os = "none")]
+pub fn store_animation<const N: usize>(
+    block: &mut crate::flash_array::FlashBlock,
+    frames: &[(Frame1d<N>, Duration)],
+) -> Result<()> {
+    let mut encoded = Vec::<u8, MAX_ENCODED_LEN>::new();
+    encoded
+        .resize_default(encoded_len::<N>(frames.len()))
+        .map_err(|()| Error::FormatError)?;
+    encode_animation(frames, &mut encoded)?;
+    block.save(&encoded)
+}
+
+/// Load an animation saved with [`store_animation`], or `None` if `block` holds no
+/// animation.
+///
+/// # Errors
+///
+/// Returns [`Error::AnimationLengthMismatch`] if the animation was saved for a strip with
+/// a different number of LEDs than `N`, [`Error::FormatError`] if it has more than
+/// `MAX_FRAMES` frames, and [`Error::StorageCorrupted`] or [`Error::Flash`] if the block
+/// cannot be read.
+///
+/// See the [module example](self) for usage.
+#[cfg(target_os = "none")]
+pub fn load_animation<const N: usize, const MAX_FRAMES: usize>(
+    block: &mut crate::flash_array::FlashBlock,
+) -> Result<Option<Vec<(Frame1d<N>, Duration), MAX_FRAMES>>> {
+    block
+        .load::<Vec<u8, MAX_ENCODED_LEN>>()?
+        .map(|encoded| decode_animation(&encoded))
+        .transpose()
+}
@@ -0,0 +1,113 @@
+//! Host-only helpers for LED strips, for use in build scripts and tests.
+//!
+//! The built-in [`Gamma`] curves cover most needs. To try another exponent, such as 2.5
+//! for a dim room, generate its table in a build script with
+//! [`generate_gamma_table_f64`] and pass it to the strip as [`Gamma::Custom`].
+//!
+//! # Example
+//!
+//! Add `device-envoy` with the `host` feature under `[build-dependencies]`, then write the
+//! table to `OUT_DIR` from `build.rs`:
+//!
+//! ```rust,no_run
+//! // build.rs
+//! use std::{env, fs, path::PathBuf};
+//!
+//! use device_envoy::led_strip::host::generate_gamma_table_f64;
+//!
+//! fn main() {
+//!     let gamma_table = generate_gamma_table_f64(2.5);
+//!     let out_dir = PathBuf::from(env::var("OUT_DIR").expect("cargo sets OUT_DIR"));
+//!     fs::write(out_dir.join("gamma_table.bin"), gamma_table).expect("OUT_DIR is writable");
+//! }
+//! ```
+//!
+//! In the firmware, include the table as a `Gamma` constant and use it as a strip's
+//! `gamma` field:
+//!
+//! ```text
+//! const GAMMA_2_5: Gamma =
+//!     Gamma::Custom(include_bytes!(concat!(env!("OUT_DIR"), "/gamma_table.bin")));
+//!
+//! led_strip! {
+//!     Gpio0LedStrip {
+//!         pin: PIN_0,
+//!         len: 8,
+//!         gamma: GAMMA_2_5,
+//!     }
+//! }
+//! ```
+//!
+//! # Previewing a gamma and brightness choice
+//!
+//! [`combo_table`] returns the table a strip applies to each color channel for a given
+//! `gamma` and brightness cap, and [`combo_table_preview`] formats it for printing, so a
+//! test can show whether dim values survive before anything is flashed:
+//!
+//! ```rust
+//! use device_envoy::led_strip::Gamma;
+//! use device_envoy::led_strip::host::{combo_table, combo_table_preview};
+//!
+//! let table = combo_table(Gamma::Srgb, 128);
+//! assert_eq!(table[255], 128);
+//! println!("{}", combo_table_preview(Gamma::Srgb, 128));
+//! ```
+
+use core::fmt::Write as _;
+
+use super::{Gamma, generate_combo_table};
+
+/// Generate a 256-entry gamma lookup table for the exponent `gamma`, using `f64` math.
+///
+/// Each entry is `round((index / 255)^gamma * 255)`, the same formula as the built-in
+/// tables, so `generate_gamma_table_f64(2.2)` reproduces [`Gamma::Srgb`]. Exponents above
+/// 1.0 darken mid-tones; exponents below 1.0 brighten them.
+///
+/// # Panics
+///
+/// Panics if `gamma` is not a positive, finite number.
+///
+/// See the [module example](self) for writing the table from a build script.
+#[must_use]
+#[expect(
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    reason = "each rounded entry is between 0 and 255"
+)]
+pub fn generate_gamma_table_f64(gamma: f64) -> [u8; 256] {
+    assert!(
+        gamma.is_finite() && gamma > 0.0,
+        "gamma must be positive and finite"
+    );
+    core::array::from_fn(|index| {
+        let normalized = f64::from(u8::try_from(index).expect("index is below 256")) / 255.0;
+        (normalized.powf(gamma) * 255.0).round() as u8
+    })
+}
+
+/// The combined gamma and brightness table a strip uses: `table[value]` is the output for
+/// channel value `value` with `gamma` applied and the result scaled to `max_brightness`.
+///
+/// Strips compute this table at compile time from their `gamma` field and the brightness
+/// derived from `max_current`; this returns the same values for inspection on the host.
+#[must_use]
+pub const fn combo_table(gamma: Gamma, max_brightness: u8) -> [u8; 256] {
+    generate_combo_table(gamma, max_brightness)
+}
+
+/// Format [`combo_table`] as 16 rows of 16 outputs, each row labeled with its first input.
+///
+/// Output values of 0 for non-zero inputs show where dim colors turn fully off.
+#[must_use]
+pub fn combo_table_preview(gamma: Gamma, max_brightness: u8) -> String {
+    let table = combo_table(gamma, max_brightness);
+    let mut preview = format!("{gamma:?}, max_brightness {max_brightness}:\n");
+    for (row_index, row) in table.chunks(16).enumerate() {
+        write!(preview, "{:>3}:", row_index * 16).expect("writing to a String succeeds");
+        for value in row {
+            write!(preview, " {value:>3}").expect("writing to a String succeeds");
+        }
+        preview.push('\n');
+    }
+    preview
+}
@@ -41,9 +41,11 @@ pub mod pio_irqs;
 /// Utilities for converting frames to PNG images (host testing only).
 pub mod to_png;
 // These modules require embedded targets.
-#[cfg(target_os = "none")]
+#[cfg(any(target_os = "none", feature = "host"))]
 pub mod button;
 #[cfg(target_os = "none")]
+pub mod buzzer;
+#[cfg(target_os = "none")]
 pub mod char_lcd;
 #[cfg(all(feature = "wifi", target_os = "none"))]
 pub(crate) mod clock;
@@ -53,6 +55,10 @@ mod error;
 #[cfg(target_os = "none")]
 pub mod flash_array;
 #[cfg(target_os = "none")]
+pub mod healthcheck;
+#[cfg(all(feature = "tls", target_os = "none"))]
+pub mod https;
+#[cfg(target_os = "none")]
 pub mod ir;
 #[cfg(target_os = "none")]
 pub mod led;
@@ -60,11 +66,12 @@ pub mod led2d;
 #[cfg(target_os = "none")]
 pub mod led4;
 pub mod led_strip;
-#[cfg(target_os = "none")]
+pub mod resources;
+#[cfg(any(target_os = "none", feature = "host"))]
 pub mod rfid;
 #[cfg(target_os = "none")]
 pub mod servo;
-#[cfg(target_os = "none")]
+#[cfg(any(target_os = "none", feature = "host"))]
 pub mod servo_player;
 #[cfg(target_os = "none")]
 pub(crate) mod time_sync;
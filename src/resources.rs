@@ -0,0 +1,123 @@
+//! Make PIO and DMA allocation explicit, and catch conflicts at compile time.
+//!
+//! LED, wireless, and IR devices take a PIO block (and usually a DMA channel) by
+//! consuming its `Peri` token, so the compiler already rejects using one twice. The error
+//! it reports, however, is "use of moved value: `p.PIO0`", pointing at whichever device
+//! was set up second. That is confusing when one side of the conflict is a macro default
+//! you never wrote.
+//!
+//! [`DEFAULT_RESOURCES`] lists the PIO and DMA channel each device macro uses when its
+//! `pio` and `dma` fields are omitted. `WifiAuto::new` and the `ir` devices take their
+//! PIO as an argument instead; the examples pass `PIO0` (and `DMA_CH0` for
+//! `WifiAuto`), which collide with the LED defaults.
+//!
+//! The [`resources!`](crate::resources!) macro records which device claims which
+//! resources in one place. It fails to compile, naming both devices, if two of them claim
+//! the same PIO or DMA channel.
+//!
+//! # Example
+//!
+//! ```rust
+//! use device_envoy::resources;
+//!
+//! // One line per device: `name: PIO` or `name: PIO, DMA_CHANNEL`.
+//! resources! {
+//!     wifi: PIO0, DMA_CH0;
+//!     led12x4: PIO1, DMA_CH1;
+//!     ir: PIO2;
+//! }
+//! ```
+//!
+//! Claiming `PIO0` for both `wifi` and `led12x4` instead fails with
+//! "`wifi` and `led12x4` both claim PIO0; give one of them a different PIO".
+
+/// The PIO block and DMA channel a device macro uses when its `pio` and `dma` fields are
+/// omitted. See the [module documentation](self).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DefaultResources {
+    /// The device macro, for example `"led_strip!"`.
+    pub device: &'static str,
+    /// The default PIO block, for example `"PIO0"`.
+    pub pio: &'static str,
+    /// The default DMA channel, for example `"DMA_CH0"`.
+    pub dma: &'static str,
+}
+
+/// Default PIO and DMA claims of each device macro.
+///
+/// `led_strips!` gives its first strip `DMA_CH0` and each later strip the next channel. All three default to `PIO0`, so using two of them, or one of
+/// them with `WifiAuto` on `PIO0`, requires setting `pio` explicitly.
+pub const DEFAULT_RESOURCES: [DefaultResources; 3] = [
+    DefaultResources {
+        device: "led_strip!",
+        pio: "PIO0",
+        dma: "DMA_CH0",
+    },
+    DefaultResources {
+        device: "led_strips!",
+        pio: "PIO0",
+        dma: "DMA_CH0",
+    },
+    DefaultResources {
+        device: "led2d!",
+        pio: "PIO0",
+        dma: "DMA_CH0",
+    },
+];
+
+/// Compare two resource names in a const context. Used by [`resources!`](crate::resources!).
+#[doc(hidden)]
+#[must_use]
+pub const fn same_resource(first: &str, second: &str) -> bool {
+    let (first, second) = (first.as_bytes(), second.as_bytes());
+    if first.len() != second.len() {
+        return false;
+    }
+    let mut index = 0;
+    while index < first.len() {
+        if first[index] != second[index] {
+            return false;
+        }
+        index += 1;
+    }
+    true
+}
+
+/// Declare which device claims which PIO block and DMA channel, checked at compile time.
+///
+/// Each line is `name: PIO` or `name: PIO, DMA_CHANNEL`. Compilation fails, naming both
+/// devices, if two lines claim the same PIO or the same DMA channel.
+///
+/// See the [resources module documentation](mod@crate::resources) for an example.
+#[macro_export]
+macro_rules! resources {
+    ($($name:ident : $pio:ident $(, $dma:ident)?);+ $(;)?) => {
+        const _: () = {
+            $crate::resources!(@check $($name $pio [$($dma)?])+);
+        };
+    };
+    (@check $name:ident $pio:ident $dma:tt $($rest_name:ident $rest_pio:ident $rest_dma:tt)*) => {
+        $(
+            assert!(
+                !$crate::resources::same_resource(stringify!($pio), stringify!($rest_pio)),
+                concat!(
+                    "`", stringify!($name), "` and `", stringify!($rest_name), "` both claim ",
+                    stringify!($pio), "; give one of them a different PIO"
+                )
+            );
+            $crate::resources!(@check_dma $name $dma $rest_name $rest_dma);
+        )*
+        $crate::resources!(@check $($rest_name $rest_pio $rest_dma)*);
+    };
+    (@check) => {};
+    (@check_dma $name:ident [$dma:ident] $other_name:ident [$other_dma:ident]) => {
+        assert!(
+            !$crate::resources::same_resource(stringify!($dma), stringify!($other_dma)),
+            concat!(
+                "`", stringify!($name), "` and `", stringify!($other_name), "` both claim ",
+                stringify!($dma), "; give one of them a different DMA channel"
+            )
+        );
+    };
+    (@check_dma $name:ident $dma:tt $other_name:ident $other_dma:tt) => {};
+}
@@ -1,21 +1,48 @@
 //! A device abstraction for RFID readers using the MFRC522 chip.
 //!
+//! Besides reporting card UIDs, the reader can read the NDEF record that phones write to
+//! `NTAG21x` (NFC Forum Type 2) tags; see [`Rfid::read_ndef`].
+//!
 //! See [`Rfid`] for the primary example; helper functions link back here.
+//!
+//! Under `feature = "host"`, only the NDEF parsers ([`find_ndef_message`] and
+//! [`parse_ndef_message`]) are available, so they can be tested without hardware.
 
+#[cfg(not(feature = "host"))]
 use defmt::info;
+#[cfg(not(feature = "host"))]
 use embassy_executor::Spawner;
+#[cfg(not(feature = "host"))]
 use embassy_rp::Peri;
+#[cfg(not(feature = "host"))]
 use embassy_rp::dma::Channel;
+#[cfg(not(feature = "host"))]
 use embassy_rp::gpio::{Level, Output, Pin};
+#[cfg(not(feature = "host"))]
 use embassy_rp::peripherals::SPI0;
+#[cfg(not(feature = "host"))]
 use embassy_rp::spi::{ClkPin, Config as SpiConfig, MisoPin, MosiPin, Phase, Polarity, Spi};
+#[cfg(not(feature = "host"))]
 use embassy_sync::blocking_mutex::raw::CriticalSectionRawMutex;
+#[cfg(not(feature = "host"))]
 use embassy_sync::channel::Channel as EmbassyChannel;
+#[cfg(not(feature = "host"))]
+use embassy_sync::signal::Signal;
+#[cfg(not(feature = "host"))]
 use embassy_time::{Instant, Timer};
+#[cfg(not(feature = "host"))]
 use embedded_hal_bus::spi::{ExclusiveDevice, NoDelay};
+#[cfg(not(feature = "host"))]
 use esp_hal_mfrc522::MFRC522;
+#[cfg(not(feature = "host"))]
 use esp_hal_mfrc522::consts::UidSize;
+#[cfg(not(feature = "host"))]
 use esp_hal_mfrc522::drivers::SpiDriver;
+use heapless::String;
+#[cfg(not(feature = "host"))]
+use heapless::Vec;
+#[cfg(not(feature = "host"))]
+use portable_atomic::{AtomicBool, Ordering};
 
 use crate::{Error, Result};
 
@@ -29,6 +56,27 @@ pub enum RfidEvent {
     },
 }
 
+/// Maximum length, in bytes, of the URI or text returned by [`Rfid::read_ndef`].
+pub const NDEF_MAX_LEN: usize = 128;
+
+/// Maximum length, in bytes, of the language code of an [`NdefMessage::Text`] record.
+pub const NDEF_LANGUAGE_MAX_LEN: usize = 8;
+
+/// The first record of an NDEF message read by [`Rfid::read_ndef`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NdefMessage {
+    /// A URI record, with its abbreviated prefix (such as `https://`) expanded.
+    Uri(String<NDEF_MAX_LEN>),
+    /// A UTF-8 text record.
+    Text {
+        /// IANA language code, such as `en`.
+        language: String<NDEF_LANGUAGE_MAX_LEN>,
+        /// The text.
+        text: String<NDEF_MAX_LEN>,
+    },
+}
+
+#[cfg(not(feature = "host"))]
 /// Static type for RFID reader events
 pub type Mfrc522Device = MFRC522<
     SpiDriver<
@@ -36,9 +84,15 @@ pub type Mfrc522Device = MFRC522<
     >,
 >;
 
+#[cfg(not(feature = "host"))]
 /// Static type for the `Rfid` device abstraction.
-pub type RfidStatic = EmbassyChannel<CriticalSectionRawMutex, RfidEvent, 4>;
+pub struct RfidStatic {
+    events: EmbassyChannel<CriticalSectionRawMutex, RfidEvent, 4>,
+    ndef_requested: AtomicBool,
+    ndef_result: Signal<CriticalSectionRawMutex, Result<NdefMessage>>,
+}
 
+#[cfg(not(feature = "host"))]
 /// A device abstraction for an RFID reader using the MFRC522 chip.
 ///
 /// ```rust,no_run
@@ -76,11 +130,16 @@ pub struct Rfid<'a> {
     rfid_static: &'a RfidStatic,
 }
 
+#[cfg(not(feature = "host"))]
 impl Rfid<'_> {
     /// Create static channel resources for the RFID reader
     #[must_use]
     pub const fn new_static() -> RfidStatic {
-        EmbassyChannel::new()
+        RfidStatic {
+            events: EmbassyChannel::new(),
+            ndef_requested: AtomicBool::new(false),
+            ndef_result: Signal::new(),
+        }
     }
 
     /// Create a new RFID reader device abstraction
@@ -120,10 +179,50 @@ impl Rfid<'_> {
 
     /// Wait for the next RFID event (card detection)
     pub async fn wait_for_tap(&self) -> RfidEvent {
-        self.rfid_static.receive().await
+        self.rfid_static.events.receive().await
+    }
+
+    /// Wait for the next tag tap and read the first record of its NDEF message.
+    ///
+    /// Supports `NTAG21x` and other NFC Forum Type 2 tags holding a single well-known URI
+    /// or UTF-8 text record, as written by phone NFC apps. The tap is consumed here and is
+    /// not also reported by [`wait_for_tap`](Self::wait_for_tap).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Mfrc522Read`] if the tag is removed or a read fails, and
+    /// [`Error::NdefUnsupported`] if the tag holds no NDEF message, its first record is
+    /// not a URI or text record, or the record does not fit in [`NDEF_MAX_LEN`] bytes.
+    ///
+    /// # Cancellation
+    ///
+    /// Dropping the returned future (for example, when it loses a `select` or a
+    /// `with_timeout`) withdraws the request, so the next tap goes to
+    /// [`wait_for_tap`](Self::wait_for_tap) again. A tap whose NDEF read has already
+    /// started when the future is dropped is discarded.
+    pub async fn read_ndef(&self) -> Result<NdefMessage> {
+        self.rfid_static.ndef_result.reset();
+        self.rfid_static
+            .ndef_requested
+            .store(true, Ordering::Release);
+        let _ndef_request = NdefRequest(&self.rfid_static.ndef_requested);
+        self.rfid_static.ndef_result.wait().await
     }
 }
 
+/// Withdraws a pending [`Rfid::read_ndef`] request when dropped, so a cancelled read does
+/// not capture the next tap.
+#[cfg(not(feature = "host"))]
+struct NdefRequest<'a>(&'a AtomicBool);
+
+#[cfg(not(feature = "host"))]
+impl Drop for NdefRequest<'_> {
+    fn drop(&mut self) {
+        self.0.store(false, Ordering::Release);
+    }
+}
+
+#[cfg(not(feature = "host"))]
 /// Convert UID bytes to a fixed-size array, padding with zeros if needed
 fn uid_to_fixed_array(uid_bytes: &[u8]) -> [u8; 10] {
     let mut uid_key = [0u8; 10];
@@ -136,6 +235,7 @@ fn uid_to_fixed_array(uid_bytes: &[u8]) -> [u8; 10] {
     uid_key
 }
 
+#[cfg(not(feature = "host"))]
 /// Embassy task that continuously polls for RFID cards
 #[embassy_executor::task]
 async fn rfid_polling_task(mut mfrc522: Mfrc522Device, rfid_static: &'static RfidStatic) -> ! {
@@ -159,19 +259,233 @@ async fn rfid_polling_task(mut mfrc522: Mfrc522Device, rfid_static: &'static Rfi
 
         info!("UID read successfully ({} bytes)", uid.uid_bytes.len());
 
-        // Convert to fixed-size array
-        let uid_key = uid_to_fixed_array(&uid.uid_bytes);
+        if rfid_static.ndef_requested.swap(false, Ordering::AcqRel) {
+            // A `read_ndef` caller is waiting: read the tag while it is still selected
+            let result = read_ndef_message(&mut mfrc522).await;
+            rfid_static.ndef_result.signal(result);
+        } else {
+            // Convert to fixed-size array
+            let uid_key = uid_to_fixed_array(&uid.uid_bytes);
 
-        // Send event to channel
-        rfid_static
-            .send(RfidEvent::CardDetected { uid: uid_key })
-            .await;
+            // Send event to channel
+            rfid_static
+                .events
+                .send(RfidEvent::CardDetected { uid: uid_key })
+                .await;
+        }
 
         // Wait to prevent repeated detections of the same card
         Timer::after_millis(50).await;
     }
 }
 
+// Type 2 tags store user data from page 4; each READ returns four 4-byte pages.
+#[cfg(not(feature = "host"))]
+const FIRST_USER_PAGE: u8 = 4;
+#[cfg(not(feature = "host"))]
+const PAGES_PER_READ: usize = 4;
+#[cfg(not(feature = "host"))]
+const TAG_MEMORY_MAX_LEN: usize = 256;
+
+#[cfg(not(feature = "host"))]
+/// Read tag memory four pages at a time until it holds a complete NDEF message, then
+/// parse its first record.
+async fn read_ndef_message(mfrc522: &mut Mfrc522Device) -> Result<NdefMessage> {
+    let mut memory: Vec<u8, TAG_MEMORY_MAX_LEN> = Vec::new();
+    for page in (FIRST_USER_PAGE..).step_by(PAGES_PER_READ) {
+        // 16 data bytes followed by a 2-byte CRC
+        let mut read_buffer = [0u8; 18];
+        let mut read_len = 18;
+        mfrc522
+            .mifare_read(page, &mut read_buffer, &mut read_len)
+            .await
+            .map_err(Error::Mfrc522Read)?;
+        let [data @ .., _, _] = read_buffer;
+        memory
+            .extend_from_slice(&data)
+            .map_err(|()| Error::NdefUnsupported)?;
+        if let Some(message) = find_ndef_message(&memory)? {
+            return parse_ndef_message(message);
+        }
+    }
+    Err(Error::NdefUnsupported)
+}
+
+// URI identifier codes from the NFC Forum URI Record Type Definition.
+const URI_PREFIXES: [&str; 36] = [
+    "",
+    "http://www.",
+    "https://www.",
+    "http://",
+    "https://",
+    "tel:",
+    "mailto:",
+    "ftp://anonymous:anonymous@",
+    "ftp://ftp.",
+    "ftps://",
+    "sftp://",
+    "smb://",
+    "nfs://",
+    "ftp://",
+    "dav://",
+    "news:",
+    "telnet://",
+    "imap:",
+    "rtsp://",
+    "urn:",
+    "pop:",
+    "sip:",
+    "sips:",
+    "tftp:",
+    "btspp://",
+    "btl2cap://",
+    "btgoep://",
+    "tcpobex://",
+    "irdaobex://",
+    "file://",
+    "urn:epc:id:",
+    "urn:epc:tag:",
+    "urn:epc:pat:",
+    "urn:epc:raw:",
+    "urn:epc:",
+    "urn:nfc:",
+];
+
+const TLV_NULL: u8 = 0x00;
+const TLV_NDEF_MESSAGE: u8 = 0x03;
+const TLV_TERMINATOR: u8 = 0xFE;
+
+/// Splits the length field off a TLV block whose type byte has been removed, returning the
+/// value length and the bytes after the length field, or `None` if `after_type` ends inside
+/// the length field.
+fn split_tlv_length(after_type: &[u8]) -> Option<(usize, &[u8])> {
+    match after_type {
+        [0xFF, high, low, rest @ ..] => {
+            Some((usize::from(u16::from_be_bytes([*high, *low])), rest))
+        }
+        [] | [0xFF, ..] => None,
+        [length, rest @ ..] => Some((usize::from(*length), rest)),
+    }
+}
+
+/// Walks the TLV blocks in Type 2 tag memory (starting at page 4) and returns the value
+/// of the first NDEF message TLV, or `Ok(None)` if `memory` ends before the message does.
+///
+/// [`Rfid::read_ndef`] calls this after each read of tag memory; pass the result to
+/// [`parse_ndef_message`].
+///
+/// # Errors
+///
+/// Returns [`Error::NdefUnsupported`] if a terminator TLV comes before any NDEF message.
+pub fn find_ndef_message(memory: &[u8]) -> Result<Option<&[u8]>> {
+    let mut remaining = memory;
+    loop {
+        let Some((&tlv_type, after_type)) = remaining.split_first() else {
+            return Ok(None);
+        };
+        match tlv_type {
+            TLV_NULL => remaining = after_type,
+            TLV_TERMINATOR => return Err(Error::NdefUnsupported),
+            _ => {
+                let Some((value, after_value)) = split_tlv_length(after_type)
+                    .and_then(|(length, after_length)| after_length.split_at_checked(length))
+                else {
+                    return Ok(None);
+                };
+                if tlv_type == TLV_NDEF_MESSAGE {
+                    return Ok(Some(value));
+                }
+                remaining = after_value;
+            }
+        }
+    }
+}
+
+fn split_byte(bytes: &[u8]) -> Result<(u8, &[u8])> {
+    bytes
+        .split_first()
+        .map(|(&byte, rest)| (byte, rest))
+        .ok_or(Error::NdefUnsupported)
+}
+
+fn split_bytes(bytes: &[u8], len: usize) -> Result<(&[u8], &[u8])> {
+    bytes.split_at_checked(len).ok_or(Error::NdefUnsupported)
+}
+
+/// Parses the first record of an NDEF message, if it is a well-known URI or text record.
+///
+/// # Errors
+///
+/// Returns [`Error::NdefUnsupported`] if the record is truncated, is not a URI or UTF-8
+/// text record, uses an unknown URI prefix code, or does not fit in [`NDEF_MAX_LEN`]
+/// bytes.
+pub fn parse_ndef_message(message: &[u8]) -> Result<NdefMessage> {
+    const FLAG_SHORT_RECORD: u8 = 0x10;
+    const FLAG_ID_LENGTH: u8 = 0x08;
+    const TNF_MASK: u8 = 0x07;
+    const TNF_WELL_KNOWN: u8 = 0x01;
+    const TEXT_UTF16: u8 = 0x80;
+    const TEXT_LANGUAGE_LENGTH_MASK: u8 = 0x3F;
+
+    let (header, rest) = split_byte(message)?;
+    let (type_length, rest) = split_byte(rest)?;
+    let (payload_length, rest) = if header & FLAG_SHORT_RECORD == 0 {
+        let (length_bytes, rest) = split_bytes(rest, 4)?;
+        let length_bytes = length_bytes
+            .try_into()
+            .map_err(|_| Error::NdefUnsupported)?;
+        let payload_length = usize::try_from(u32::from_be_bytes(length_bytes))
+            .map_err(|_| Error::NdefUnsupported)?;
+        (payload_length, rest)
+    } else {
+        let (payload_length, rest) = split_byte(rest)?;
+        (usize::from(payload_length), rest)
+    };
+    let (id_length, rest) = if header & FLAG_ID_LENGTH == 0 {
+        (0, rest)
+    } else {
+        split_byte(rest)?
+    };
+    let (record_type, rest) = split_bytes(rest, usize::from(type_length))?;
+    let (_id, rest) = split_bytes(rest, usize::from(id_length))?;
+    let (payload, _) = split_bytes(rest, payload_length)?;
+    if header & TNF_MASK != TNF_WELL_KNOWN {
+        return Err(Error::NdefUnsupported);
+    }
+
+    match record_type {
+        b"U" => {
+            let (prefix_code, uri) = split_byte(payload)?;
+            let prefix = URI_PREFIXES
+                .get(usize::from(prefix_code))
+                .ok_or(Error::NdefUnsupported)?;
+            let uri = core::str::from_utf8(uri).map_err(|_| Error::NdefUnsupported)?;
+            let mut full_uri = String::new();
+            full_uri
+                .push_str(prefix)
+                .and_then(|()| full_uri.push_str(uri))
+                .map_err(|()| Error::NdefUnsupported)?;
+            Ok(NdefMessage::Uri(full_uri))
+        }
+        b"T" => {
+            let (status, rest) = split_byte(payload)?;
+            if status & TEXT_UTF16 != 0 {
+                return Err(Error::NdefUnsupported);
+            }
+            let (language, text) =
+                split_bytes(rest, usize::from(status & TEXT_LANGUAGE_LENGTH_MASK))?;
+            let language = core::str::from_utf8(language).map_err(|_| Error::NdefUnsupported)?;
+            let text = core::str::from_utf8(text).map_err(|_| Error::NdefUnsupported)?;
+            Ok(NdefMessage::Text {
+                language: String::try_from(language).map_err(|()| Error::NdefUnsupported)?,
+                text: String::try_from(text).map_err(|()| Error::NdefUnsupported)?,
+            })
+        }
+        _ => Err(Error::NdefUnsupported),
+    }
+}
+
+#[cfg(not(feature = "host"))]
 /// Initialize MFRC522 hardware (internal helper function)
 async fn init_mfrc522_hardware<Sck, Mosi, Miso, Dma0, Dma1, Cs, Rst>(
     spi: Peri<'static, SPI0>,
@@ -9,21 +9,24 @@ use defmt::info;
 use embassy_rp::clocks::clk_sys_freq;
 use embassy_rp::pwm::{Config, Pwm};
 
-const SERVO_PERIOD_US: u16 = 20_000; // 20 ms
-
 /// Default minimum pulse width for hobby servos (microseconds).
 pub const SERVO_MIN_US_DEFAULT: u16 = 500;
 
 /// Default maximum pulse width for hobby servos (microseconds).
 pub const SERVO_MAX_US_DEFAULT: u16 = 2_500;
 
+/// Default PWM frequency for hobby servos (50 Hz, a 20 ms frame).
+pub const SERVO_PWM_FREQ_HZ_DEFAULT: u32 = 50;
+
 /// Create a servo with keyword arguments and default pulse widths.
 ///
 /// Required fields: `pin`, `slice`.
 ///
 /// Optional fields: `min_us`, `max_us`, `max_degrees` (defaults to
 /// [`SERVO_MIN_US_DEFAULT`]/[`SERVO_MAX_US_DEFAULT`]/[`Servo::DEFAULT_MAX_DEGREES`]),
-/// plus `channel: A/B` or `odd`/`even` to override the inferred channel.
+/// `pwm_freq_hz` (default [`SERVO_PWM_FREQ_HZ_DEFAULT`]; see
+/// [PWM Frequency](Servo#pwm-frequency)), plus `channel: A/B` or `odd`/`even` to override
+/// the inferred channel.
 ///
 /// See [`Servo`] for details and examples.
 #[macro_export]
@@ -45,6 +48,7 @@ macro_rules! __servo_impl {
         min_us: $min_us:expr,
         max_us: $max_us:expr,
         max_degrees: $max_degrees:expr,
+        pwm_freq_hz: $pwm_freq_hz:expr,
         fields: [ ]
     ) => {
         $crate::__servo_impl! {
@@ -54,7 +58,8 @@ macro_rules! __servo_impl {
             channel: $channel,
             min_us: $min_us,
             max_us: $max_us,
-            max_degrees: $max_degrees
+            max_degrees: $max_degrees,
+            pwm_freq_hz: $pwm_freq_hz
         }
     };
 
@@ -65,6 +70,7 @@ macro_rules! __servo_impl {
         min_us: $min_us:expr,
         max_us: $max_us:expr,
         max_degrees: $max_degrees:expr,
+        pwm_freq_hz: $pwm_freq_hz:expr,
         fields: [ pin: $pin_value:expr, $($rest:tt)* ]
     ) => {
         $crate::__servo_impl! {
@@ -75,6 +81,7 @@ macro_rules! __servo_impl {
             min_us: $min_us,
             max_us: $max_us,
             max_degrees: $max_degrees,
+            pwm_freq_hz: $pwm_freq_hz,
             fields: [ $($rest)* ]
         }
     };
@@ -86,6 +93,7 @@ macro_rules! __servo_impl {
         min_us: $min_us:expr,
         max_us: $max_us:expr,
         max_degrees: $max_degrees:expr,
+        pwm_freq_hz: $pwm_freq_hz:expr,
         fields: [ pin: $pin_value:expr ]
     ) => {
         $crate::__servo_impl! {
@@ -96,6 +104,7 @@ macro_rules! __servo_impl {
             min_us: $min_us,
             max_us: $max_us,
             max_degrees: $max_degrees,
+            pwm_freq_hz: $pwm_freq_hz,
             fields: [ ]
         }
     };
@@ -107,6 +116,7 @@ macro_rules! __servo_impl {
         min_us: $min_us:expr,
         max_us: $max_us:expr,
         max_degrees: $max_degrees:expr,
+        pwm_freq_hz: $pwm_freq_hz:expr,
         fields: [ slice: $slice_value:expr, $($rest:tt)* ]
     ) => {
         $crate::__servo_impl! {
@@ -117,6 +127,7 @@ macro_rules! __servo_impl {
             min_us: $min_us,
             max_us: $max_us,
             max_degrees: $max_degrees,
+            pwm_freq_hz: $pwm_freq_hz,
             fields: [ $($rest)* ]
         }
     };
@@ -128,6 +139,7 @@ macro_rules! __servo_impl {
         min_us: $min_us:expr,
         max_us: $max_us:expr,
         max_degrees: $max_degrees:expr,
+        pwm_freq_hz: $pwm_freq_hz:expr,
         fields: [ slice: $slice_value:expr ]
     ) => {
         $crate::__servo_impl! {
@@ -138,6 +150,7 @@ macro_rules! __servo_impl {
             min_us: $min_us,
             max_us: $max_us,
             max_degrees: $max_degrees,
+            pwm_freq_hz: $pwm_freq_hz,
             fields: [ ]
         }
     };
@@ -149,6 +162,7 @@ macro_rules! __servo_impl {
         min_us: $min_us:expr,
         max_us: $max_us:expr,
         max_degrees: $max_degrees:expr,
+        pwm_freq_hz: $pwm_freq_hz:expr,
         fields: [ min_us: $min_us_value:expr, $($rest:tt)* ]
     ) => {
         $crate::__servo_impl! {
@@ -159,6 +173,7 @@ macro_rules! __servo_impl {
             min_us: $min_us_value,
             max_us: $max_us,
             max_degrees: $max_degrees,
+            pwm_freq_hz: $pwm_freq_hz,
             fields: [ $($rest)* ]
         }
     };
@@ -170,6 +185,7 @@ macro_rules! __servo_impl {
         min_us: $min_us:expr,
         max_us: $max_us:expr,
         max_degrees: $max_degrees:expr,
+        pwm_freq_hz: $pwm_freq_hz:expr,
         fields: [ min_us: $min_us_value:expr ]
     ) => {
         $crate::__servo_impl! {
@@ -180,6 +196,7 @@ macro_rules! __servo_impl {
             min_us: $min_us_value,
             max_us: $max_us,
             max_degrees: $max_degrees,
+            pwm_freq_hz: $pwm_freq_hz,
             fields: [ ]
         }
     };
@@ -191,6 +208,7 @@ macro_rules! __servo_impl {
         min_us: $min_us:expr,
         max_us: $max_us:expr,
         max_degrees: $max_degrees:expr,
+        pwm_freq_hz: $pwm_freq_hz:expr,
         fields: [ max_us: $max_us_value:expr, $($rest:tt)* ]
     ) => {
         $crate::__servo_impl! {
@@ -201,6 +219,7 @@ macro_rules! __servo_impl {
             min_us: $min_us,
             max_us: $max_us_value,
             max_degrees: $max_degrees,
+            pwm_freq_hz: $pwm_freq_hz,
             fields: [ $($rest)* ]
         }
     };
@@ -212,6 +231,7 @@ macro_rules! __servo_impl {
         min_us: $min_us:expr,
         max_us: $max_us:expr,
         max_degrees: $max_degrees:expr,
+        pwm_freq_hz: $pwm_freq_hz:expr,
         fields: [ max_us: $max_us_value:expr ]
     ) => {
         $crate::__servo_impl! {
@@ -222,6 +242,7 @@ macro_rules! __servo_impl {
             min_us: $min_us,
             max_us: $max_us_value,
             max_degrees: $max_degrees,
+            pwm_freq_hz: $pwm_freq_hz,
             fields: [ ]
         }
     };
@@ -233,6 +254,7 @@ macro_rules! __servo_impl {
         min_us: $min_us:expr,
         max_us: $max_us:expr,
         max_degrees: $max_degrees:expr,
+        pwm_freq_hz: $pwm_freq_hz:expr,
         fields: [ max_degrees: $max_degrees_value:expr, $($rest:tt)* ]
     ) => {
         $crate::__servo_impl! {
@@ -243,6 +265,7 @@ macro_rules! __servo_impl {
             min_us: $min_us,
             max_us: $max_us,
             max_degrees: $max_degrees_value,
+            pwm_freq_hz: $pwm_freq_hz,
             fields: [ $($rest)* ]
         }
     };
@@ -254,6 +277,7 @@ macro_rules! __servo_impl {
         min_us: $min_us:expr,
         max_us: $max_us:expr,
         max_degrees: $max_degrees:expr,
+        pwm_freq_hz: $pwm_freq_hz:expr,
         fields: [ max_degrees: $max_degrees_value:expr ]
     ) => {
         $crate::__servo_impl! {
@@ -264,6 +288,53 @@ macro_rules! __servo_impl {
             min_us: $min_us,
             max_us: $max_us,
             max_degrees: $max_degrees_value,
+            pwm_freq_hz: $pwm_freq_hz,
+            fields: [ ]
+        }
+    };
+
+    (@__fill_defaults
+        pin: $pin:tt,
+        slice: $slice:tt,
+        channel: $channel:tt,
+        min_us: $min_us:expr,
+        max_us: $max_us:expr,
+        max_degrees: $max_degrees:expr,
+        pwm_freq_hz: $pwm_freq_hz:expr,
+        fields: [ pwm_freq_hz: $pwm_freq_hz_value:expr, $($rest:tt)* ]
+    ) => {
+        $crate::__servo_impl! {
+            @__fill_defaults
+            pin: $pin,
+            slice: $slice,
+            channel: $channel,
+            min_us: $min_us,
+            max_us: $max_us,
+            max_degrees: $max_degrees,
+            pwm_freq_hz: $pwm_freq_hz_value,
+            fields: [ $($rest)* ]
+        }
+    };
+
+    (@__fill_defaults
+        pin: $pin:tt,
+        slice: $slice:tt,
+        channel: $channel:tt,
+        min_us: $min_us:expr,
+        max_us: $max_us:expr,
+        max_degrees: $max_degrees:expr,
+        pwm_freq_hz: $pwm_freq_hz:expr,
+        fields: [ pwm_freq_hz: $pwm_freq_hz_value:expr ]
+    ) => {
+        $crate::__servo_impl! {
+            @__fill_defaults
+            pin: $pin,
+            slice: $slice,
+            channel: $channel,
+            min_us: $min_us,
+            max_us: $max_us,
+            max_degrees: $max_degrees,
+            pwm_freq_hz: $pwm_freq_hz_value,
             fields: [ ]
         }
     };
@@ -275,6 +346,7 @@ macro_rules! __servo_impl {
         min_us: $min_us:expr,
         max_us: $max_us:expr,
         max_degrees: $max_degrees:expr,
+        pwm_freq_hz: $pwm_freq_hz:expr,
         fields: [ channel: A, $($rest:tt)* ]
     ) => {
         $crate::__servo_impl! {
@@ -285,6 +357,7 @@ macro_rules! __servo_impl {
             min_us: $min_us,
             max_us: $max_us,
             max_degrees: $max_degrees,
+            pwm_freq_hz: $pwm_freq_hz,
             fields: [ $($rest)* ]
         }
     };
@@ -296,6 +369,7 @@ macro_rules! __servo_impl {
         min_us: $min_us:expr,
         max_us: $max_us:expr,
         max_degrees: $max_degrees:expr,
+        pwm_freq_hz: $pwm_freq_hz:expr,
         fields: [ channel: A ]
     ) => {
         $crate::__servo_impl! {
@@ -306,6 +380,7 @@ macro_rules! __servo_impl {
             min_us: $min_us,
             max_us: $max_us,
             max_degrees: $max_degrees,
+            pwm_freq_hz: $pwm_freq_hz,
             fields: [ ]
         }
     };
@@ -317,6 +392,7 @@ macro_rules! __servo_impl {
         min_us: $min_us:expr,
         max_us: $max_us:expr,
         max_degrees: $max_degrees:expr,
+        pwm_freq_hz: $pwm_freq_hz:expr,
         fields: [ channel: B, $($rest:tt)* ]
     ) => {
         $crate::__servo_impl! {
@@ -327,6 +403,7 @@ macro_rules! __servo_impl {
             min_us: $min_us,
             max_us: $max_us,
             max_degrees: $max_degrees,
+            pwm_freq_hz: $pwm_freq_hz,
             fields: [ $($rest)* ]
         }
     };
@@ -338,6 +415,7 @@ macro_rules! __servo_impl {
         min_us: $min_us:expr,
         max_us: $max_us:expr,
         max_degrees: $max_degrees:expr,
+        pwm_freq_hz: $pwm_freq_hz:expr,
         fields: [ channel: B ]
     ) => {
         $crate::__servo_impl! {
@@ -348,6 +426,7 @@ macro_rules! __servo_impl {
             min_us: $min_us,
             max_us: $max_us,
             max_degrees: $max_degrees,
+            pwm_freq_hz: $pwm_freq_hz,
             fields: [ ]
         }
     };
@@ -359,6 +438,7 @@ macro_rules! __servo_impl {
         min_us: $min_us:expr,
         max_us: $max_us:expr,
         max_degrees: $max_degrees:expr,
+        pwm_freq_hz: $pwm_freq_hz:expr,
         fields: [ even, $($rest:tt)* ]
     ) => {
         $crate::__servo_impl! {
@@ -369,6 +449,7 @@ macro_rules! __servo_impl {
             min_us: $min_us,
             max_us: $max_us,
             max_degrees: $max_degrees,
+            pwm_freq_hz: $pwm_freq_hz,
             fields: [ $($rest)* ]
         }
     };
@@ -380,6 +461,7 @@ macro_rules! __servo_impl {
         min_us: $min_us:expr,
         max_us: $max_us:expr,
         max_degrees: $max_degrees:expr,
+        pwm_freq_hz: $pwm_freq_hz:expr,
         fields: [ even ]
     ) => {
         $crate::__servo_impl! {
@@ -390,6 +472,7 @@ macro_rules! __servo_impl {
             min_us: $min_us,
             max_us: $max_us,
             max_degrees: $max_degrees,
+            pwm_freq_hz: $pwm_freq_hz,
             fields: [ ]
         }
     };
@@ -401,6 +484,7 @@ macro_rules! __servo_impl {
         min_us: $min_us:expr,
         max_us: $max_us:expr,
         max_degrees: $max_degrees:expr,
+        pwm_freq_hz: $pwm_freq_hz:expr,
         fields: [ odd, $($rest:tt)* ]
     ) => {
         $crate::__servo_impl! {
@@ -411,6 +495,7 @@ macro_rules! __servo_impl {
             min_us: $min_us,
             max_us: $max_us,
             max_degrees: $max_degrees,
+            pwm_freq_hz: $pwm_freq_hz,
             fields: [ $($rest)* ]
         }
     };
@@ -422,6 +507,7 @@ macro_rules! __servo_impl {
         min_us: $min_us:expr,
         max_us: $max_us:expr,
         max_degrees: $max_degrees:expr,
+        pwm_freq_hz: $pwm_freq_hz:expr,
         fields: [ odd ]
     ) => {
         $crate::__servo_impl! {
@@ -432,6 +518,7 @@ macro_rules! __servo_impl {
             min_us: $min_us,
             max_us: $max_us,
             max_degrees: $max_degrees,
+            pwm_freq_hz: $pwm_freq_hz,
             fields: [ ]
         }
     };
@@ -442,7 +529,8 @@ macro_rules! __servo_impl {
         channel: $channel:tt,
         min_us: $min_us:expr,
         max_us: $max_us:expr,
-        max_degrees: $max_degrees:expr
+        max_degrees: $max_degrees:expr,
+        pwm_freq_hz: $pwm_freq_hz:expr
     ) => {
         compile_error!("servo! requires `pin: ...`");
     };
@@ -453,7 +541,8 @@ macro_rules! __servo_impl {
         channel: $channel:tt,
         min_us: $min_us:expr,
         max_us: $max_us:expr,
-        max_degrees: $max_degrees:expr
+        max_degrees: $max_degrees:expr,
+        pwm_freq_hz: $pwm_freq_hz:expr
     ) => {
         compile_error!("servo! requires `slice: ...`");
     };
@@ -464,9 +553,10 @@ macro_rules! __servo_impl {
         channel: _UNSET_,
         min_us: $min_us:expr,
         max_us: $max_us:expr,
-        max_degrees: $max_degrees:expr
+        max_degrees: $max_degrees:expr,
+        pwm_freq_hz: $pwm_freq_hz:expr
     ) => {
-        $crate::servo::servo_from_pin_slice($pin, $slice, $min_us, $max_us, $max_degrees)
+        $crate::servo::servo_from_pin_slice($pin, $slice, $min_us, $max_us, $max_degrees, $pwm_freq_hz)
     };
 
     (@__build
@@ -475,7 +565,8 @@ macro_rules! __servo_impl {
         channel: A,
         min_us: $min_us:expr,
         max_us: $max_us:expr,
-        max_degrees: $max_degrees:expr
+        max_degrees: $max_degrees:expr,
+        pwm_freq_hz: $pwm_freq_hz:expr
     ) => {
         $crate::servo::Servo::new_output_a(
             embassy_rp::pwm::Pwm::new_output_a(
@@ -486,6 +577,7 @@ macro_rules! __servo_impl {
             $min_us,
             $max_us,
             $max_degrees,
+            $pwm_freq_hz,
         )
     };
 
@@ -495,7 +587,8 @@ macro_rules! __servo_impl {
         channel: B,
         min_us: $min_us:expr,
         max_us: $max_us:expr,
-        max_degrees: $max_degrees:expr
+        max_degrees: $max_degrees:expr,
+        pwm_freq_hz: $pwm_freq_hz:expr
     ) => {
         $crate::servo::Servo::new_output_b(
             embassy_rp::pwm::Pwm::new_output_b(
@@ -506,6 +599,7 @@ macro_rules! __servo_impl {
             $min_us,
             $max_us,
             $max_degrees,
+            $pwm_freq_hz,
         )
     };
 
@@ -520,6 +614,7 @@ macro_rules! __servo_impl {
             min_us: $crate::servo::SERVO_MIN_US_DEFAULT,
             max_us: $crate::servo::SERVO_MAX_US_DEFAULT,
             max_degrees: $crate::servo::Servo::DEFAULT_MAX_DEGREES,
+            pwm_freq_hz: $crate::servo::SERVO_PWM_FREQ_HZ_DEFAULT,
             fields: [ $($fields)* ]
         }
     };
@@ -540,6 +635,7 @@ pub fn servo_from_pin_slice<'d, P, S>(
     min_us: u16,
     max_us: u16,
     max_degrees: u16,
+    pwm_freq_hz: u32,
 ) -> Servo<'d>
 where
     P: ServoPwmPin<S>,
@@ -547,9 +643,9 @@ where
 {
     let pwm = P::new_pwm(slice, pin);
     if P::IS_CHANNEL_A {
-        Servo::new_output_a(pwm, min_us, max_us, max_degrees)
+        Servo::new_output_a(pwm, min_us, max_us, max_degrees, pwm_freq_hz)
     } else {
-        Servo::new_output_b(pwm, min_us, max_us, max_degrees)
+        Servo::new_output_b(pwm, min_us, max_us, max_degrees, pwm_freq_hz)
     }
 }
 
@@ -678,6 +774,38 @@ servo_pin_map!(PIN_47, PWM_SLICE11, B);
 ///     servo.relax();                                  // Let the servo relax. It will re-enable on next set_degrees()
 /// }
 /// ```
+///
+/// # PWM Frequency
+///
+/// By default the control signal repeats at 50 Hz (every 20 ms), which every hobby servo
+/// accepts. Many digital servos also accept faster frame rates, typically up to 300–333 Hz,
+/// which gives them more frequent position updates and smoother, stiffer holding. Set
+/// `pwm_freq_hz` in [`servo!`] or [`servo_player!`](macro@crate::servo_player) to use one:
+///
+/// ```rust,no_run
+/// # #![no_std]
+/// # #![no_main]
+/// use device_envoy::servo;
+/// # use core::panic::PanicInfo;
+/// # #[panic_handler]
+/// # fn panic(_info: &PanicInfo) -> ! { loop {} }
+/// # fn example(p: embassy_rp::Peripherals) {
+/// let mut servo = servo! {
+///     pin: p.PIN_11,
+///     slice: p.PWM_SLICE5,
+///     pwm_freq_hz: 300, // Digital servo rated for 300 Hz.
+/// };
+/// servo.set_degrees(90);
+/// # }
+/// ```
+///
+/// Pulse widths (`min_us`, `max_us`) keep their meaning at any frequency; only the gap between
+/// pulses shrinks. The frame must still be longer than `max_us`, so `pwm_freq_hz` must be at
+/// least 16 Hz and less than `1_000_000 / max_us` (399 Hz with the default 2500 µs).
+///
+/// **Only raise the frequency for servos whose datasheet allows it.** Analog servos (such as
+/// the SG90) expect about 50 Hz; driving them much faster can make them buzz, overheat, and
+/// burn out.
 pub struct Servo<'d> {
     pwm: Pwm<'d>,
     cfg: Config, // Store config to avoid recreating default (which resets divider)
@@ -708,15 +836,41 @@ impl<'d> Servo<'d> {
     /// Create a servo on a PWM output A channel.
     ///
     /// See the [`Servo`] example for usage.
-    pub(crate) fn new_output_a(pwm: Pwm<'d>, min_us: u16, max_us: u16, max_degrees: u16) -> Self {
-        Self::init(pwm, ServoChannel::A, min_us, max_us, max_degrees)
+    pub(crate) fn new_output_a(
+        pwm: Pwm<'d>,
+        min_us: u16,
+        max_us: u16,
+        max_degrees: u16,
+        pwm_freq_hz: u32,
+    ) -> Self {
+        Self::init(
+            pwm,
+            ServoChannel::A,
+            min_us,
+            max_us,
+            max_degrees,
+            pwm_freq_hz,
+        )
     }
 
     /// Create a servo on a PWM output B channel.
     ///
     /// See the [`Servo`] example for usage.
-    pub(crate) fn new_output_b(pwm: Pwm<'d>, min_us: u16, max_us: u16, max_degrees: u16) -> Self {
-        Self::init(pwm, ServoChannel::B, min_us, max_us, max_degrees)
+    pub(crate) fn new_output_b(
+        pwm: Pwm<'d>,
+        min_us: u16,
+        max_us: u16,
+        max_degrees: u16,
+        pwm_freq_hz: u32,
+    ) -> Self {
+        Self::init(
+            pwm,
+            ServoChannel::B,
+            min_us,
+            max_us,
+            max_degrees,
+            pwm_freq_hz,
+        )
     }
 
     /// Configure PWM and initialize servo. Internal shared logic.
@@ -726,10 +880,12 @@ impl<'d> Servo<'d> {
         min_us: u16,
         max_us: u16,
         max_degrees: u16,
+        pwm_freq_hz: u32,
     ) -> Self {
         // TODO: consider if these could/should be checked at compile time.
         assert!(min_us < max_us, "min_us must be less than max_us");
         assert!(max_degrees > 0, "max_degrees must be positive");
+        assert!(pwm_freq_hz > 0, "pwm_freq_hz must be positive");
         let clk = clk_sys_freq() as u64; // Hz
         // Aim for tick ≈ 1 µs: divider = clk_sys / 1_000_000 (with /16 fractional)
         let mut div_int = (clk / 1_000_000).clamp(1, 255) as u16;
@@ -740,9 +896,15 @@ impl<'d> Servo<'d> {
             div_int = (div_int + 1).min(255);
         }
 
-        let top = SERVO_PERIOD_US - 1; // 19999 -> 20_000 ticks/frame
+        // One tick ≈ 1 µs, so the frame is 1_000_000 / pwm_freq_hz ticks (20_000 at 50 Hz).
+        let period_us = u16::try_from(1_000_000 / pwm_freq_hz)
+            .expect("pwm_freq_hz must be at least 16 Hz so the frame fits the PWM counter");
+        let top = period_us - 1;
         assert!(min_us <= top, "min_us must fit in the PWM frame");
-        assert!(max_us <= top, "max_us must fit in the PWM frame");
+        assert!(
+            max_us <= top,
+            "max_us must fit in the PWM frame (lower pwm_freq_hz)"
+        );
 
         let mut cfg = Config::default();
         cfg.top = top;
@@ -760,8 +922,8 @@ impl<'d> Servo<'d> {
         pwm.set_config(&cfg);
 
         info!(
-            "servo clk={}Hz div={}.{} top={}",
-            clk, div_int, div_frac, top
+            "servo clk={}Hz div={}.{} top={} freq={}Hz",
+            clk, div_int, div_frac, top, pwm_freq_hz
         );
 
         let mut servo = Self {
@@ -811,6 +973,20 @@ impl<'d> Servo<'d> {
         self.pwm.set_config(&self.cfg);
     }
 
+    /// The pulse width, in microseconds, most recently set by
+    /// [`set_degrees`](Self::set_degrees) or at startup.
+    ///
+    /// Useful for calibrating `min_us`/`max_us` against a servo's mechanical limits.
+    /// While the servo is [relaxed](Self::relax), this is the pulse that
+    /// [`hold`](Self::hold) will resume, not what the pin is outputting.
+    #[must_use]
+    pub const fn current_pulse_us(&self) -> u16 {
+        match self.channel {
+            ServoChannel::A => self.cfg.compare_a,
+            ServoChannel::B => self.cfg.compare_b,
+        }
+    }
+
     fn ensure_enabled(&mut self) {
         if self.state == ServoState::Enabled {
             return;
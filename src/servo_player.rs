@@ -13,6 +13,11 @@
 //!   type showing all methods and associated constants.
 //! - [`combine!`](macro@crate::servo_player::combine) & [`linear`] — Macro and function for creating
 //!   complex motion sequences.
+//! - [`trapezoid`] — Accelerate, cruise at a velocity cap, and decelerate, for moves that are
+//!   gentle on the mechanism.
+//! - [`linear_steps`] — Runtime version of `linear` for sequences whose parameters aren't
+//!   known at compile time.
+//! - [`relax_all`] — Emergency stop: immediately relax every servo player.
 //! - [`Servo`] — Direct servo control without animation support. Use `Servo` for direct,
 //!   immediate control; use `servo_player` when you want motion to continue in the background.
 
@@ -25,7 +30,7 @@
 //! # Controlling Multiple Servos
 //!
 //! Supports up to eight servos, one per [PWM slice](crate#glossary) resource. To calculate which PWM slice a pin uses,
-//! use the formula: `PWM slice = (pin / 2) % 8`. For example, PIN_10 and PIN_11 must both use PWM_SLICE5
+//! use the formula: `PWM slice = (pin / 2) % 8`. For example, `PIN_10` and `PIN_11` must both use `PWM_SLICE5`
 //! ((10 / 2) % 8 = 5, (11 / 2) % 8 = 5). Therefore, either of these these two pins can have a servo, but not both.
 //!
 //!
@@ -137,16 +142,41 @@
 //! }
 //! ```
 
+#[cfg(not(feature = "host"))]
 use crate::servo::Servo;
+#[cfg(not(feature = "host"))]
+use crate::{Error, Result};
+#[cfg(not(feature = "host"))]
 use core::borrow::Borrow;
+#[cfg(not(feature = "host"))]
+use core::cell::RefCell;
+#[cfg(not(feature = "host"))]
+use core::future::poll_fn;
+#[cfg(not(feature = "host"))]
+use core::task::Poll;
+#[cfg(not(feature = "host"))]
+use defmt::warn;
+#[cfg(not(feature = "host"))]
 use embassy_futures::select::{Either, select};
+#[cfg(not(feature = "host"))]
+use embassy_sync::blocking_mutex::Mutex;
+#[cfg(not(feature = "host"))]
 use embassy_sync::blocking_mutex::raw::CriticalSectionRawMutex;
+#[cfg(not(feature = "host"))]
 use embassy_sync::signal::Signal;
-use embassy_time::{Duration, Timer};
+#[cfg(not(feature = "host"))]
+use embassy_sync::waitqueue::MultiWakerRegistration;
+use embassy_time::Duration;
+#[cfg(not(feature = "host"))]
+use embassy_time::{Instant, Timer};
+#[cfg(not(feature = "host"))]
 use heapless::Vec;
+#[cfg(not(feature = "host"))]
+use portable_atomic::{AtomicBool, Ordering};
 
 #[doc(inline)]
 pub use crate::combine;
+#[cfg(not(feature = "host"))]
 /// Re-exported [`servo!`](macro@crate::servo) macro from the [`servo`](mod@crate::servo)
 /// module for convenience.
 ///
@@ -159,24 +189,79 @@ pub use paste;
 // Submodules
 // ============================================================================
 
+#[cfg(not(feature = "host"))]
 pub mod servo_player_generated;
 
+// ============================================================================
+// Emergency stop
+// ============================================================================
+
+#[cfg(not(feature = "host"))]
+/// Maximum number of servo players that [`relax_all`] can reach: one per PWM channel, 16
+/// on the RP2040 and 24 on the RP2350.
+pub const MAX_SERVO_PLAYERS: usize = if cfg!(feature = "pico2") { 24 } else { 16 };
+
+#[cfg(not(feature = "host"))]
+type EmergencyStopSignal = Signal<CriticalSectionRawMutex, ()>;
+
+#[cfg(not(feature = "host"))]
+// Every running servo player's emergency-stop signal, registered when its task starts.
+static EMERGENCY_STOP_SIGNALS: Mutex<
+    CriticalSectionRawMutex,
+    RefCell<Vec<&'static EmergencyStopSignal, MAX_SERVO_PLAYERS>>,
+> = Mutex::new(RefCell::new(Vec::new()));
+
+#[cfg(not(feature = "host"))]
+/// Immediately relax every running servo player, cutting all servo drive.
+///
+/// Each player handles this like its own
+/// [`emergency_stop`](servo_player_generated::ServoPlayerGenerated::emergency_stop):
+/// any animation stops at once and pending commands are dropped. Later commands move the
+/// servos again.
+///
+/// See the [servo_player module documentation](mod@crate::servo_player) for usage of the
+/// players themselves.
+pub fn relax_all() {
+    EMERGENCY_STOP_SIGNALS.lock(|emergency_stop_signals| {
+        for emergency_stop_signal in emergency_stop_signals.borrow().iter() {
+            emergency_stop_signal.signal(());
+        }
+    });
+}
+
+#[cfg(not(feature = "host"))]
+fn register_emergency_stop(emergency_stop_signal: &'static EmergencyStopSignal) -> Result<()> {
+    EMERGENCY_STOP_SIGNALS.lock(|emergency_stop_signals| {
+        emergency_stop_signals
+            .borrow_mut()
+            .push(emergency_stop_signal)
+            .map_err(|_| Error::TooManyServoPlayers)
+    })
+}
+
+#[cfg(not(feature = "host"))]
 /// Commands sent to the servo player device.
 enum PlayerCommand<const MAX_STEPS: usize> {
     Set {
         degrees: u16,
     },
+    MoveBy {
+        degrees: u16,
+        deadline: Instant,
+    },
     Animate {
         steps: Vec<(u16, Duration), MAX_STEPS>,
         mode: AtEnd,
+        play_id: Option<u32>,
     },
     Hold,
     Relax,
+    Shutdown,
 }
 
 /// Animation end behavior.
 ///
-/// See the [servo_player module documentation](mod@crate::servo_player) for usage.
+/// See the [`servo_player` module documentation](mod@crate::servo_player) for usage.
 #[derive(Clone, Copy, Debug, defmt::Format)]
 pub enum AtEnd {
     /// Repeat the animation sequence indefinitely.
@@ -192,7 +277,7 @@ pub enum AtEnd {
 /// Returns a fixed-size array with `N` steps interpolating linearly from `start_degrees` to
 /// `end_degrees` over `total_duration`. Can be used in const contexts.
 ///
-/// See the [servo_player module documentation](mod@crate::servo_player) for usage.
+/// See the [`servo_player` module documentation](mod@crate::servo_player) for usage.
 ///
 /// # Parameters
 ///
@@ -200,7 +285,17 @@ pub enum AtEnd {
 /// - `start_degrees` — Starting angle in degrees
 /// - `end_degrees` — Ending angle in degrees
 /// - `total_duration` — Total time for the entire sequence
+///
+/// # Panics
+///
+/// Panics if `N` is 0.
 #[must_use]
+#[expect(
+    clippy::cast_possible_truncation,
+    clippy::cast_possible_wrap,
+    clippy::cast_sign_loss,
+    reason = "step counts and angles fit in i32, and each angle lies between two u16 angles"
+)]
 pub const fn linear<const N: usize>(
     start_degrees: u16,
     end_degrees: u16,
@@ -226,6 +321,160 @@ pub const fn linear<const N: usize>(
     result
 }
 
+/// Build a const trapezoidal-velocity sequence of animation steps as an array.
+///
+/// The servo accelerates at `accel_degrees_per_sec2` up to `max_degrees_per_sec`, cruises,
+/// then decelerates to stop at `end_degrees`. Moves too short to reach the velocity cap
+/// become a triangle profile: accelerate, then immediately decelerate. The total duration
+/// follows from the profile, and the `N` steps sample it at equal time intervals.
+///
+/// See the [`servo_player` module documentation](mod@crate::servo_player) for usage.
+///
+/// # Parameters
+///
+/// - `N` — Number of steps in the sequence (const generic parameter)
+/// - `start_degrees` — Starting angle in degrees
+/// - `end_degrees` — Ending angle in degrees
+/// - `max_degrees_per_sec` — Velocity cap in degrees per second
+/// - `accel_degrees_per_sec2` — Acceleration and deceleration in degrees per second squared
+///
+/// # Panics
+///
+/// Panics if `N` is 0, if either rate is 0, if `start_degrees` equals `end_degrees`, or
+/// if the move is so short that it lasts fewer microseconds than `N`, which would leave
+/// steps with no duration.
+#[must_use]
+pub const fn trapezoid<const N: usize>(
+    start_degrees: u16,
+    end_degrees: u16,
+    max_degrees_per_sec: u32,
+    accel_degrees_per_sec2: u32,
+) -> [(u16, Duration); N] {
+    // Positions are scaled by MICROS_PER_SEC² so that times stay in whole microseconds.
+    const MICROS_PER_SEC: u128 = 1_000_000;
+    const SCALE: u128 = MICROS_PER_SEC * MICROS_PER_SEC;
+    assert!(N > 0, "at least one step required");
+    assert!(
+        max_degrees_per_sec > 0,
+        "max_degrees_per_sec must be positive"
+    );
+    assert!(
+        accel_degrees_per_sec2 > 0,
+        "accel_degrees_per_sec2 must be positive"
+    );
+    assert!(
+        start_degrees != end_degrees,
+        "trapezoid requires distinct start and end angles"
+    );
+
+    let distance = start_degrees.abs_diff(end_degrees) as u128;
+    let max_velocity = max_degrees_per_sec as u128;
+    let accel = accel_degrees_per_sec2 as u128;
+    let distance_scaled = distance * SCALE;
+
+    // Time to reach cruise velocity, or to the midpoint for a triangle profile.
+    let accel_micros = if max_velocity.pow(2) < distance * accel {
+        max_velocity * MICROS_PER_SEC / accel
+    } else {
+        (distance_scaled / accel).isqrt()
+    };
+    let accel_distance_scaled = accel * accel_micros * accel_micros / 2;
+    let peak_velocity_scaled = accel * accel_micros;
+    let cruise_micros =
+        match (distance_scaled - 2 * accel_distance_scaled).checked_div(peak_velocity_scaled) {
+            Some(cruise_micros) => cruise_micros,
+            None => 0,
+        };
+    let total_micros = 2 * accel_micros + cruise_micros;
+    assert!(
+        total_micros >= N as u128,
+        "move is too short for N steps; use fewer steps"
+    );
+
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "move durations fit in u64 microseconds"
+    )]
+    let step_duration = Duration::from_micros((total_micros / N as u128) as u64);
+    let denom = if N == 1 { 1 } else { (N - 1) as u128 };
+
+    let mut result = [(0u16, Duration::from_micros(0)); N];
+    let mut step_index = 0;
+    while step_index < N {
+        let micros = total_micros * step_index as u128 / denom;
+        let position_scaled = if micros < accel_micros {
+            accel * micros * micros / 2
+        } else if micros < accel_micros + cruise_micros {
+            accel_distance_scaled + peak_velocity_scaled * (micros - accel_micros)
+        } else {
+            let remaining_micros = total_micros - micros;
+            distance_scaled - accel * remaining_micros * remaining_micros / 2
+        };
+        #[expect(
+            clippy::cast_possible_truncation,
+            reason = "position never exceeds the u16 move distance"
+        )]
+        let offset = ((position_scaled + SCALE / 2) / SCALE) as u16;
+        let degrees = if end_degrees >= start_degrees {
+            start_degrees + offset
+        } else {
+            start_degrees - offset
+        };
+        result[step_index] = (degrees, step_duration);
+        step_index += 1;
+    }
+    result
+}
+
+/// Build a linear sequence of animation steps at runtime.
+///
+/// Like [`linear`], but `step_count` is a runtime value, so the sequence can come from
+/// configuration or user input. Returns an iterator instead of an array; chain several
+/// with [`Iterator::chain`] and pass the result straight to `animate` or `play`. Nothing
+/// is allocated.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # #![no_std]
+/// # #![no_main]
+/// # use core::iter;
+/// # use embassy_time::Duration;
+/// # use device_envoy::servo_player::linear_steps;
+/// # use panic_probe as _;
+/// # fn example(sweep_degrees: u16, step_count: usize) {
+/// // Sweep out, pause, and sweep back, with the angle and step count chosen at runtime.
+/// let steps = linear_steps(0, sweep_degrees, Duration::from_secs(1), step_count)
+///     .chain(iter::once((sweep_degrees, Duration::from_millis(500))))
+///     .chain(linear_steps(sweep_degrees, 0, Duration::from_secs(1), step_count));
+/// # let _ = steps;
+/// # }
+/// ```
+///
+/// # Panics
+///
+/// Panics if `step_count` is 0.
+pub fn linear_steps(
+    start_degrees: u16,
+    end_degrees: u16,
+    total_duration: Duration,
+    step_count: usize,
+) -> impl Iterator<Item = (u16, Duration)> + Clone {
+    assert!(step_count > 0, "at least one step required");
+    let step_count_u64 = u64::try_from(step_count).expect("step count fits in u64");
+    let step_duration = Duration::from_micros(total_duration.as_micros() / step_count_u64);
+    let delta = i64::from(end_degrees) - i64::from(start_degrees);
+    let denom = i64::try_from(step_count.max(2) - 1).expect("step count fits in i64");
+    (0..step_count).map(move |step_index| {
+        let step_index = i64::try_from(step_index).expect("step index fits in i64");
+        let degrees = i64::from(start_degrees) + delta * step_index / denom;
+        (
+            u16::try_from(degrees).expect("degrees lie between start and end"),
+            step_duration,
+        )
+    })
+}
+
 /// Combine two animation step arrays into one larger array.
 ///
 /// For combining more than two arrays, use the `combine!` macro.
@@ -264,9 +513,54 @@ pub const fn combine<const N1: usize, const N2: usize, const OUT_N: usize>(
     result
 }
 
+/// Total number of steps in `parts`, for sizing the array built by [`combine_all`].
+#[must_use]
+#[doc(hidden)]
+pub const fn combined_len(parts: &[&[(u16, Duration)]]) -> usize {
+    let mut total = 0;
+    let mut part_index = 0;
+    while part_index < parts.len() {
+        total += parts[part_index].len();
+        part_index += 1;
+    }
+    total
+}
+
+/// Concatenate `parts` into one array of `OUT_N` steps in a single pass.
+///
+/// Used by the `combine!` macro, which sizes `OUT_N` with [`combined_len`].
+#[must_use]
+#[doc(hidden)]
+pub const fn combine_all<const OUT_N: usize>(
+    parts: &[&[(u16, Duration)]],
+) -> [(u16, Duration); OUT_N] {
+    assert!(
+        OUT_N == combined_len(parts),
+        "OUT_N must equal the total length of parts"
+    );
+
+    let mut result = [(0u16, Duration::from_micros(0)); OUT_N];
+    let mut out_index = 0;
+    let mut part_index = 0;
+    while part_index < parts.len() {
+        let part = parts[part_index];
+        let mut i = 0;
+        while i < part.len() {
+            result[out_index] = part[i];
+            out_index += 1;
+            i += 1;
+        }
+        part_index += 1;
+    }
+    result
+}
+
 /// Combine multiple animation step arrays into one larger array.
 ///
-/// This macro allows combining any number of const arrays with a clean syntax.
+/// This macro allows combining up to 16 const arrays with a clean syntax. The arrays
+/// are concatenated in one flat pass, so long choreographies do not build deeply
+/// nested const expressions. To combine more than 16, split the sequence: combine
+/// the parts into a few intermediate consts, then combine those.
 ///
 /// See the [servo_player module documentation](mod@crate::servo_player) for usage.
 #[doc(hidden)]
@@ -275,46 +569,187 @@ macro_rules! combine {
     () => {
         []
     };
-    ($single:expr) => {
+    ($single:expr $(,)?) => {
         $single
     };
-    ($first:expr, $second:expr) => {{
-        const FIRST: &[(u16, ::embassy_time::Duration)] = &$first;
-        const SECOND: &[(u16, ::embassy_time::Duration)] = &$second;
-        $crate::servo_player::combine::<{FIRST.len()}, {SECOND.len()}, {FIRST.len() + SECOND.len()}>($first, $second)
-    }};
-    ($first:expr, $($rest:expr),+ $(,)?) => {{
-        const FIRST: &[(u16, ::embassy_time::Duration)] = &$first;
-        const REST: &[(u16, ::embassy_time::Duration)] = &$crate::combine!($($rest),+);
-        $crate::servo_player::combine::<{FIRST.len()}, {REST.len()}, {FIRST.len() + REST.len()}>($first, $crate::combine!($($rest),+))
+    (
+        $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr, $a6:expr, $a7:expr, $a8:expr,
+        $a9:expr, $a10:expr, $a11:expr, $a12:expr, $a13:expr, $a14:expr, $a15:expr,
+        $a16:expr, $a17:expr $(, $rest:expr)* $(,)?
+    ) => {
+        compile_error!(
+            "combine! supports at most 16 arrays; split the sequence into smaller combine! calls stored in consts, then combine those"
+        )
+    };
+    ($($part:expr),+ $(,)?) => {{
+        const PARTS: &[&[(u16, ::embassy_time::Duration)]] = &[$(&$part),+];
+        $crate::servo_player::combine_all::<{ $crate::servo_player::combined_len(PARTS) }>(PARTS)
     }};
 }
 
+#[cfg(not(feature = "host"))]
+// Tasks that can wait on `play` at once before a new one wakes them all to re-register.
+const MAX_PLAY_WAITERS: usize = 4;
+
+#[cfg(not(feature = "host"))]
+/// Bookkeeping for [`ServoPlayer::play`] futures.
+///
+/// Play ids count up from 1 in the order their commands are signaled. Because every
+/// command supersedes all earlier ones, a single "finished through" id covers every
+/// play that has completed a pass, been interrupted, or been replaced before the device
+/// picked it up.
+struct PlayProgress {
+    issued_through: u32,
+    finished_through: u32,
+    waiters: MultiWakerRegistration<MAX_PLAY_WAITERS>,
+}
+
+#[cfg(not(feature = "host"))]
+impl PlayProgress {
+    const fn new() -> Self {
+        Self {
+            issued_through: 0,
+            finished_through: 0,
+            waiters: MultiWakerRegistration::new(),
+        }
+    }
+
+    fn finish_through(&mut self, play_id: u32) {
+        if play_id > self.finished_through {
+            self.finished_through = play_id;
+            self.waiters.wake();
+        }
+    }
+}
+
+#[cfg(not(feature = "host"))]
 // Public so macro-generated types can reference it; hidden from docs.
 #[doc(hidden)]
 /// Static resources for [`ServoPlayer`].
 pub struct ServoPlayerStatic<const MAX_STEPS: usize> {
     command: Signal<CriticalSectionRawMutex, PlayerCommand<MAX_STEPS>>,
+    emergency_stop: EmergencyStopSignal,
+    play_progress: Mutex<CriticalSectionRawMutex, RefCell<PlayProgress>>,
+    shut_down: AtomicBool,
 }
 
+#[cfg(not(feature = "host"))]
 impl<const MAX_STEPS: usize> ServoPlayerStatic<MAX_STEPS> {
     /// Create static resources for the servo player device.
     #[must_use]
     pub const fn new_static() -> Self {
         Self {
             command: Signal::new(),
+            emergency_stop: Signal::new(),
+            play_progress: Mutex::new(RefCell::new(PlayProgress::new())),
+            shut_down: AtomicBool::new(false),
         }
     }
 
+    // A new command supersedes every earlier play, whether it is running or still pending.
+    // Commands sent after shutdown are dropped.
     fn signal(&self, command: PlayerCommand<MAX_STEPS>) {
-        self.command.signal(command);
+        self.play_progress.lock(|play_progress| {
+            let mut play_progress = play_progress.borrow_mut();
+            let issued_through = play_progress.issued_through;
+            play_progress.finish_through(issued_through);
+            if !self.shut_down.load(Ordering::Relaxed) {
+                self.command.signal(command);
+            }
+        });
     }
 
+    // Like `signal`, but issues the animation a fresh play id and returns it. After
+    // shutdown the id is finished at once, so `play` returns instead of waiting.
+    fn signal_play(&self, steps: Vec<(u16, Duration), MAX_STEPS>, mode: AtEnd) -> u32 {
+        self.play_progress.lock(|play_progress| {
+            let mut play_progress = play_progress.borrow_mut();
+            let issued_through = play_progress.issued_through;
+            play_progress.finish_through(issued_through);
+            let play_id = issued_through.wrapping_add(1);
+            play_progress.issued_through = play_id;
+            if self.shut_down.load(Ordering::Relaxed) {
+                play_progress.finish_through(play_id);
+            } else {
+                self.command.signal(PlayerCommand::Animate {
+                    steps,
+                    mode,
+                    play_id: Some(play_id),
+                });
+            }
+            play_id
+        })
+    }
+
+    // The flag is set before signaling, so no later command can replace the shutdown.
+    fn shutdown(&self) {
+        self.play_progress.lock(|play_progress| {
+            let mut play_progress = play_progress.borrow_mut();
+            let issued_through = play_progress.issued_through;
+            play_progress.finish_through(issued_through);
+            self.shut_down.store(true, Ordering::Relaxed);
+            self.command.signal(PlayerCommand::Shutdown);
+        });
+    }
+
+    #[doc(hidden)] // Required pub for macro expansion in downstream crates
+    /// Make this player reachable from [`relax_all`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::TooManyServoPlayers`] if [`MAX_SERVO_PLAYERS`] are already
+    /// registered.
+    pub fn register_emergency_stop(&'static self) -> Result<()> {
+        register_emergency_stop(&self.emergency_stop)
+    }
+
+    // An emergency stop takes priority over, and discards, any pending command. A
+    // discarded `play` still counts as finished, so its caller does not wait forever.
     async fn wait(&self) -> PlayerCommand<MAX_STEPS> {
-        self.command.wait().await
+        let command = match select(self.emergency_stop.wait(), self.command.wait()).await {
+            Either::First(()) => {
+                self.play_progress.lock(|play_progress| {
+                    let mut play_progress = play_progress.borrow_mut();
+                    let issued_through = play_progress.issued_through;
+                    play_progress.finish_through(issued_through);
+                    self.command.reset();
+                });
+                PlayerCommand::Relax
+            }
+            Either::Second(command) => command,
+        };
+        if self.shut_down.load(Ordering::Relaxed) {
+            PlayerCommand::Shutdown
+        } else {
+            command
+        }
+    }
+
+    fn signal_play_finished(&self, play_id: Option<u32>) {
+        if let Some(play_id) = play_id {
+            self.play_progress.lock(|play_progress| {
+                play_progress.borrow_mut().finish_through(play_id);
+            });
+        }
+    }
+
+    async fn wait_play_finished(&self, play_id: u32) {
+        poll_fn(|context| {
+            self.play_progress.lock(|play_progress| {
+                let mut play_progress = play_progress.borrow_mut();
+                if play_progress.finished_through >= play_id {
+                    Poll::Ready(())
+                } else {
+                    play_progress.waiters.register(context.waker());
+                    Poll::Pending
+                }
+            })
+        })
+        .await;
     }
 }
 
+#[cfg(not(feature = "host"))]
 // Public so macro-generated types can deref to it; hidden from docs.
 #[doc(hidden)]
 /// Internal deref target for generated servo player types.
@@ -323,8 +758,12 @@ impl<const MAX_STEPS: usize> ServoPlayerStatic<MAX_STEPS> {
 /// See [`servo_player!`] macro documentation for usage.
 pub struct ServoPlayer<const MAX_STEPS: usize> {
     servo_player_static: &'static ServoPlayerStatic<MAX_STEPS>,
+    max_degrees: u16,
+    ease_start_steps: usize,
+    ease_end_steps: usize,
 }
 
+#[cfg(not(feature = "host"))]
 impl<const MAX_STEPS: usize> ServoPlayer<MAX_STEPS> {
     /// Create static resources for a servo player.
     #[must_use]
@@ -334,14 +773,36 @@ impl<const MAX_STEPS: usize> ServoPlayer<MAX_STEPS> {
 
     /// Create a servo player handle. The device loop must already be running.
     ///
+    /// `max_degrees` must match the servo's configured maximum angle; animation steps
+    /// beyond it are clamped.
+    ///
     /// See the [servo_player module documentation](mod@crate::servo_player) for usage.
     #[must_use]
-    pub const fn new(servo_player_static: &'static ServoPlayerStatic<MAX_STEPS>) -> Self {
+    pub const fn new(
+        servo_player_static: &'static ServoPlayerStatic<MAX_STEPS>,
+        max_degrees: u16,
+    ) -> Self {
         Self {
             servo_player_static,
+            max_degrees,
+            ease_start_steps: 0,
+            ease_end_steps: 0,
         }
     }
 
+    /// Return this handle with automatic easing on every animation.
+    ///
+    /// The first `ease_start_steps` moves of each sequence are reshaped into a half-sine
+    /// ease-in and the last `ease_end_steps` into a half-sine ease-out, as by
+    /// [`ease_steps`]. See the `servo_player!` `ease_start_steps` and `ease_end_steps`
+    /// fields.
+    #[must_use]
+    pub const fn with_easing(mut self, ease_start_steps: usize, ease_end_steps: usize) -> Self {
+        self.ease_start_steps = ease_start_steps;
+        self.ease_end_steps = ease_end_steps;
+        self
+    }
+
     /// Set the target angle. The most recent command always wins.
     ///
     /// See the [servo_player module documentation](mod@crate::servo_player) for
@@ -351,6 +812,48 @@ impl<const MAX_STEPS: usize> ServoPlayer<MAX_STEPS> {
             .signal(PlayerCommand::Set { degrees });
     }
 
+    /// Set the target angle as the fraction `numerator / denominator` of the servo's full
+    /// range, from 0 to `max_degrees`. The most recent command always wins.
+    ///
+    /// This takes raw readings directly: for a potentiometer on a 12-bit ADC, pass the
+    /// reading and 4095; for a normalized joystick axis, pass the value and `u16::MAX`.
+    /// The angle is rounded to the nearest degree.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `denominator` is 0 or `numerator` exceeds `denominator`.
+    pub fn set_fraction(&self, numerator: u16, denominator: u16) {
+        assert!(denominator > 0, "denominator must be positive");
+        assert!(
+            numerator <= denominator,
+            "numerator must not exceed denominator"
+        );
+        self.set_degrees(fraction_to_degrees(
+            numerator,
+            denominator,
+            self.max_degrees,
+        ));
+    }
+
+    /// Move in a straight line from the current angle to `degrees`, arriving exactly at
+    /// `deadline`, then hold there. The most recent command always wins.
+    ///
+    /// Use it to land on externally timed events, such as a music beat or a clock tick,
+    /// without precomputing steps. The angle is updated every 20 ms, once per PWM
+    /// period. If `deadline` has already passed, the servo is set to `degrees` at once.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `degrees` exceeds `max_degrees`.
+    pub fn move_to_by(&self, degrees: u16, deadline: Instant) {
+        assert!(
+            degrees <= self.max_degrees,
+            "degrees must not exceed max_degrees"
+        );
+        self.servo_player_static
+            .signal(PlayerCommand::MoveBy { degrees, deadline });
+    }
+
     /// Hold the servo at its current position.
     ///
     /// See the [servo_player module documentation](mod@crate::servo_player) for
@@ -367,10 +870,30 @@ impl<const MAX_STEPS: usize> ServoPlayer<MAX_STEPS> {
         self.servo_player_static.signal(PlayerCommand::Relax);
     }
 
+    /// Relax the servo and stop the background task.
+    ///
+    /// The task returns, dropping its PWM slice so the hardware is quiesced. The player's
+    /// statics cannot be reclaimed, so the player cannot be created again; later commands
+    /// are ignored, and a later [`play`](Self::play) returns at once.
+    pub fn shutdown(&self) {
+        self.servo_player_static.shutdown();
+    }
+
+    /// Relax the servo immediately, on a path that bypasses ordinary commands.
+    ///
+    /// Unlike [`relax`](Self::relax), an emergency stop cannot be overwritten by a command
+    /// sent right after it: the device loop always handles it first, stops any animation,
+    /// and drops the pending command. Later commands move the servo again. Use
+    /// [`relax_all`] to stop every servo player at once.
+    pub fn emergency_stop(&self) {
+        self.servo_player_static.emergency_stop.signal(());
+    }
+
     /// Animate the servo through a sequence of angles with per-step hold durations.
     ///
     /// Each step is a tuple `(degrees, duration)`. Accepts both owned iterators and
-    /// references to collections.
+    /// references to collections. Steps beyond the servo's `max_degrees` are clamped
+    /// to it, with a warning logged.
     ///
     /// See the [servo_player module documentation](mod@crate::servo_player) for
     /// usage.
@@ -379,24 +902,169 @@ impl<const MAX_STEPS: usize> ServoPlayer<MAX_STEPS> {
         I: IntoIterator,
         I::Item: Borrow<(u16, Duration)>,
     {
-        assert!(MAX_STEPS > 0, "animate disabled: max_steps is 0");
-        let mut sequence: Vec<(u16, Duration), MAX_STEPS> = Vec::new();
-        for step in steps {
-            let step = *step.borrow();
-            assert!(
-                step.1.as_micros() > 0,
-                "animation step duration must be positive"
-            );
-            sequence
-                .push(step)
-                .expect("animate sequence fits within max_steps");
-        }
-        assert!(!sequence.is_empty(), "animate requires at least one step");
-
         self.servo_player_static.signal(PlayerCommand::Animate {
-            steps: sequence,
+            steps: self.collect_steps(steps),
             mode: at_end,
+            play_id: None,
+        });
+    }
+
+    /// Animate the servo through a sequence of steps and wait for it to finish.
+    ///
+    /// Takes the same arguments as [`animate`](Self::animate). The returned future
+    /// resolves once the sequence has run through one full pass, so motions can be
+    /// chained without a manual [`Timer`]. With [`AtEnd::Loop`], the servo keeps looping
+    /// after the future resolves.
+    ///
+    /// If another command (for example, [`set_degrees`](Self::set_degrees) or a later
+    /// `animate`) interrupts the sequence first, the future resolves at that point.
+    ///
+    /// See the [servo_player module documentation](mod@crate::servo_player) for
+    /// usage.
+    pub async fn play<I>(&self, steps: I, at_end: AtEnd)
+    where
+        I: IntoIterator,
+        I::Item: Borrow<(u16, Duration)>,
+    {
+        let play_id = self
+            .servo_player_static
+            .signal_play(self.collect_steps(steps), at_end);
+        self.servo_player_static.wait_play_finished(play_id).await;
+    }
+}
+
+#[cfg(not(feature = "host"))]
+impl<const MAX_STEPS: usize> ServoPlayer<MAX_STEPS> {
+    fn collect_steps<I>(&self, steps: I) -> Vec<(u16, Duration), MAX_STEPS>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<(u16, Duration)>,
+    {
+        let mut sequence = collect_steps(steps, self.max_degrees);
+        ease_steps(&mut sequence, self.ease_start_steps, self.ease_end_steps);
+        sequence
+    }
+}
+
+#[cfg(not(feature = "host"))]
+fn collect_steps<const MAX_STEPS: usize, I>(
+    steps: I,
+    max_degrees: u16,
+) -> Vec<(u16, Duration), MAX_STEPS>
+where
+    I: IntoIterator,
+    I::Item: Borrow<(u16, Duration)>,
+{
+    assert!(MAX_STEPS > 0, "animate disabled: max_steps is 0");
+    let mut sequence: Vec<(u16, Duration), MAX_STEPS> = Vec::new();
+    let mut clamped_count = 0usize;
+    for step in steps {
+        let mut step = *step.borrow();
+        if step.0 > max_degrees {
+            step.0 = max_degrees;
+            clamped_count += 1;
+        }
+        assert!(
+            step.1.as_micros() > 0,
+            "animation step duration must be positive"
+        );
+        sequence
+            .push(step)
+            .expect("animate sequence fits within max_steps");
+    }
+    assert!(!sequence.is_empty(), "animate requires at least one step");
+    if clamped_count > 0 {
+        warn!(
+            "servo_player: clamped {} animation step(s) to max_degrees {}",
+            clamped_count, max_degrees
+        );
+    }
+    sequence
+}
+
+#[cfg(not(feature = "host"))]
+/// Round `numerator / denominator` of `max_degrees` to the nearest degree.
+fn fraction_to_degrees(numerator: u16, denominator: u16, max_degrees: u16) -> u16 {
+    let numerator = u32::from(numerator);
+    let denominator = u32::from(denominator);
+    let degrees = (numerator * u32::from(max_degrees) + denominator / 2) / denominator;
+    u16::try_from(degrees).unwrap_or(max_degrees)
+}
+
+// sin(k × 9°) × 1000 for k = 0..=10, a quarter sine wave in ten segments.
+const QUARTER_SINE_PERMILLE: [i32; 11] = [0, 156, 309, 454, 588, 707, 809, 891, 951, 988, 1000];
+
+/// Integer approximation of `sin(fraction × 90°) × 1000`, for `fraction_permille` in
+/// `0..=1000`, by linear interpolation in [`QUARTER_SINE_PERMILLE`].
+const fn quarter_sine_permille(fraction_permille: i32) -> i32 {
+    let segment = fraction_permille / 100;
+    if segment >= 10 {
+        return 1000;
+    }
+    let within = fraction_permille % 100;
+    #[expect(clippy::cast_sign_loss, reason = "fraction_permille is not negative")]
+    let segment = segment as usize;
+    let low = QUARTER_SINE_PERMILLE[segment];
+    let high = QUARTER_SINE_PERMILLE[segment + 1];
+    low + (high - low) * within / 100
+}
+
+/// Reshape the first `ease_start_steps` and last `ease_end_steps` moves of an animation
+/// into half-sine ramps, keeping every step's duration and the step count.
+///
+/// This is the easing the `servo_player!` `ease_start_steps` and `ease_end_steps` fields
+/// apply to every animation; call it directly to preview the eased steps.
+///
+/// Within each ramp, the waypoints where the motion starts, stops, or turns around are
+/// kept. Only the positions strictly between two such waypoints move, onto a quarter-sine
+/// curve between them: bunched toward the start for an ease-in, toward the end for an
+/// ease-out. A sequence such as `[0, 180, 0]` therefore still reaches 180. Ramps are
+/// clamped so they never overlap.
+///
+/// See the [`servo_player` module documentation](mod@crate::servo_player) for usage.
+pub fn ease_steps(steps: &mut [(u16, Duration)], ease_start_steps: usize, ease_end_steps: usize) {
+    let Some(last_index) = steps.len().checked_sub(1) else {
+        return;
+    };
+    let ease_start_steps = ease_start_steps.min(last_index);
+    let ease_end_steps = ease_end_steps.min(last_index - ease_start_steps);
+
+    ease_ramp(&mut steps[..=ease_start_steps], true);
+    ease_ramp(&mut steps[last_index - ease_end_steps..], false);
+}
+
+/// Split `steps` at every waypoint where the motion changes direction (or stops) and ease
+/// each run between consecutive waypoints. The first and last steps are waypoints.
+fn ease_ramp(steps: &mut [(u16, Duration)], is_ease_in: bool) {
+    let mut from_index = 0;
+    for to_index in 1..steps.len() {
+        let is_waypoint = steps.get(to_index + 1).is_none_or(|next_step| {
+            steps[to_index - 1].0.cmp(&steps[to_index].0) != steps[to_index].0.cmp(&next_step.0)
         });
+        if is_waypoint {
+            ease_run(&mut steps[from_index..=to_index], is_ease_in);
+            from_index = to_index;
+        }
+    }
+}
+
+/// Move the positions strictly between the first and last step of a monotonic run onto a
+/// quarter-sine curve between its endpoint positions.
+fn ease_run(steps: &mut [(u16, Duration)], is_ease_in: bool) {
+    let last_index = steps.len() - 1;
+    let from_degrees = i32::from(steps[0].0);
+    let to_degrees = i32::from(steps[last_index].0);
+    let span = i32::try_from(last_index).expect("ramp length fits in i32");
+    for (offset, step) in steps[1..last_index].iter_mut().enumerate() {
+        let position = i32::try_from(offset + 1).expect("ramp length fits in i32");
+        let fraction_permille = position * 1000 / span;
+        let progress_permille = if is_ease_in {
+            1000 - quarter_sine_permille(1000 - fraction_permille)
+        } else {
+            quarter_sine_permille(fraction_permille)
+        };
+        let degrees = from_degrees + (to_degrees - from_degrees) * progress_permille / 1000;
+        step.0 = u16::try_from(degrees).expect("eased angle lies between two u16 angles");
     }
 }
 
@@ -430,8 +1098,21 @@ impl<const MAX_STEPS: usize> ServoPlayer<MAX_STEPS> {
 /// - `min_us` — Minimum pulse width in microseconds for 0° (default: 500)
 /// - `max_us` — Maximum pulse width in microseconds for max_degrees
 ///   (default: 2500)
-/// - `max_degrees` — Maximum servo angle in degrees (default: 180)
+/// - `max_degrees` — Maximum servo angle in degrees (default: 180). Exposed as the
+///   generated type's `MAX_DEGREES` constant; `animate` and `play` clamp steps beyond it
+///   and log a warning.
+/// - `pwm_freq_hz` — Control signal frequency in Hz (default: 50). Only raise it for digital
+///   servos rated for faster frames; see [PWM Frequency](crate::servo::Servo#pwm-frequency).
 /// - `max_steps` — Maximum number of animation steps (default: 16)
+/// - `ease_start_steps` — Number of leading moves of every animation to reshape into a
+///   half-sine ease-in (default: 0)
+/// - `ease_end_steps` — Number of trailing moves of every animation to reshape into a
+///   half-sine ease-out (default: 0)
+///
+/// Easing shrinks the first and last moves by moving in-between positions toward the
+/// waypoints where motion starts, stops, or turns around; see [`ease_steps`]. It keeps
+/// every waypoint and every step's duration and adds no steps, so `max_steps` need not
+/// grow and animations take as long as before.
 ///
 /// `max_steps = 0` disables animation and allocates no step storage; `set_degrees()`,
 /// `hold()`, and `relax()` are still supported.
@@ -442,6 +1123,7 @@ impl<const MAX_STEPS: usize> ServoPlayer<MAX_STEPS> {
 macro_rules! servo_player {
     ($($tt:tt)*) => { $crate::__servo_player_impl! { $($tt)* } };
 }
+#[cfg(not(feature = "host"))]
 #[doc(inline)]
 pub use servo_player;
 
@@ -465,7 +1147,10 @@ macro_rules! __servo_player_impl {
             min_us: $crate::servo::SERVO_MIN_US_DEFAULT,
             max_us: $crate::servo::SERVO_MAX_US_DEFAULT,
             max_degrees: $crate::servo::Servo::DEFAULT_MAX_DEGREES,
+            pwm_freq_hz: $crate::servo::SERVO_PWM_FREQ_HZ_DEFAULT,
             max_steps: 16,
+            ease_start_steps: 0,
+            ease_end_steps: 0,
             fields: [ $($fields)* ]
         }
     };
@@ -486,7 +1171,10 @@ macro_rules! __servo_player_impl {
             min_us: $crate::servo::SERVO_MIN_US_DEFAULT,
             max_us: $crate::servo::SERVO_MAX_US_DEFAULT,
             max_degrees: $crate::servo::Servo::DEFAULT_MAX_DEGREES,
+            pwm_freq_hz: $crate::servo::SERVO_PWM_FREQ_HZ_DEFAULT,
             max_steps: 16,
+            ease_start_steps: 0,
+            ease_end_steps: 0,
             fields: [ $($fields)* ]
         }
     };
@@ -501,7 +1189,10 @@ macro_rules! __servo_player_impl {
         min_us: $min_us:expr,
         max_us: $max_us:expr,
         max_degrees: $max_degrees:expr,
+        pwm_freq_hz: $pwm_freq_hz:expr,
         max_steps: $max_steps:expr,
+        ease_start_steps: $ease_start_steps:expr,
+        ease_end_steps: $ease_end_steps:expr,
         fields: [ pin: $pin_value:ident $(, $($rest:tt)* )? ]
     ) => {
         $crate::__servo_player_impl! {
@@ -514,7 +1205,10 @@ macro_rules! __servo_player_impl {
             min_us: $min_us,
             max_us: $max_us,
             max_degrees: $max_degrees,
+            pwm_freq_hz: $pwm_freq_hz,
             max_steps: $max_steps,
+            ease_start_steps: $ease_start_steps,
+            ease_end_steps: $ease_end_steps,
             fields: [ $($($rest)*)? ]
         }
     };
@@ -528,7 +1222,10 @@ macro_rules! __servo_player_impl {
         min_us: $min_us:expr,
         max_us: $max_us:expr,
         max_degrees: $max_degrees:expr,
+        pwm_freq_hz: $pwm_freq_hz:expr,
         max_steps: $max_steps:expr,
+        ease_start_steps: $ease_start_steps:expr,
+        ease_end_steps: $ease_end_steps:expr,
         fields: [ pin: $pin_value:ident ]
     ) => {
         $crate::__servo_player_impl! {
@@ -541,7 +1238,10 @@ macro_rules! __servo_player_impl {
             min_us: $min_us,
             max_us: $max_us,
             max_degrees: $max_degrees,
+            pwm_freq_hz: $pwm_freq_hz,
             max_steps: $max_steps,
+            ease_start_steps: $ease_start_steps,
+            ease_end_steps: $ease_end_steps,
             fields: [ ]
         }
     };
@@ -556,7 +1256,10 @@ macro_rules! __servo_player_impl {
         min_us: $min_us:expr,
         max_us: $max_us:expr,
         max_degrees: $max_degrees:expr,
+        pwm_freq_hz: $pwm_freq_hz:expr,
         max_steps: $max_steps:expr,
+        ease_start_steps: $ease_start_steps:expr,
+        ease_end_steps: $ease_end_steps:expr,
         fields: [ slice: $slice_value:ident $(, $($rest:tt)* )? ]
     ) => {
         $crate::__servo_player_impl! {
@@ -569,7 +1272,10 @@ macro_rules! __servo_player_impl {
             min_us: $min_us,
             max_us: $max_us,
             max_degrees: $max_degrees,
+            pwm_freq_hz: $pwm_freq_hz,
             max_steps: $max_steps,
+            ease_start_steps: $ease_start_steps,
+            ease_end_steps: $ease_end_steps,
             fields: [ $($($rest)*)? ]
         }
     };
@@ -583,7 +1289,10 @@ macro_rules! __servo_player_impl {
         min_us: $min_us:expr,
         max_us: $max_us:expr,
         max_degrees: $max_degrees:expr,
+        pwm_freq_hz: $pwm_freq_hz:expr,
         max_steps: $max_steps:expr,
+        ease_start_steps: $ease_start_steps:expr,
+        ease_end_steps: $ease_end_steps:expr,
         fields: [ slice: $slice_value:ident ]
     ) => {
         $crate::__servo_player_impl! {
@@ -596,7 +1305,10 @@ macro_rules! __servo_player_impl {
             min_us: $min_us,
             max_us: $max_us,
             max_degrees: $max_degrees,
+            pwm_freq_hz: $pwm_freq_hz,
             max_steps: $max_steps,
+            ease_start_steps: $ease_start_steps,
+            ease_end_steps: $ease_end_steps,
             fields: [ ]
         }
     };
@@ -611,7 +1323,10 @@ macro_rules! __servo_player_impl {
         min_us: $min_us:expr,
         max_us: $max_us:expr,
         max_degrees: $max_degrees:expr,
+        pwm_freq_hz: $pwm_freq_hz:expr,
         max_steps: $max_steps:expr,
+        ease_start_steps: $ease_start_steps:expr,
+        ease_end_steps: $ease_end_steps:expr,
         fields: [ min_us: $min_us_value:expr $(, $($rest:tt)* )? ]
     ) => {
         $crate::__servo_player_impl! {
@@ -624,7 +1339,10 @@ macro_rules! __servo_player_impl {
             min_us: $min_us_value,
             max_us: $max_us,
             max_degrees: $max_degrees,
+            pwm_freq_hz: $pwm_freq_hz,
             max_steps: $max_steps,
+            ease_start_steps: $ease_start_steps,
+            ease_end_steps: $ease_end_steps,
             fields: [ $($($rest)*)? ]
         }
     };
@@ -638,7 +1356,10 @@ macro_rules! __servo_player_impl {
         min_us: $min_us:expr,
         max_us: $max_us:expr,
         max_degrees: $max_degrees:expr,
+        pwm_freq_hz: $pwm_freq_hz:expr,
         max_steps: $max_steps:expr,
+        ease_start_steps: $ease_start_steps:expr,
+        ease_end_steps: $ease_end_steps:expr,
         fields: [ min_us: $min_us_value:expr ]
     ) => {
         $crate::__servo_player_impl! {
@@ -651,7 +1372,10 @@ macro_rules! __servo_player_impl {
             min_us: $min_us_value,
             max_us: $max_us,
             max_degrees: $max_degrees,
+            pwm_freq_hz: $pwm_freq_hz,
             max_steps: $max_steps,
+            ease_start_steps: $ease_start_steps,
+            ease_end_steps: $ease_end_steps,
             fields: [ ]
         }
     };
@@ -666,7 +1390,10 @@ macro_rules! __servo_player_impl {
         min_us: $min_us:expr,
         max_us: $max_us:expr,
         max_degrees: $max_degrees:expr,
+        pwm_freq_hz: $pwm_freq_hz:expr,
         max_steps: $max_steps:expr,
+        ease_start_steps: $ease_start_steps:expr,
+        ease_end_steps: $ease_end_steps:expr,
         fields: [ max_us: $max_us_value:expr $(, $($rest:tt)* )? ]
     ) => {
         $crate::__servo_player_impl! {
@@ -679,7 +1406,10 @@ macro_rules! __servo_player_impl {
             min_us: $min_us,
             max_us: $max_us_value,
             max_degrees: $max_degrees,
+            pwm_freq_hz: $pwm_freq_hz,
             max_steps: $max_steps,
+            ease_start_steps: $ease_start_steps,
+            ease_end_steps: $ease_end_steps,
             fields: [ $($($rest)*)? ]
         }
     };
@@ -693,7 +1423,10 @@ macro_rules! __servo_player_impl {
         min_us: $min_us:expr,
         max_us: $max_us:expr,
         max_degrees: $max_degrees:expr,
+        pwm_freq_hz: $pwm_freq_hz:expr,
         max_steps: $max_steps:expr,
+        ease_start_steps: $ease_start_steps:expr,
+        ease_end_steps: $ease_end_steps:expr,
         fields: [ max_us: $max_us_value:expr ]
     ) => {
         $crate::__servo_player_impl! {
@@ -706,7 +1439,10 @@ macro_rules! __servo_player_impl {
             min_us: $min_us,
             max_us: $max_us_value,
             max_degrees: $max_degrees,
+            pwm_freq_hz: $pwm_freq_hz,
             max_steps: $max_steps,
+            ease_start_steps: $ease_start_steps,
+            ease_end_steps: $ease_end_steps,
             fields: [ ]
         }
     };
@@ -721,7 +1457,10 @@ macro_rules! __servo_player_impl {
         min_us: $min_us:expr,
         max_us: $max_us:expr,
         max_degrees: $max_degrees:expr,
+        pwm_freq_hz: $pwm_freq_hz:expr,
         max_steps: $max_steps:expr,
+        ease_start_steps: $ease_start_steps:expr,
+        ease_end_steps: $ease_end_steps:expr,
         fields: [ max_degrees: $max_degrees_value:expr $(, $($rest:tt)* )? ]
     ) => {
         $crate::__servo_player_impl! {
@@ -734,7 +1473,10 @@ macro_rules! __servo_player_impl {
             min_us: $min_us,
             max_us: $max_us,
             max_degrees: $max_degrees_value,
+            pwm_freq_hz: $pwm_freq_hz,
             max_steps: $max_steps,
+            ease_start_steps: $ease_start_steps,
+            ease_end_steps: $ease_end_steps,
             fields: [ $($($rest)*)? ]
         }
     };
@@ -748,7 +1490,10 @@ macro_rules! __servo_player_impl {
         min_us: $min_us:expr,
         max_us: $max_us:expr,
         max_degrees: $max_degrees:expr,
+        pwm_freq_hz: $pwm_freq_hz:expr,
         max_steps: $max_steps:expr,
+        ease_start_steps: $ease_start_steps:expr,
+        ease_end_steps: $ease_end_steps:expr,
         fields: [ max_degrees: $max_degrees_value:expr ]
     ) => {
         $crate::__servo_player_impl! {
@@ -761,7 +1506,77 @@ macro_rules! __servo_player_impl {
             min_us: $min_us,
             max_us: $max_us,
             max_degrees: $max_degrees_value,
+            pwm_freq_hz: $pwm_freq_hz,
+            max_steps: $max_steps,
+            ease_start_steps: $ease_start_steps,
+            ease_end_steps: $ease_end_steps,
+            fields: [ ]
+        }
+    };
+
+    // Fill defaults: pwm_freq_hz
+    (@__fill_defaults
+        vis: $vis:vis,
+        name: $name:ident,
+        pin: $pin:tt,
+        slice: $slice:tt,
+        channel: $channel:tt,
+        min_us: $min_us:expr,
+        max_us: $max_us:expr,
+        max_degrees: $max_degrees:expr,
+        pwm_freq_hz: $pwm_freq_hz:expr,
+        max_steps: $max_steps:expr,
+        ease_start_steps: $ease_start_steps:expr,
+        ease_end_steps: $ease_end_steps:expr,
+        fields: [ pwm_freq_hz: $pwm_freq_hz_value:expr $(, $($rest:tt)* )? ]
+    ) => {
+        $crate::__servo_player_impl! {
+            @__fill_defaults
+            vis: $vis,
+            name: $name,
+            pin: $pin,
+            slice: $slice,
+            channel: $channel,
+            min_us: $min_us,
+            max_us: $max_us,
+            max_degrees: $max_degrees,
+            pwm_freq_hz: $pwm_freq_hz_value,
+            max_steps: $max_steps,
+            ease_start_steps: $ease_start_steps,
+            ease_end_steps: $ease_end_steps,
+            fields: [ $($($rest)*)? ]
+        }
+    };
+
+    (@__fill_defaults
+        vis: $vis:vis,
+        name: $name:ident,
+        pin: $pin:tt,
+        slice: $slice:tt,
+        channel: $channel:tt,
+        min_us: $min_us:expr,
+        max_us: $max_us:expr,
+        max_degrees: $max_degrees:expr,
+        pwm_freq_hz: $pwm_freq_hz:expr,
+        max_steps: $max_steps:expr,
+        ease_start_steps: $ease_start_steps:expr,
+        ease_end_steps: $ease_end_steps:expr,
+        fields: [ pwm_freq_hz: $pwm_freq_hz_value:expr ]
+    ) => {
+        $crate::__servo_player_impl! {
+            @__fill_defaults
+            vis: $vis,
+            name: $name,
+            pin: $pin,
+            slice: $slice,
+            channel: $channel,
+            min_us: $min_us,
+            max_us: $max_us,
+            max_degrees: $max_degrees,
+            pwm_freq_hz: $pwm_freq_hz_value,
             max_steps: $max_steps,
+            ease_start_steps: $ease_start_steps,
+            ease_end_steps: $ease_end_steps,
             fields: [ ]
         }
     };
@@ -776,7 +1591,10 @@ macro_rules! __servo_player_impl {
         min_us: $min_us:expr,
         max_us: $max_us:expr,
         max_degrees: $max_degrees:expr,
+        pwm_freq_hz: $pwm_freq_hz:expr,
         max_steps: $max_steps:expr,
+        ease_start_steps: $ease_start_steps:expr,
+        ease_end_steps: $ease_end_steps:expr,
         fields: [ max_steps: $max_steps_value:expr $(, $($rest:tt)* )? ]
     ) => {
         $crate::__servo_player_impl! {
@@ -789,7 +1607,10 @@ macro_rules! __servo_player_impl {
             min_us: $min_us,
             max_us: $max_us,
             max_degrees: $max_degrees,
+            pwm_freq_hz: $pwm_freq_hz,
             max_steps: $max_steps_value,
+            ease_start_steps: $ease_start_steps,
+            ease_end_steps: $ease_end_steps,
             fields: [ $($($rest)*)? ]
         }
     };
@@ -803,7 +1624,10 @@ macro_rules! __servo_player_impl {
         min_us: $min_us:expr,
         max_us: $max_us:expr,
         max_degrees: $max_degrees:expr,
+        pwm_freq_hz: $pwm_freq_hz:expr,
         max_steps: $max_steps:expr,
+        ease_start_steps: $ease_start_steps:expr,
+        ease_end_steps: $ease_end_steps:expr,
         fields: [ max_steps: $max_steps_value:expr ]
     ) => {
         $crate::__servo_player_impl! {
@@ -816,7 +1640,144 @@ macro_rules! __servo_player_impl {
             min_us: $min_us,
             max_us: $max_us,
             max_degrees: $max_degrees,
+            pwm_freq_hz: $pwm_freq_hz,
             max_steps: $max_steps_value,
+            ease_start_steps: $ease_start_steps,
+            ease_end_steps: $ease_end_steps,
+            fields: [ ]
+        }
+    };
+
+    // Fill defaults: ease_start_steps
+    (@__fill_defaults
+        vis: $vis:vis,
+        name: $name:ident,
+        pin: $pin:tt,
+        slice: $slice:tt,
+        channel: $channel:tt,
+        min_us: $min_us:expr,
+        max_us: $max_us:expr,
+        max_degrees: $max_degrees:expr,
+        pwm_freq_hz: $pwm_freq_hz:expr,
+        max_steps: $max_steps:expr,
+        ease_start_steps: $ease_start_steps:expr,
+        ease_end_steps: $ease_end_steps:expr,
+        fields: [ ease_start_steps: $ease_start_steps_value:expr $(, $($rest:tt)* )? ]
+    ) => {
+        $crate::__servo_player_impl! {
+            @__fill_defaults
+            vis: $vis,
+            name: $name,
+            pin: $pin,
+            slice: $slice,
+            channel: $channel,
+            min_us: $min_us,
+            max_us: $max_us,
+            max_degrees: $max_degrees,
+            pwm_freq_hz: $pwm_freq_hz,
+            max_steps: $max_steps,
+            ease_start_steps: $ease_start_steps_value,
+            ease_end_steps: $ease_end_steps,
+            fields: [ $($($rest)*)? ]
+        }
+    };
+
+    (@__fill_defaults
+        vis: $vis:vis,
+        name: $name:ident,
+        pin: $pin:tt,
+        slice: $slice:tt,
+        channel: $channel:tt,
+        min_us: $min_us:expr,
+        max_us: $max_us:expr,
+        max_degrees: $max_degrees:expr,
+        pwm_freq_hz: $pwm_freq_hz:expr,
+        max_steps: $max_steps:expr,
+        ease_start_steps: $ease_start_steps:expr,
+        ease_end_steps: $ease_end_steps:expr,
+        fields: [ ease_start_steps: $ease_start_steps_value:expr ]
+    ) => {
+        $crate::__servo_player_impl! {
+            @__fill_defaults
+            vis: $vis,
+            name: $name,
+            pin: $pin,
+            slice: $slice,
+            channel: $channel,
+            min_us: $min_us,
+            max_us: $max_us,
+            max_degrees: $max_degrees,
+            pwm_freq_hz: $pwm_freq_hz,
+            max_steps: $max_steps,
+            ease_start_steps: $ease_start_steps_value,
+            ease_end_steps: $ease_end_steps,
+            fields: [ ]
+        }
+    };
+
+    // Fill defaults: ease_end_steps
+    (@__fill_defaults
+        vis: $vis:vis,
+        name: $name:ident,
+        pin: $pin:tt,
+        slice: $slice:tt,
+        channel: $channel:tt,
+        min_us: $min_us:expr,
+        max_us: $max_us:expr,
+        max_degrees: $max_degrees:expr,
+        pwm_freq_hz: $pwm_freq_hz:expr,
+        max_steps: $max_steps:expr,
+        ease_start_steps: $ease_start_steps:expr,
+        ease_end_steps: $ease_end_steps:expr,
+        fields: [ ease_end_steps: $ease_end_steps_value:expr $(, $($rest:tt)* )? ]
+    ) => {
+        $crate::__servo_player_impl! {
+            @__fill_defaults
+            vis: $vis,
+            name: $name,
+            pin: $pin,
+            slice: $slice,
+            channel: $channel,
+            min_us: $min_us,
+            max_us: $max_us,
+            max_degrees: $max_degrees,
+            pwm_freq_hz: $pwm_freq_hz,
+            max_steps: $max_steps,
+            ease_start_steps: $ease_start_steps,
+            ease_end_steps: $ease_end_steps_value,
+            fields: [ $($($rest)*)? ]
+        }
+    };
+
+    (@__fill_defaults
+        vis: $vis:vis,
+        name: $name:ident,
+        pin: $pin:tt,
+        slice: $slice:tt,
+        channel: $channel:tt,
+        min_us: $min_us:expr,
+        max_us: $max_us:expr,
+        max_degrees: $max_degrees:expr,
+        pwm_freq_hz: $pwm_freq_hz:expr,
+        max_steps: $max_steps:expr,
+        ease_start_steps: $ease_start_steps:expr,
+        ease_end_steps: $ease_end_steps:expr,
+        fields: [ ease_end_steps: $ease_end_steps_value:expr ]
+    ) => {
+        $crate::__servo_player_impl! {
+            @__fill_defaults
+            vis: $vis,
+            name: $name,
+            pin: $pin,
+            slice: $slice,
+            channel: $channel,
+            min_us: $min_us,
+            max_us: $max_us,
+            max_degrees: $max_degrees,
+            pwm_freq_hz: $pwm_freq_hz,
+            max_steps: $max_steps,
+            ease_start_steps: $ease_start_steps,
+            ease_end_steps: $ease_end_steps_value,
             fields: [ ]
         }
     };
@@ -831,7 +1792,10 @@ macro_rules! __servo_player_impl {
         min_us: $min_us:expr,
         max_us: $max_us:expr,
         max_degrees: $max_degrees:expr,
+        pwm_freq_hz: $pwm_freq_hz:expr,
         max_steps: $max_steps:expr,
+        ease_start_steps: $ease_start_steps:expr,
+        ease_end_steps: $ease_end_steps:expr,
         fields: [ channel: A $(, $($rest:tt)* )? ]
     ) => {
         $crate::__servo_player_impl! {
@@ -844,7 +1808,10 @@ macro_rules! __servo_player_impl {
             min_us: $min_us,
             max_us: $max_us,
             max_degrees: $max_degrees,
+            pwm_freq_hz: $pwm_freq_hz,
             max_steps: $max_steps,
+            ease_start_steps: $ease_start_steps,
+            ease_end_steps: $ease_end_steps,
             fields: [ $($($rest)*)? ]
         }
     };
@@ -858,7 +1825,10 @@ macro_rules! __servo_player_impl {
         min_us: $min_us:expr,
         max_us: $max_us:expr,
         max_degrees: $max_degrees:expr,
+        pwm_freq_hz: $pwm_freq_hz:expr,
         max_steps: $max_steps:expr,
+        ease_start_steps: $ease_start_steps:expr,
+        ease_end_steps: $ease_end_steps:expr,
         fields: [ channel: A ]
     ) => {
         $crate::__servo_player_impl! {
@@ -871,7 +1841,10 @@ macro_rules! __servo_player_impl {
             min_us: $min_us,
             max_us: $max_us,
             max_degrees: $max_degrees,
+            pwm_freq_hz: $pwm_freq_hz,
             max_steps: $max_steps,
+            ease_start_steps: $ease_start_steps,
+            ease_end_steps: $ease_end_steps,
             fields: [ ]
         }
     };
@@ -885,7 +1858,10 @@ macro_rules! __servo_player_impl {
         min_us: $min_us:expr,
         max_us: $max_us:expr,
         max_degrees: $max_degrees:expr,
+        pwm_freq_hz: $pwm_freq_hz:expr,
         max_steps: $max_steps:expr,
+        ease_start_steps: $ease_start_steps:expr,
+        ease_end_steps: $ease_end_steps:expr,
         fields: [ channel: B $(, $($rest:tt)* )? ]
     ) => {
         $crate::__servo_player_impl! {
@@ -898,7 +1874,10 @@ macro_rules! __servo_player_impl {
             min_us: $min_us,
             max_us: $max_us,
             max_degrees: $max_degrees,
+            pwm_freq_hz: $pwm_freq_hz,
             max_steps: $max_steps,
+            ease_start_steps: $ease_start_steps,
+            ease_end_steps: $ease_end_steps,
             fields: [ $($($rest)*)? ]
         }
     };
@@ -912,7 +1891,10 @@ macro_rules! __servo_player_impl {
         min_us: $min_us:expr,
         max_us: $max_us:expr,
         max_degrees: $max_degrees:expr,
+        pwm_freq_hz: $pwm_freq_hz:expr,
         max_steps: $max_steps:expr,
+        ease_start_steps: $ease_start_steps:expr,
+        ease_end_steps: $ease_end_steps:expr,
         fields: [ channel: B ]
     ) => {
         $crate::__servo_player_impl! {
@@ -925,7 +1907,10 @@ macro_rules! __servo_player_impl {
             min_us: $min_us,
             max_us: $max_us,
             max_degrees: $max_degrees,
+            pwm_freq_hz: $pwm_freq_hz,
             max_steps: $max_steps,
+            ease_start_steps: $ease_start_steps,
+            ease_end_steps: $ease_end_steps,
             fields: [ ]
         }
     };
@@ -939,7 +1924,10 @@ macro_rules! __servo_player_impl {
         min_us: $min_us:expr,
         max_us: $max_us:expr,
         max_degrees: $max_degrees:expr,
+        pwm_freq_hz: $pwm_freq_hz:expr,
         max_steps: $max_steps:expr,
+        ease_start_steps: $ease_start_steps:expr,
+        ease_end_steps: $ease_end_steps:expr,
         fields: [ even $(, $($rest:tt)* )? ]
     ) => {
         $crate::__servo_player_impl! {
@@ -952,7 +1940,10 @@ macro_rules! __servo_player_impl {
             min_us: $min_us,
             max_us: $max_us,
             max_degrees: $max_degrees,
+            pwm_freq_hz: $pwm_freq_hz,
             max_steps: $max_steps,
+            ease_start_steps: $ease_start_steps,
+            ease_end_steps: $ease_end_steps,
             fields: [ $($($rest)*)? ]
         }
     };
@@ -966,7 +1957,10 @@ macro_rules! __servo_player_impl {
         min_us: $min_us:expr,
         max_us: $max_us:expr,
         max_degrees: $max_degrees:expr,
+        pwm_freq_hz: $pwm_freq_hz:expr,
         max_steps: $max_steps:expr,
+        ease_start_steps: $ease_start_steps:expr,
+        ease_end_steps: $ease_end_steps:expr,
         fields: [ even ]
     ) => {
         $crate::__servo_player_impl! {
@@ -979,7 +1973,10 @@ macro_rules! __servo_player_impl {
             min_us: $min_us,
             max_us: $max_us,
             max_degrees: $max_degrees,
+            pwm_freq_hz: $pwm_freq_hz,
             max_steps: $max_steps,
+            ease_start_steps: $ease_start_steps,
+            ease_end_steps: $ease_end_steps,
             fields: [ ]
         }
     };
@@ -993,7 +1990,10 @@ macro_rules! __servo_player_impl {
         min_us: $min_us:expr,
         max_us: $max_us:expr,
         max_degrees: $max_degrees:expr,
+        pwm_freq_hz: $pwm_freq_hz:expr,
         max_steps: $max_steps:expr,
+        ease_start_steps: $ease_start_steps:expr,
+        ease_end_steps: $ease_end_steps:expr,
         fields: [ odd $(, $($rest:tt)* )? ]
     ) => {
         $crate::__servo_player_impl! {
@@ -1006,7 +2006,10 @@ macro_rules! __servo_player_impl {
             min_us: $min_us,
             max_us: $max_us,
             max_degrees: $max_degrees,
+            pwm_freq_hz: $pwm_freq_hz,
             max_steps: $max_steps,
+            ease_start_steps: $ease_start_steps,
+            ease_end_steps: $ease_end_steps,
             fields: [ $($($rest)*)? ]
         }
     };
@@ -1020,7 +2023,10 @@ macro_rules! __servo_player_impl {
         min_us: $min_us:expr,
         max_us: $max_us:expr,
         max_degrees: $max_degrees:expr,
+        pwm_freq_hz: $pwm_freq_hz:expr,
         max_steps: $max_steps:expr,
+        ease_start_steps: $ease_start_steps:expr,
+        ease_end_steps: $ease_end_steps:expr,
         fields: [ odd ]
     ) => {
         $crate::__servo_player_impl! {
@@ -1033,7 +2039,10 @@ macro_rules! __servo_player_impl {
             min_us: $min_us,
             max_us: $max_us,
             max_degrees: $max_degrees,
+            pwm_freq_hz: $pwm_freq_hz,
             max_steps: $max_steps,
+            ease_start_steps: $ease_start_steps,
+            ease_end_steps: $ease_end_steps,
             fields: [ ]
         }
     };
@@ -1048,7 +2057,10 @@ macro_rules! __servo_player_impl {
         min_us: $min_us:expr,
         max_us: $max_us:expr,
         max_degrees: $max_degrees:expr,
+        pwm_freq_hz: $pwm_freq_hz:expr,
         max_steps: $max_steps:expr,
+        ease_start_steps: $ease_start_steps:expr,
+        ease_end_steps: $ease_end_steps:expr,
         fields: [ ]
     ) => {
         $crate::__servo_player_impl! {
@@ -1061,7 +2073,10 @@ macro_rules! __servo_player_impl {
             min_us: $min_us,
             max_us: $max_us,
             max_degrees: $max_degrees,
-            max_steps: $max_steps
+            pwm_freq_hz: $pwm_freq_hz,
+            max_steps: $max_steps,
+            ease_start_steps: $ease_start_steps,
+            ease_end_steps: $ease_end_steps
         }
     };
 
@@ -1075,7 +2090,10 @@ macro_rules! __servo_player_impl {
         min_us: $min_us:expr,
         max_us: $max_us:expr,
         max_degrees: $max_degrees:expr,
-        max_steps: $max_steps:expr
+        pwm_freq_hz: $pwm_freq_hz:expr,
+        max_steps: $max_steps:expr,
+        ease_start_steps: $ease_start_steps:expr,
+        ease_end_steps: $ease_end_steps:expr
     ) => {
         compile_error!("servo_player! requires `pin: ...`");
     };
@@ -1090,7 +2108,10 @@ macro_rules! __servo_player_impl {
         min_us: $min_us:expr,
         max_us: $max_us:expr,
         max_degrees: $max_degrees:expr,
-        max_steps: $max_steps:expr
+        pwm_freq_hz: $pwm_freq_hz:expr,
+        max_steps: $max_steps:expr,
+        ease_start_steps: $ease_start_steps:expr,
+        ease_end_steps: $ease_end_steps:expr
     ) => {
         $crate::servo_player::paste::paste! {
             static [<$name:upper _SERVO_PLAYER_STATIC>]: $crate::servo_player::ServoPlayerStatic<$max_steps> =
@@ -1105,6 +2126,9 @@ macro_rules! __servo_player_impl {
 
             #[allow(missing_docs)]
             impl $name {
+                /// Maximum servo angle in degrees. Animation steps beyond it are clamped.
+                pub const MAX_DEGREES: u16 = $max_degrees;
+
                 /// Create the servo player and spawn its background task.
                 ///
                 /// The slice is automatically determined from the pin via the type
@@ -1139,11 +2163,18 @@ macro_rules! __servo_player_impl {
                         slice,
                         $min_us,
                         $max_us,
-                        $max_degrees
+                        $max_degrees,
+                        $pwm_freq_hz
                     );
+                    // Register first so a running player is always reachable from `relax_all`.
+                    [<$name:upper _SERVO_PLAYER_STATIC>].register_emergency_stop()?;
                     let token = [<$name:snake _servo_player_task>](&[<$name:upper _SERVO_PLAYER_STATIC>], servo);
                     spawner.spawn(token)?;
-                    let player = $crate::servo_player::ServoPlayer::new(&[<$name:upper _SERVO_PLAYER_STATIC>]);
+                    let player = $crate::servo_player::ServoPlayer::new(
+                        &[<$name:upper _SERVO_PLAYER_STATIC>],
+                        Self::MAX_DEGREES,
+                    )
+                    .with_easing($ease_start_steps, $ease_end_steps);
                     Ok([<$name:upper _SERVO_PLAYER_CELL>].init(Self { player }))
                 }
             }
@@ -1160,7 +2191,7 @@ macro_rules! __servo_player_impl {
             async fn [<$name:snake _servo_player_task>](
                 servo_player_static: &'static $crate::servo_player::ServoPlayerStatic<$max_steps>,
                 servo: $crate::servo::Servo<'static>,
-            ) -> ! {
+            ) {
                 $crate::servo_player::device_loop(servo_player_static, servo).await
             }
         }
@@ -1175,7 +2206,10 @@ macro_rules! __servo_player_impl {
         min_us: $min_us:expr,
         max_us: $max_us:expr,
         max_degrees: $max_degrees:expr,
-        max_steps: $max_steps:expr
+        pwm_freq_hz: $pwm_freq_hz:expr,
+        max_steps: $max_steps:expr,
+        ease_start_steps: $ease_start_steps:expr,
+        ease_end_steps: $ease_end_steps:expr
     ) => {
         $crate::servo_player::paste::paste! {
             static [<$name:upper _SERVO_PLAYER_STATIC>]: $crate::servo_player::ServoPlayerStatic<$max_steps> =
@@ -1190,6 +2224,9 @@ macro_rules! __servo_player_impl {
 
             #[allow(missing_docs)]
             impl $name {
+                /// Maximum servo angle in degrees. Animation steps beyond it are clamped.
+                pub const MAX_DEGREES: u16 = $max_degrees;
+
                 /// Create the servo player and spawn its background task.
                 ///
                 /// # PWM Slice Calculation
@@ -1219,11 +2256,18 @@ macro_rules! __servo_player_impl {
                         channel: $channel,
                         min_us: $min_us,
                         max_us: $max_us,
-                        max_degrees: $max_degrees
+                        max_degrees: $max_degrees,
+                        pwm_freq_hz: $pwm_freq_hz
                     };
+                    // Register first so a running player is always reachable from `relax_all`.
+                    [<$name:upper _SERVO_PLAYER_STATIC>].register_emergency_stop()?;
                     let token = [<$name:snake _servo_player_task>](&[<$name:upper _SERVO_PLAYER_STATIC>], servo);
                     spawner.spawn(token)?;
-                    let player = $crate::servo_player::ServoPlayer::new(&[<$name:upper _SERVO_PLAYER_STATIC>]);
+                    let player = $crate::servo_player::ServoPlayer::new(
+                        &[<$name:upper _SERVO_PLAYER_STATIC>],
+                        Self::MAX_DEGREES,
+                    )
+                    .with_easing($ease_start_steps, $ease_end_steps);
                     Ok([<$name:upper _SERVO_PLAYER_CELL>].init(Self { player }))
                 }
             }
@@ -1240,7 +2284,7 @@ macro_rules! __servo_player_impl {
             async fn [<$name:snake _servo_player_task>](
                 servo_player_static: &'static $crate::servo_player::ServoPlayerStatic<$max_steps>,
                 servo: $crate::servo::Servo<'static>,
-            ) -> ! {
+            ) {
                 $crate::servo_player::device_loop(servo_player_static, servo).await
             }
         }
@@ -1253,9 +2297,9 @@ macro_rules! __servo_player_impl {
         min_us: $min_us:expr,
         max_us: $max_us:expr,
         max_degrees: $max_degrees:expr,
-        max_steps: $max_steps:expr
+        pwm_freq_hz: $pwm_freq_hz:expr
     ) => {
-        $crate::servo::servo_from_pin_slice($pin, $slice, $min_us, $max_us, $max_degrees)
+        $crate::servo::servo_from_pin_slice($pin, $slice, $min_us, $max_us, $max_degrees, $pwm_freq_hz)
     };
 
     (@__build_servo
@@ -1265,7 +2309,7 @@ macro_rules! __servo_player_impl {
         min_us: $min_us:expr,
         max_us: $max_us:expr,
         max_degrees: $max_degrees:expr,
-        max_steps: $max_steps:expr
+        pwm_freq_hz: $pwm_freq_hz:expr
     ) => {
         $crate::servo::Servo::new_output_a(
             embassy_rp::pwm::Pwm::new_output_a(
@@ -1276,6 +2320,7 @@ macro_rules! __servo_player_impl {
             $min_us,
             $max_us,
             $max_degrees,
+            $pwm_freq_hz,
         )
     };
 
@@ -1286,7 +2331,7 @@ macro_rules! __servo_player_impl {
         min_us: $min_us:expr,
         max_us: $max_us:expr,
         max_degrees: $max_degrees:expr,
-        max_steps: $max_steps:expr
+        pwm_freq_hz: $pwm_freq_hz:expr
     ) => {
         $crate::servo::Servo::new_output_b(
             embassy_rp::pwm::Pwm::new_output_b(
@@ -1297,6 +2342,7 @@ macro_rules! __servo_player_impl {
             $min_us,
             $max_us,
             $max_degrees,
+            $pwm_freq_hz,
         )
     };
 
@@ -1313,18 +2359,22 @@ macro_rules! __servo_player_impl {
             min_us: $crate::servo::SERVO_MIN_US_DEFAULT,
             max_us: $crate::servo::SERVO_MAX_US_DEFAULT,
             max_degrees: $crate::servo::Servo::DEFAULT_MAX_DEGREES,
+            pwm_freq_hz: $crate::servo::SERVO_PWM_FREQ_HZ_DEFAULT,
             max_steps: 16,
+            ease_start_steps: 0,
+            ease_end_steps: 0,
             fields: [ $($fields)* ]
         }
     };
 }
 
+#[cfg(not(feature = "host"))]
 // Called by macro-generated code in downstream crates; must be public.
 #[doc(hidden)]
 pub async fn device_loop<const MAX_STEPS: usize>(
     servo_player_static: &'static ServoPlayerStatic<MAX_STEPS>,
     mut servo: Servo<'static>,
-) -> ! {
+) {
     let mut current_degrees: u16 = 0;
     servo.set_degrees(current_degrees);
 
@@ -1336,6 +2386,16 @@ pub async fn device_loop<const MAX_STEPS: usize>(
                 servo.set_degrees(current_degrees);
                 command = servo_player_static.wait().await;
             }
+            PlayerCommand::MoveBy { degrees, deadline } => {
+                command = run_move_by(
+                    degrees,
+                    deadline,
+                    &mut servo,
+                    servo_player_static,
+                    &mut current_degrees,
+                )
+                .await;
+            }
             PlayerCommand::Hold => {
                 servo.hold();
                 command = servo_player_static.wait().await;
@@ -1344,10 +2404,19 @@ pub async fn device_loop<const MAX_STEPS: usize>(
                 servo.relax();
                 command = servo_player_static.wait().await;
             }
-            PlayerCommand::Animate { steps, mode } => {
+            PlayerCommand::Shutdown => {
+                servo.relax();
+                return;
+            }
+            PlayerCommand::Animate {
+                steps,
+                mode,
+                play_id,
+            } => {
                 command = run_animation(
                     &steps,
                     mode,
+                    play_id,
                     &mut servo,
                     servo_player_static,
                     &mut current_degrees,
@@ -1358,9 +2427,55 @@ pub async fn device_loop<const MAX_STEPS: usize>(
     }
 }
 
+#[cfg(not(feature = "host"))]
+// How often `move_to_by` updates the angle: one 50 Hz PWM period.
+const MOVE_BY_UPDATE_PERIOD: Duration = Duration::from_millis(20);
+
+#[cfg(not(feature = "host"))]
+async fn run_move_by<const MAX_STEPS: usize>(
+    target_degrees: u16,
+    deadline: Instant,
+    servo: &mut Servo<'static>,
+    servo_player_static: &'static ServoPlayerStatic<MAX_STEPS>,
+    current_degrees: &mut u16,
+) -> PlayerCommand<MAX_STEPS> {
+    let start_degrees = i64::from(*current_degrees);
+    let start = Instant::now();
+    let total_micros =
+        i64::try_from(deadline.saturating_duration_since(start).as_micros()).unwrap_or(i64::MAX);
+    loop {
+        let now = Instant::now();
+        if now >= deadline {
+            break;
+        }
+        let elapsed_micros =
+            i64::try_from(now.duration_since(start).as_micros()).unwrap_or(i64::MAX);
+        let degrees = start_degrees
+            + (i64::from(target_degrees) - start_degrees) * elapsed_micros / total_micros;
+        let degrees = u16::try_from(degrees).expect("degrees lie between start and target");
+        if *current_degrees != degrees {
+            servo.set_degrees(degrees);
+            *current_degrees = degrees;
+        }
+        let wake_at = (now + MOVE_BY_UPDATE_PERIOD).min(deadline);
+        if let Either::Second(command) =
+            select(Timer::at(wake_at), servo_player_static.wait()).await
+        {
+            return command;
+        }
+    }
+    if *current_degrees != target_degrees {
+        servo.set_degrees(target_degrees);
+        *current_degrees = target_degrees;
+    }
+    servo_player_static.wait().await
+}
+
+#[cfg(not(feature = "host"))]
 async fn run_animation<const MAX_STEPS: usize>(
     steps: &[(u16, Duration)],
     mode: AtEnd,
+    mut play_id: Option<u32>,
     servo: &mut Servo<'static>,
     servo_player_static: &'static ServoPlayerStatic<MAX_STEPS>,
     current_degrees: &mut u16,
@@ -1373,10 +2488,20 @@ async fn run_animation<const MAX_STEPS: usize>(
             }
             match select(Timer::after(step.1), servo_player_static.wait()).await {
                 Either::First(_) => {}
-                Either::Second(command) => return command,
+                Either::Second(command) => {
+                    // Interrupted - release any `play` caller still waiting on this sequence
+                    servo_player_static.signal_play_finished(play_id);
+                    return command;
+                }
             }
         }
 
+        if matches!(mode, AtEnd::Relax) {
+            servo.relax();
+        }
+        // First pass completed - a looping `play` resolves here and keeps looping
+        servo_player_static.signal_play_finished(play_id.take());
+
         // Animation sequence completed - handle end behavior
         match mode {
             AtEnd::Loop => {
@@ -1387,8 +2512,7 @@ async fn run_animation<const MAX_STEPS: usize>(
                 return servo_player_static.wait().await;
             }
             AtEnd::Relax => {
-                // Stop holding position (servo relaxes) and wait for next command
-                servo.relax();
+                // Servo already relaxed above; wait for next command
                 return servo_player_static.wait().await;
             }
         }
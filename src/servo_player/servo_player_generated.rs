@@ -47,6 +47,11 @@ impl ServoPlayerGenerated {
     /// Specified in the [`servo_player!`](macro@crate::servo_player) macro.
     pub const MAX_STEPS: usize = 16;
 
+    /// Maximum servo angle in degrees. Animation steps beyond it are clamped.
+    ///
+    /// Specified in the [`servo_player!`](macro@crate::servo_player) macro.
+    pub const MAX_DEGREES: u16 = 180;
+
     /// Create static resources.
     ///
     /// See the [`servo_player`](mod@crate::servo_player) module docs for usage.
@@ -88,6 +93,13 @@ impl ServoPlayerGenerated {
         let _ = degrees;
     }
 
+    /// Move in a straight line to `degrees`, arriving exactly at `deadline`, then hold.
+    ///
+    /// See the [`servo_player`](mod@crate::servo_player) module docs for usage.
+    pub fn move_to_by(&self, degrees: u16, deadline: embassy_time::Instant) {
+        let _ = (degrees, deadline);
+    }
+
     /// Hold the servo at its current position.
     ///
     /// See the [`servo_player`](mod@crate::servo_player) module docs for usage.
@@ -98,10 +110,26 @@ impl ServoPlayerGenerated {
     /// See the [`servo_player`](mod@crate::servo_player) module docs for usage.
     pub fn relax(&self) {}
 
+    /// Relax the servo and stop its background task.
+    ///
+    /// The task returns, dropping its PWM slice so the hardware is quiesced. The player's
+    /// statics cannot be reclaimed, so the player cannot be created again; later commands
+    /// are ignored.
+    pub fn shutdown(&self) {}
+
+    /// Relax the servo immediately, on a path that bypasses ordinary commands.
+    ///
+    /// Unlike [`relax`](Self::relax), an emergency stop cannot be overwritten by a command
+    /// sent right after it: the device loop always handles it first, stops any animation,
+    /// and drops the pending command. Later commands move the servo again. Use
+    /// [`relax_all`](crate::servo_player::relax_all) to stop every servo player at once.
+    pub fn emergency_stop(&self) {}
+
     /// Animate the servo through a sequence of angles with per-step hold durations.
     ///
     /// Each step is a tuple `(degrees, duration)`. Accepts both owned iterators and
-    /// references to collections.
+    /// references to collections. Steps beyond [`MAX_DEGREES`](Self::MAX_DEGREES) are
+    /// clamped to it, with a warning logged.
     ///
     /// See the [`servo_player`](mod@crate::servo_player) module docs for usage.
     pub fn animate<I>(&self, steps: I, at_end: AtEnd)
@@ -111,4 +139,17 @@ impl ServoPlayerGenerated {
     {
         let _ = (steps, at_end);
     }
+
+    /// Animate the servo through a sequence of steps and wait for it to finish.
+    ///
+    /// Resolves after one full pass (or when another command interrupts the sequence).
+    ///
+    /// See the [`servo_player`](mod@crate::servo_player) module docs for usage.
+    pub async fn play<I>(&self, steps: I, at_end: AtEnd)
+    where
+        I: IntoIterator,
+        I::Item: core::borrow::Borrow<(u16, Duration)>,
+    {
+        let _ = (steps, at_end);
+    }
 }
@@ -43,30 +43,87 @@ use stack::{WifiStartMode, WifiStatic as InnerWifiStatic};
 pub use stack::WifiPio;
 pub(crate) use stack::{Wifi, WifiEvent};
 
-pub use portal::WifiAutoField;
+pub use portal::{FieldValue, FormData, HtmlBuffer, WifiAutoField};
 
 /// Events emitted while connecting. See [`WifiAuto::connect`](crate::wifi_auto::WifiAuto::connect)
 /// for usage examples.
+///
+/// Every event records the [`Instant`] it happened, so handlers can time phases (for
+/// example, "connected in 3.2 s") without calling `Instant::now()` themselves.
 #[derive(Clone, Copy, Debug, defmt::Format)]
 pub enum WifiAutoEvent {
     /// Captive portal is ready and waiting for user configuration.
-    CaptivePortalReady,
+    CaptivePortalReady {
+        /// When the portal became ready.
+        at: Instant,
+    },
     /// Attempting to connect to WiFi network.
     Connecting {
         /// Current attempt number (0-based).
         try_index: u8,
         /// Total number of attempts that will be made.
         try_count: u8,
+        /// When this attempt started.
+        at: Instant,
+    },
+    /// Connected, with the network stack up. This is the last event `connect` reports
+    /// before returning.
+    Connected {
+        /// When the network stack came up.
+        at: Instant,
     },
     /// Connection failed after all attempts, device will reset.
-    ConnectionFailed,
+    ConnectionFailed {
+        /// When the last attempt gave up.
+        at: Instant,
+    },
+}
+
+impl WifiAutoEvent {
+    /// When this event happened.
+    ///
+    /// See [`WifiAuto::connect`] for usage examples.
+    #[must_use]
+    pub const fn at(&self) -> Instant {
+        match self {
+            Self::CaptivePortalReady { at }
+            | Self::Connecting { at, .. }
+            | Self::Connected { at }
+            | Self::ConnectionFailed { at } => *at,
+        }
+    }
+}
+
+/// CYW43 power management applied after WiFi connects. See [`WifiAuto::new`] for usage.
+///
+/// Power save lets the radio sleep between access-point beacons. This lowers current
+/// draw but can add hundreds of milliseconds of latency to network responses.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, defmt::Format)]
+pub enum WifiPowerMode {
+    /// Radio sleeps between beacons: lower current, higher latency (default).
+    #[default]
+    PowerSave,
+    /// Radio stays awake: lowest latency, higher current.
+    Performance,
+}
+
+impl WifiPowerMode {
+    const fn to_cyw43(self) -> cyw43::PowerManagementMode {
+        match self {
+            Self::PowerSave => cyw43::PowerManagementMode::PowerSave,
+            Self::Performance => cyw43::PowerManagementMode::Performance,
+        }
+    }
 }
 
-const MAX_CONNECT_ATTEMPTS: u8 = 4;
-const CONNECT_TIMEOUT: Duration = Duration::from_secs(40);
 const RETRY_BASE_DELAY: Duration = Duration::from_secs(3);
+const RETRY_MAX_DELAY: Duration = Duration::from_secs(60);
 const RETRY_JITTER_MAX: Duration = Duration::from_millis(500);
 
+/// How long the button must be held at boot for a factory reset rather than just
+/// reconfiguring WiFi. See [Resetting with the button](WifiAuto#resetting-with-the-button).
+pub const FACTORY_RESET_HOLD: Duration = Duration::from_secs(5);
+
 pub(crate) type WifiAutoEvents = Signal<CriticalSectionRawMutex, WifiAutoEvent>;
 
 const MAX_WIFI_AUTO_FIELDS: usize = 8;
@@ -94,13 +151,15 @@ pub(crate) struct WifiAutoStatic {
 ///
 /// The typical usage pattern is:
 ///
-/// 0. Ensure your hardware includes a button wired to a GPIO. The button can be used during boot to force captive-portal mode.
+/// 0. Ensure your hardware includes a button wired to a GPIO. The button can be used during boot to force captive-portal mode
+///    or a factory reset; see [Resetting with the button](Self#resetting-with-the-button).
 /// 1. Construct a [`FlashArray`](crate::flash_array::FlashArray) to store WiFi credentials.
 /// 2. Use [`WifiAuto::new`] to construct a `WifiAuto`.
 /// 3. Use [`WifiAuto::connect`] to connect to WiFi while optionally showing status.
 ///
-/// The [`WifiAuto::connect`] method returns a network stack and the button, and it consumes
-/// the `WifiAuto`. See its documentation for examples and details.
+/// The [`WifiAuto::connect`] method returns a network stack and the button. See its
+/// documentation for examples and details. Afterwards, [`WifiAuto::field_value`] reads
+/// what the user entered in any custom fields.
 ///
 /// Let’s look at an example. Following the example, we’ll explain the details.
 /// (For additional examples, see the [wifi_auto::fields module example](crate::wifi_auto::fields)
@@ -118,7 +177,7 @@ pub(crate) struct WifiAutoStatic {
 ///     Result,
 ///     button::PressedTo,
 ///     flash_array::FlashArray,
-///     wifi_auto::{WifiAuto, WifiAutoEvent},
+///     wifi_auto::{WifiAuto, WifiAutoEvent, WifiPowerMode},
 /// };
 /// use embassy_time::Duration;
 ///
@@ -142,6 +201,9 @@ pub(crate) struct WifiAutoStatic {
 ///         PressedTo::Ground,
 ///         "PicoAccess",      // Captive-portal SSID
 ///         [],                // Any extra fields
+///         WifiPowerMode::PowerSave, // Radio power management once connected
+///         4,                 // Connect attempts before the portal
+///         Duration::from_secs(40), // Timeout for each attempt
 ///         spawner,
 ///     )?;
 ///
@@ -149,11 +211,14 @@ pub(crate) struct WifiAutoStatic {
 ///     let (stack, _button) = wifi_auto
 ///         .connect(|event| async move {
 ///             match event {
-///                 WifiAutoEvent::CaptivePortalReady =>
+///                 WifiAutoEvent::CaptivePortalReady { .. } =>
 ///                     defmt::info!("Captive portal ready"),
-///                 WifiAutoEvent::Connecting { .. } =>
-///                     defmt::info!("Connecting to WiFi"),
-///                 WifiAutoEvent::ConnectionFailed =>
+///                 // Each event records when it happened.
+///                 WifiAutoEvent::Connecting { try_index, at, .. } =>
+///                     defmt::info!("Connecting to WiFi (attempt {}) at {} ms", try_index + 1, at.as_millis()),
+///                 WifiAutoEvent::Connected { .. } =>
+///                     defmt::info!("WiFi connected"),
+///                 WifiAutoEvent::ConnectionFailed { .. } =>
 ///                     defmt::info!("WiFi connection failed"),
 ///             }
 ///             Ok(())
@@ -186,6 +251,108 @@ pub(crate) struct WifiAutoStatic {
 /// ## WiFi limitations
 ///
 /// - Only standard SSID/password 2.4 Ghz WiFi networks are supported.
+/// - WPS push-button setup is not supported. The CYW43 firmware does not run the WPS
+///   exchange itself; Infineon's C driver does it on the host, and the `cyw43` crate used
+///   here has no equivalent. Credentials come only from the captive portal or the
+///   defaults you provide.
+///
+/// ## Resetting with the button
+///
+/// Holding the button while the device boots forces the captive portal. How long it is
+/// held picks what is kept:
+///
+/// - Released before [`FACTORY_RESET_HOLD`] (5 seconds): reconfigure WiFi. The form is
+///   pre-filled with the saved network, and custom fields keep their values.
+/// - Held for [`FACTORY_RESET_HOLD`] or longer: factory reset. The saved network is erased
+///   and every custom field is cleared with [`WifiAutoField::clear`] before the portal
+///   starts.
+///
+/// The hold is timed from [`WifiAuto::new`]. [`WifiAuto::connect`] waits for the release,
+/// for at most the rest of [`FACTORY_RESET_HOLD`], before its first event. The wait is
+/// async, so other tasks keep running; a display task can show a "release for WiFi setup,
+/// keep holding to reset" hint meanwhile.
+///
+/// ## Power and latency
+///
+/// By default ([`WifiPowerMode::PowerSave`]), the CYW43 radio sleeps between access-point
+/// beacons once connected. This suits battery devices but can add hundreds of milliseconds
+/// to network responses. Pass [`WifiPowerMode::Performance`] to [`WifiAuto::new`] to keep
+/// the radio awake for lower latency at higher current.
+///
+/// ## Connection retries
+///
+/// `connect` makes up to `max_connect_attempts` attempts, each waiting up to
+/// `connect_timeout`, before giving up and returning to the captive portal. Between
+/// attempts it waits 3 seconds, doubling each time up to a minute. The examples pass 4
+/// attempts of 40 seconds. On a fast local network, a shorter timeout reports a bad
+/// password sooner; where the signal is poor, allow more attempts:
+///
+/// ```rust,no_run
+/// # #![no_std]
+/// # #![no_main]
+/// # use panic_probe as _;
+/// # use device_envoy::{
+/// #     Result,
+/// #     button::PressedTo,
+/// #     flash_array::FlashArray,
+/// #     wifi_auto::{WifiAuto, WifiPowerMode},
+/// # };
+/// # use embassy_time::Duration;
+/// # async fn example(spawner: embassy_executor::Spawner, p: embassy_rp::Peripherals) -> Result<()> {
+/// # let [wifi_flash] = FlashArray::<1>::new(p.FLASH)?;
+/// let wifi_auto = WifiAuto::new(
+///     p.PIN_23,
+///     p.PIN_24,
+///     p.PIN_25,
+///     p.PIN_29,
+///     p.PIO0,
+///     p.DMA_CH0,
+///     wifi_flash,
+///     p.PIN_13,
+///     PressedTo::Ground,
+///     "PicoAccess",
+///     [],
+///     WifiPowerMode::PowerSave,
+///     2,                       // Connect attempts
+///     Duration::from_secs(10), // Timeout for each attempt
+///     spawner,
+/// )?;
+/// # let _ = wifi_auto;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// ## Access point only
+///
+/// A device with no router nearby can host its own network permanently instead. Skip
+/// [`WifiAuto::new`] and call [`WifiAuto::start_access_point`], which returns a network
+/// stack at 192.168.4.1 once the network is up:
+///
+/// ```rust,no_run
+/// # #![no_std]
+/// # #![no_main]
+/// # use panic_probe as _;
+/// use device_envoy::wifi_auto::{WifiAuto, WifiPowerMode};
+///
+/// async fn host_network(spawner: embassy_executor::Spawner, p: embassy_rp::Peripherals) {
+///     let stack = WifiAuto::start_access_point(
+///         p.PIN_23,
+///         p.PIN_24,
+///         p.PIN_25,
+///         p.PIN_29,
+///         p.PIO0,
+///         p.DMA_CH0,
+///         "FieldSensor",
+///         "sensor-pass", // or "" for an open network
+///         WifiPowerMode::Performance,
+///         spawner,
+///     )
+///     .await;
+///
+///     // Serve clients at 192.168.4.1, for example with embassy_net::tcp::TcpSocket.
+///     let _ = stack;
+/// }
+/// ```
 ///
 /// ## Performance and code size
 ///
@@ -219,6 +386,8 @@ pub(crate) struct WifiAutoStatic {
 /// pair cannot be shared with other uses; the compiler enforces this.
 pub struct WifiAuto {
     wifi_auto: &'static WifiAutoInner,
+    max_connect_attempts: u8,
+    connect_timeout: Duration,
 }
 
 struct WifiAutoInner {
@@ -229,6 +398,7 @@ struct WifiAutoInner {
     defaults: &'static Mutex<CriticalSectionRawMutex, RefCell<Option<InnerWifiCredentials>>>,
     button: &'static Mutex<CriticalSectionRawMutex, RefCell<Option<Button<'static>>>>,
     fields: &'static [&'static dyn WifiAutoField],
+    pressed_at_boot: Option<Instant>,
 }
 
 impl WifiAutoStatic {
@@ -272,14 +442,24 @@ impl WifiAuto {
     /// - `dma`: DMA resource for WiFi.
     /// - `wifi_credentials_flash_block`: [`FlashBlock`] reserved
     ///   for WiFi credentials.
-    /// - `button_pin`: Button pin used to force setup mode on boot.
+    /// - `button_pin`: Button pin used to force setup mode or a factory reset on boot. See
+    ///   [Resetting with the button](Self#resetting-with-the-button).
     /// - `button_pressed_to`: Wiring for the button (ground or VCC).
     /// - `captive_portal_ssid`: SSID shown when the device starts setup mode.
     /// - `custom_fields`: Extra fields collected in the setup page. See the
     ///   [wifi_auto::fields module example](crate::wifi_auto::fields) for usage.
+    /// - `power_mode`: CYW43 power management applied after connecting. See [`WifiPowerMode`].
+    /// - `max_connect_attempts`: How many times [`connect`](Self::connect) tries the saved
+    ///   network before it reports [`WifiAutoEvent::ConnectionFailed`] and returns to the
+    ///   captive portal. See [Connection retries](Self#connection-retries).
+    /// - `connect_timeout`: How long each attempt waits for the network to come up.
     /// - `spawner`: Embassy task spawner for background work.
     ///
     /// See the [WifiAuto struct example](Self) for a complete example.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_connect_attempts` is 0 or `connect_timeout` is zero.
     #[allow(clippy::too_many_arguments)]
     pub fn new<const N: usize, PIO: WifiPio, DMA: Channel>(
         pin_23: Peri<'static, PIN_23>,
@@ -293,11 +473,30 @@ impl WifiAuto {
         button_pressed_to: PressedTo,
         captive_portal_ssid: &'static str,
         custom_fields: [&'static dyn WifiAutoField; N],
+        power_mode: WifiPowerMode,
+        max_connect_attempts: u8,
+        connect_timeout: Duration,
         spawner: Spawner,
     ) -> Result<Self> {
+        assert!(
+            max_connect_attempts > 0,
+            "max_connect_attempts must be positive"
+        );
+        assert!(
+            connect_timeout.as_ticks() > 0,
+            "connect_timeout must be positive"
+        );
         static WIFI_AUTO_STATIC: WifiAutoStatic = WifiAutoInner::new_static();
         let wifi_auto_static = &WIFI_AUTO_STATIC;
 
+        // Allow the pull-up to stabilize after reset before sampling the button.
+        let button = Button::new(button_pin, button_pressed_to);
+        let button_reset_stabilize_cycles: u32 = 300_000;
+        cortex_m::asm::delay(button_reset_stabilize_cycles);
+        let force_captive_portal = button.is_pressed_debounced();
+        // `connect` times the rest of the hold; blocking here would stall the executor.
+        let pressed_at_boot = force_captive_portal.then(Instant::now);
+
         let stored_credentials = Wifi::peek_credentials(&mut wifi_credentials_flash_block);
         let stored_start_mode = Wifi::peek_start_mode(&mut wifi_credentials_flash_block);
         if matches!(stored_start_mode, WifiStartMode::CaptivePortal) {
@@ -308,12 +507,6 @@ impl WifiAuto {
             }
         }
 
-        // Allow the pull-up to stabilize after reset before sampling the button.
-        let button = Button::new(button_pin, button_pressed_to);
-        let button_reset_stabilize_cycles: u32 = 300_000;
-        cortex_m::asm::delay(button_reset_stabilize_cycles);
-        let force_captive_portal = button.is_pressed();
-
         // Check if custom fields are satisfied
         let extras_ready = custom_fields
             .iter()
@@ -342,6 +535,7 @@ impl WifiAuto {
             dma,
             wifi_credentials_flash_block,
             captive_portal_ssid,
+            power_mode,
             spawner,
         );
 
@@ -374,6 +568,7 @@ impl WifiAuto {
             defaults: wifi_auto_static.defaults(),
             button: wifi_auto_static.button(),
             fields: fields_ref,
+            pressed_at_boot,
         });
 
         if force_captive_portal {
@@ -382,11 +577,13 @@ impl WifiAuto {
 
         Ok(Self {
             wifi_auto: instance,
+            max_connect_attempts,
+            connect_timeout,
         })
     }
 
     /// Connects to WiFi (if possible), reports status, and returns the
-    /// network stack and button, consuming the `WifiAuto`.
+    /// network stack and button. Call it once.
     ///
     /// See the [WifiAuto struct example](Self) for a usage example.
     ///
@@ -399,9 +596,12 @@ impl WifiAuto {
     /// The handler receives a [`WifiAutoEvent`].
     /// The handler is called sequentially for each event and may `await`.
     ///
-    /// The three events are:
+    /// The four events are:
     /// - `CaptivePortalReady`: The device is hosting a captive portal and waiting for user input.
     /// - `Connecting`: The device is attempting to connect to the WiFi network.
+    /// - `Connected`: The connection succeeded and the network stack is up. Because the
+    ///   handler runs while `connect` is still pending, this is where to stop a "connecting"
+    ///   animation started from an earlier `Connecting` event.
     /// - `ConnectionFailed`: All connection attempts failed. The device
     ///   will reset and re-enter setup mode (for example, if the password
     ///   is incorrect).
@@ -420,9 +620,10 @@ impl WifiAuto {
     /// #     Result,
     /// #     button::PressedTo,
     /// #     flash_array::FlashArray,
-    /// #     wifi_auto::WifiAuto,
+    /// #     wifi_auto::{WifiAuto, WifiPowerMode},
     /// # };
     /// # use embassy_executor::Spawner;
+    /// # use embassy_time::Duration;
     /// # use embassy_rp::Peripherals;
     /// # async fn example(spawner: Spawner, p: Peripherals) -> Result<()> {
     /// # let [wifi_flash] = FlashArray::<1>::new(p.FLASH)?;
@@ -438,6 +639,9 @@ impl WifiAuto {
     /// #     PressedTo::Ground,
     /// #     "PicoAccess",
     /// #     [],
+    /// #     WifiPowerMode::PowerSave,
+    /// #     4,                       // Connect attempts
+    /// #     Duration::from_secs(40), // Timeout for each attempt
     /// #     spawner,
     /// # )?;
     /// let (_stack, _button) = wifi_auto
@@ -458,10 +662,11 @@ impl WifiAuto {
     /// #     button::PressedTo,
     /// #     flash_array::FlashArray,
     /// #     led_strip::colors,
-    /// #     wifi_auto::{WifiAuto, WifiAutoEvent},
+    /// #     wifi_auto::{WifiAuto, WifiAutoEvent, WifiPowerMode},
     /// # };
     /// # use smart_leds::RGB8;
     /// # use embassy_executor::Spawner;
+    /// # use embassy_time::Duration;
     /// # use embassy_rp::Peripherals;
     /// # struct Led8x12;
     /// # impl Led8x12 {
@@ -483,6 +688,9 @@ impl WifiAuto {
     /// #     PressedTo::Ground,
     /// #     "PicoAccess",
     /// #     [],
+    /// #     WifiPowerMode::PowerSave,
+    /// #     4,                       // Connect attempts
+    /// #     Duration::from_secs(40), // Timeout for each attempt
     /// #     spawner,
     /// # )?;
     /// # let led8x12 = Led8x12;
@@ -491,13 +699,14 @@ impl WifiAuto {
     /// let (stack, button) = wifi_auto
     ///     .connect(|event| async move {
     ///         match event {
-    ///             WifiAutoEvent::CaptivePortalReady => {
+    ///             WifiAutoEvent::CaptivePortalReady { .. } => {
     ///                 led8x12_ref.write_text("JO\nIN", COLORS).await?;
     ///             }
     ///             WifiAutoEvent::Connecting { .. } => {
     ///                 show_animated_dots(led8x12_ref).await?;
     ///             }
-    ///             WifiAutoEvent::ConnectionFailed => {
+    ///             WifiAutoEvent::Connected { .. } => {}
+    ///             WifiAutoEvent::ConnectionFailed { .. } => {
     ///                 led8x12_ref.write_text("FA\nIL", COLORS).await?;
     ///             }
     ///         }
@@ -510,14 +719,108 @@ impl WifiAuto {
     /// # }
     /// ```
     pub async fn connect<Fut, F>(
-        self,
+        &self,
         on_event: F,
     ) -> Result<(&'static Stack<'static>, Button<'static>)>
     where
         F: FnMut(WifiAutoEvent) -> Fut,
         Fut: Future<Output = Result<()>>,
     {
-        self.wifi_auto.connect(on_event).await
+        self.wifi_auto
+            .connect(self.max_connect_attempts, self.connect_timeout, on_event)
+            .await
+    }
+
+    /// Read the stored value of the custom field whose form control is named `key`, as text.
+    ///
+    /// After [`connect`](Self::connect) returns, this is the value the user submitted in the
+    /// captive portal, or the one saved on an earlier boot. The built-in fields use the
+    /// `field_name` given to [`TextField::new`](fields::TextField::new) or
+    /// [`IntField::new`](fields::IntField::new); [`TimezoneField`](fields::TimezoneField)
+    /// uses `"timezone"` and reports minutes from UTC. Custom fields are found only if they
+    /// implement [`WifiAutoField::value`].
+    ///
+    /// Returns `None` if no field has that key or the field has no stored value.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a field cannot read its value from flash.
+    ///
+    /// See the [wifi_auto::fields module example](crate::wifi_auto::fields) for usage.
+    pub fn field_value(&self, key: &str) -> Result<Option<FieldValue>> {
+        for field in self.wifi_auto.fields {
+            if let Some(value) = field.value(key)? {
+                return Ok(Some(value));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Host a persistent WiFi network and return its network stack, without joining any
+    /// other network.
+    ///
+    /// Use this instead of [`WifiAuto::new`] for field devices with no router: phones and
+    /// laptops join the Pico's network and reach your server directly. The device never
+    /// switches to client mode, so no flash storage, button, or captive portal is needed.
+    ///
+    /// The stack has static IP 192.168.4.1 and runs a DHCP server for its clients
+    /// (192.168.4.2–254). There is no upstream Internet access and no DNS server.
+    ///
+    /// # Parameters
+    ///
+    /// - `pin_23`, `pin_24`, `pin_25`, `pin_29`: the internal GPIO pins for the CYW43 WiFi chip.
+    /// - `pio`: PIO resource used for WiFi.
+    /// - `dma`: DMA resource for WiFi.
+    /// - `ssid`: name of the network the device hosts.
+    /// - `password`: WPA2 password, or `""` for an open network.
+    /// - `power_mode`: CYW43 power management. See [`WifiPowerMode`].
+    /// - `spawner`: Embassy task spawner for background work.
+    ///
+    /// Call it once, and not together with [`WifiAuto::new`]: both use the same WiFi chip.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ssid` is empty or longer than 32 bytes, or if `password` is neither empty
+    /// nor 8 to 63 bytes long.
+    ///
+    /// See [Access point only](Self#access-point-only) for an example.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn start_access_point<PIO: WifiPio, DMA: Channel>(
+        pin_23: Peri<'static, PIN_23>,
+        pin_24: Peri<'static, PIN_24>,
+        pin_25: Peri<'static, PIN_25>,
+        pin_29: Peri<'static, PIN_29>,
+        pio: Peri<'static, PIO>,
+        dma: Peri<'static, DMA>,
+        ssid: &'static str,
+        password: &'static str,
+        power_mode: WifiPowerMode,
+        spawner: Spawner,
+    ) -> &'static Stack<'static> {
+        static WIFI_STATIC: InnerWifiStatic = Wifi::new_static();
+        assert!(
+            (1..=32).contains(&ssid.len()),
+            "access point ssid must be 1 to 32 bytes"
+        );
+        assert!(
+            password.is_empty() || (8..=63).contains(&password.len()),
+            "access point password must be empty or 8 to 63 bytes"
+        );
+        info!("WifiAuto: starting access-point-only mode");
+        Wifi::start_access_point(
+            &WIFI_STATIC,
+            pin_23,
+            pin_24,
+            pin_25,
+            pin_29,
+            pio,
+            dma,
+            ssid,
+            password,
+            power_mode,
+            spawner,
+        )
+        .await
     }
 }
 
@@ -535,6 +838,40 @@ impl WifiAutoInner {
         self.button.lock(|cell| cell.borrow_mut().take())
     }
 
+    /// Finish timing a button press from boot and, if the button is still held at
+    /// [`FACTORY_RESET_HOLD`], erase the saved network and every custom field.
+    async fn factory_reset_if_held(&self) -> Result<()> {
+        let Some(pressed_at) = self.pressed_at_boot else {
+            return Ok(());
+        };
+        let mut button = self.take_button().ok_or(Error::StorageCorrupted)?;
+        let remaining_hold = FACTORY_RESET_HOLD
+            .checked_sub(pressed_at.elapsed())
+            .unwrap_or(Duration::from_ticks(0));
+        let held = with_timeout(remaining_hold, button.wait_for_release())
+            .await
+            .is_err()
+            && button.is_pressed();
+        self.button.lock(|cell| {
+            *cell.borrow_mut() = Some(button);
+        });
+        if !held {
+            return Ok(());
+        }
+
+        info!("WifiAuto: factory reset, clearing credentials and custom fields");
+        self.wifi
+            .clear_credentials()
+            .map_err(|_| Error::StorageCorrupted)?;
+        for field in self.fields {
+            field.clear()?;
+        }
+        self.defaults.lock(|cell| {
+            *cell.borrow_mut() = None;
+        });
+        Ok(())
+    }
+
     fn extra_fields_ready(&self) -> Result<bool> {
         for field in self.fields {
             let satisfied = field.is_satisfied().map_err(|_| Error::StorageCorrupted)?;
@@ -552,14 +889,23 @@ impl WifiAutoInner {
 
     async fn connect<Fut, F>(
         &self,
+        max_connect_attempts: u8,
+        connect_timeout: Duration,
         mut on_event: F,
     ) -> Result<(&'static Stack<'static>, Button<'static>)>
     where
         F: FnMut(WifiAutoEvent) -> Fut,
         Fut: Future<Output = Result<()>>,
     {
-        self.ensure_connected_with(&mut on_event).await?;
+        self.factory_reset_if_held().await?;
+        self.ensure_connected_with(max_connect_attempts, connect_timeout, &mut on_event)
+            .await?;
         let stack = self.wifi.wait_for_stack().await;
+        self.signal_event_with(
+            &mut on_event,
+            WifiAutoEvent::Connected { at: Instant::now() },
+        )
+        .await?;
         let button = self.take_button().ok_or(Error::StorageCorrupted)?;
         Ok((stack, button))
     }
@@ -574,7 +920,12 @@ impl WifiAutoInner {
         Ok(())
     }
 
-    async fn ensure_connected_with<Fut, F>(&self, on_event: &mut F) -> Result<()>
+    async fn ensure_connected_with<Fut, F>(
+        &self,
+        max_connect_attempts: u8,
+        connect_timeout: Duration,
+        on_event: &mut F,
+    ) -> Result<()>
     where
         F: FnMut(WifiAutoEvent) -> Fut,
         Fut: Future<Output = Result<()>>,
@@ -600,27 +951,31 @@ impl WifiAutoInner {
                         });
                     }
                 }
-                self.signal_event_with(on_event, WifiAutoEvent::CaptivePortalReady)
-                    .await?;
+                self.signal_event_with(
+                    on_event,
+                    WifiAutoEvent::CaptivePortalReady { at: Instant::now() },
+                )
+                .await?;
                 self.run_captive_portal().await?;
                 unreachable!("Device should reset after captive portal submission");
             }
 
-            for attempt in 1..=MAX_CONNECT_ATTEMPTS {
+            for attempt in 1..=max_connect_attempts {
                 info!(
                     "WifiAuto: connection attempt {}/{}",
-                    attempt, MAX_CONNECT_ATTEMPTS
+                    attempt, max_connect_attempts
                 );
                 self.signal_event_with(
                     on_event,
                     WifiAutoEvent::Connecting {
                         try_index: attempt - 1,
-                        try_count: MAX_CONNECT_ATTEMPTS,
+                        try_count: max_connect_attempts,
+                        at: Instant::now(),
                     },
                 )
                 .await?;
                 if self
-                    .wait_for_client_ready_with_timeout(CONNECT_TIMEOUT)
+                    .wait_for_client_ready_with_timeout(connect_timeout)
                     .await
                 {
                     return Ok(());
@@ -637,11 +992,14 @@ impl WifiAutoInner {
 
             info!(
                 "WifiAuto: failed to connect after {} attempts, returning to captive portal",
-                MAX_CONNECT_ATTEMPTS
+                max_connect_attempts
             );
             info!("WifiAuto: signaling ConnectionFailed event");
-            self.signal_event_with(on_event, WifiAutoEvent::ConnectionFailed)
-                .await?;
+            self.signal_event_with(
+                on_event,
+                WifiAutoEvent::ConnectionFailed { at: Instant::now() },
+            )
+            .await?;
             if let Some(creds) = self.wifi.load_persisted_credentials() {
                 self.defaults.lock(|cell| {
                     *cell.borrow_mut() = Some(creds);
@@ -668,6 +1026,11 @@ impl WifiAutoInner {
                             "WifiAuto: received captive-portal-ready event while waiting for client mode"
                         );
                     }
+                    WifiEvent::AccessPointReady => {
+                        info!(
+                            "WifiAuto: received access-point-ready event while waiting for client mode"
+                        );
+                    }
                 }
             }
         })
@@ -714,10 +1077,10 @@ fn retry_delay_with_jitter(attempt_index: u8) -> Duration {
     let jitter_max_ms = RETRY_JITTER_MAX.as_millis();
     let multiplier = 1u64
         .checked_shl(u32::from(attempt_index))
-        .expect("attempt_index must fit in shift");
+        .unwrap_or(u64::MAX);
     let delay_ms = base_ms
-        .checked_mul(multiplier)
-        .expect("retry delay must fit in millis");
+        .saturating_mul(multiplier)
+        .min(RETRY_MAX_DELAY.as_millis());
     let jitter_ms = if jitter_max_ms == 0 {
         0
     } else {
@@ -3,8 +3,12 @@
 //!
 //! This module provides ready-to-use field types that can be passed to
 //! [`WifiAuto::new()`](super::WifiAuto::new) for collecting additional
-//! configuration beyond WiFi credentials. The fields module example below focuses
-//! on adding custom fields.
+//! configuration beyond WiFi credentials: [`TextField`], [`TimezoneField`], and
+//! [`IntField`] (with [`PortField`] for network ports). The fields module example
+//! below focuses on adding custom fields.
+//!
+//! After [`connect`](super::WifiAuto::connect), read each value through its field, or by
+//! form key with [`WifiAuto::field_value`](super::WifiAuto::field_value).
 //!
 //! # Example
 //!
@@ -16,13 +20,14 @@
 //! use device_envoy::button::PressedTo;
 //! use device_envoy::flash_array::FlashArray;
 //! use device_envoy::Error;
-//! use device_envoy::wifi_auto::{WifiAuto, WifiAutoEvent};
+//! use device_envoy::wifi_auto::{WifiAuto, WifiAutoEvent, WifiPowerMode};
 //! use device_envoy::wifi_auto::fields::{
 //!     TextField,
 //!     TextFieldStatic,
 //!     TimezoneField,
 //!     TimezoneFieldStatic,
 //! };
+//! use embassy_time::Duration;
 //!
 //! async fn example(
 //!     spawner: embassy_executor::Spawner,
@@ -54,18 +59,22 @@
 //!         PressedTo::Ground,
 //!         "Pico",
 //!         [website_field, timezone_field],
+//!         WifiPowerMode::PowerSave,
+//!         4,                       // Connect attempts
+//!         Duration::from_secs(40), // Timeout for each attempt
 //!         spawner,
 //!     )?;
 //!
 //!     let (stack, _button) = wifi_auto
 //!         .connect(|event| async move {
 //!             match event {
-//!                 WifiAutoEvent::CaptivePortalReady => {
+//!                 WifiAutoEvent::CaptivePortalReady { .. } => {
 //!                     defmt::info!("Captive portal ready");
 //!                 }
 //!                 WifiAutoEvent::Connecting {
 //!                     try_index,
 //!                     try_count,
+//!                     ..
 //!                 } => {
 //!                     defmt::info!(
 //!                         "Connecting to WiFi (attempt {} of {})...",
@@ -73,7 +82,10 @@
 //!                         try_count
 //!                     );
 //!                 }
-//!                 WifiAutoEvent::ConnectionFailed => {
+//!                 WifiAutoEvent::Connected { .. } => {
+//!                     defmt::info!("WiFi connected");
+//!                 }
+//!                 WifiAutoEvent::ConnectionFailed { .. } => {
 //!                     defmt::info!("WiFi connection failed");
 //!                 }
 //!             }
@@ -82,6 +94,9 @@
 //!         .await?;
 //!
 //!     let website = website_field.text()?.unwrap_or_default();
+//!     // The same value, looked up by its form key.
+//!     let website_by_key = wifi_auto.field_value("website")?.unwrap_or_default();
+//!     defmt::info!("Website: {}", website_by_key.as_str());
 //!     let offset_minutes = timezone_field
 //!         .offset_minutes()?
 //!         .ok_or(Error::MissingCustomWifiAutoField)?;
@@ -112,7 +127,7 @@ use defmt::info;
 use heapless::String;
 use static_cell::StaticCell;
 
-use super::portal::{FormData, HtmlBuffer, WifiAutoField};
+use super::portal::{FieldValue, FormData, HtmlBuffer, WifiAutoField};
 use crate::flash_array::FlashBlock;
 use crate::{Error, Result};
 
@@ -239,6 +254,19 @@ impl WifiAutoField for TimezoneField {
     fn is_satisfied(&self) -> Result<bool> {
         Ok(self.offset_minutes()?.is_some())
     }
+
+    fn clear(&self) -> Result<()> {
+        Self::clear(self)
+    }
+
+    fn value(&self, key: &str) -> Result<Option<FieldValue>> {
+        if key != "timezone" {
+            return Ok(None);
+        }
+        self.offset_minutes()?
+            .map(|offset| format_field_value(format_args!("{offset}")))
+            .transpose()
+    }
 }
 
 struct TimezoneOption {
@@ -570,6 +598,195 @@ impl<const N: usize> WifiAutoField for TextField<N> {
     fn is_satisfied(&self) -> Result<bool> {
         Ok(self.text()?.map_or(false, |text| !text.is_empty()))
     }
+
+    fn clear(&self) -> Result<()> {
+        self.flash.borrow_mut().clear()
+    }
+
+    fn value(&self, key: &str) -> Result<Option<FieldValue>> {
+        if key != self.field_name {
+            return Ok(None);
+        }
+        self.text()?
+            .map(|text| format_field_value(format_args!("{text}")))
+            .transpose()
+    }
+}
+
+/// A whole-number input field with range validation, for WiFi provisioning.
+///
+/// Presents a number input box in the captive portal. Submitted values outside
+/// `MIN..=MAX` are rejected; valid values are stored to flash. Use it for polling
+/// intervals, sensor thresholds, and similar settings. [`PortField`] is the
+/// `1..=65535` version for network ports.
+///
+/// Works like [`TextField`]; see the [wifi_auto::fields module example](crate::wifi_auto::fields)
+/// for usage.
+pub struct IntField<const MIN: u32, const MAX: u32> {
+    flash: RefCell<FlashBlock>,
+    field_name: &'static str,
+    label: &'static str,
+    default_value: u32,
+}
+
+// SAFETY: IntField is used in a single-threaded Embassy executor on RP2040/RP2350.
+// There are no interrupts that access this data, and all async operations are cooperative
+// (non-preemptive). The Sync bound is required only because WifiAutoField trait objects
+// are stored in static storage, not because of actual concurrent access.
+unsafe impl<const MIN: u32, const MAX: u32> Sync for IntField<MIN, MAX> {}
+
+/// Static for [`IntField`]. See the [wifi_auto::fields module example](crate::wifi_auto::fields)
+/// for usage.
+pub struct IntFieldStatic<const MIN: u32, const MAX: u32> {
+    cell: StaticCell<IntField<MIN, MAX>>,
+}
+
+impl<const MIN: u32, const MAX: u32> IntFieldStatic<MIN, MAX> {
+    const fn new() -> Self {
+        Self {
+            cell: StaticCell::new(),
+        }
+    }
+}
+
+/// A network port input field (`1..=65535`). See [`IntField`].
+pub type PortField = IntField<1, 65535>;
+
+/// Static for [`PortField`].
+pub type PortFieldStatic = IntFieldStatic<1, 65535>;
+
+impl<const MIN: u32, const MAX: u32> IntField<MIN, MAX> {
+    /// Create static resources for [`IntField`].
+    ///
+    /// See the [wifi_auto::fields module example](crate::wifi_auto::fields) for usage.
+    #[must_use]
+    pub const fn new_static() -> IntFieldStatic<MIN, MAX> {
+        IntFieldStatic::new()
+    }
+
+    /// Initialize a new number input field.
+    ///
+    /// # Parameters
+    /// - `int_field_static`: Static resources for initialization
+    /// - `flash`: Flash block for persistent storage
+    /// - `field_name`: HTML form field name (e.g., "port", "poll_seconds")
+    /// - `label`: HTML label text (e.g., "Port", "Poll interval (s)")
+    /// - `default_value`: Value shown in the form if nothing is saved
+    ///
+    /// # Panics
+    ///
+    /// Panics if `MIN > MAX` or if `default_value` is outside `MIN..=MAX`.
+    ///
+    /// See the [wifi_auto::fields module example](crate::wifi_auto::fields) for usage.
+    pub fn new(
+        int_field_static: &'static IntFieldStatic<MIN, MAX>,
+        flash: FlashBlock,
+        field_name: &'static str,
+        label: &'static str,
+        default_value: u32,
+    ) -> &'static Self {
+        assert!(MIN <= MAX, "IntField requires MIN <= MAX");
+        assert!(
+            (MIN..=MAX).contains(&default_value),
+            "IntField default value must be within MIN..=MAX"
+        );
+        int_field_static.cell.init(Self {
+            flash: RefCell::new(flash),
+            field_name,
+            label,
+            default_value,
+        })
+    }
+
+    /// Load the stored value from flash.
+    ///
+    /// Returns `None` if no value has been configured yet.
+    pub fn value(&self) -> Result<Option<u32>> {
+        self.flash.borrow_mut().load::<u32>()
+    }
+
+    /// Save a new value to flash.
+    ///
+    /// Only writes to flash if the value has changed, avoiding unnecessary flash wear.
+    /// Returns [`Error::FormatError`] if `value` is outside `MIN..=MAX`.
+    pub fn set_value(&self, value: u32) -> Result<()> {
+        if !(MIN..=MAX).contains(&value) {
+            return Err(Error::FormatError);
+        }
+        if self.value()? != Some(value) {
+            self.flash.borrow_mut().save(&value)?;
+        }
+        Ok(())
+    }
+
+    /// Clear the stored value, returning the field to an unconfigured state.
+    pub fn clear(&self) -> Result<()> {
+        self.flash.borrow_mut().clear()
+    }
+}
+
+impl PortField {
+    /// Load the stored port from flash.
+    ///
+    /// Returns `None` if no port has been configured yet.
+    pub fn port(&self) -> Result<Option<u16>> {
+        Ok(self
+            .value()?
+            .map(|port| u16::try_from(port).expect("PortField values fit in u16")))
+    }
+}
+
+impl<const MIN: u32, const MAX: u32> WifiAutoField for IntField<MIN, MAX> {
+    fn render(&self, page: &mut HtmlBuffer) -> Result<()> {
+        info!("WifiAuto field: rendering number input");
+        let current = self.value()?.unwrap_or(self.default_value);
+        FmtWrite::write_fmt(
+            page,
+            format_args!(
+                "<label for=\"{}\">{}:</label>\
+                 <input type=\"number\" id=\"{}\" name=\"{}\" value=\"{}\" \
+                 min=\"{}\" max=\"{}\" step=\"1\" required>",
+                self.field_name, self.label, self.field_name, self.field_name, current, MIN, MAX
+            ),
+        )
+        .map_err(|_| Error::FormatError)?;
+        Ok(())
+    }
+
+    fn parse(&self, form: &FormData<'_>) -> Result<()> {
+        let Some(value) = form.get(self.field_name) else {
+            info!("WifiAuto field: number input missing from submission");
+            return Ok(());
+        };
+        let value = value
+            .trim()
+            .parse::<u32>()
+            .map_err(|_| Error::FormatError)?;
+        self.set_value(value)
+    }
+
+    fn is_satisfied(&self) -> Result<bool> {
+        Ok(self.value()?.is_some())
+    }
+
+    fn clear(&self) -> Result<()> {
+        Self::clear(self)
+    }
+
+    fn value(&self, key: &str) -> Result<Option<FieldValue>> {
+        if key != self.field_name {
+            return Ok(None);
+        }
+        Self::value(self)?
+            .map(|value| format_field_value(format_args!("{value}")))
+            .transpose()
+    }
+}
+
+fn format_field_value(args: core::fmt::Arguments<'_>) -> Result<FieldValue> {
+    let mut value = FieldValue::new();
+    FmtWrite::write_fmt(&mut value, args).map_err(|_| Error::FormatError)?;
+    Ok(value)
 }
 
 fn simple_escape(input: &str) -> String<128> {
@@ -13,8 +13,14 @@ use static_cell::StaticCell;
 use super::credentials::WifiCredentials;
 use crate::Result;
 
+/// Buffer that holds the captive portal page while
+/// [`WifiAutoField::render`] appends to it.
 pub type HtmlBuffer = String<16384>;
 
+/// A field's stored value as text, returned by [`WifiAutoField::value`]. Its capacity
+/// matches the longest value the captive portal accepts for one form control.
+pub type FieldValue = String<256>;
+
 /// Traits for custom extra information that [`WifiAuto`](crate::wifi_auto::WifiAuto) can ask the
 /// user for on its setup web page. Supports HTML snippets.
 ///
@@ -22,13 +28,66 @@ pub type HtmlBuffer = String<16384>;
 /// during the captive portal setup. Fields must be `Sync` since they're shared across
 /// async tasks.
 ///
-/// See the [wifi_auto::fields module example](crate::wifi_auto::fields) for usage.
+/// Each field renders its own label and form control, so a custom field can use any
+/// HTML element (text input, `<select>` dropdown, checkbox, and so on). The portal
+/// calls [`render`](Self::render) for each field in order, inside the setup form.
+///
+/// See the [wifi_auto::fields module example](crate::wifi_auto::fields) for the built-in
+/// fields.
+///
+/// # Example
+///
+/// A custom checkbox field:
+///
+/// ```rust,no_run
+/// # #![no_std]
+/// # #![no_main]
+/// # use panic_probe as _;
+/// use core::fmt::Write;
+/// use device_envoy::{Error, Result};
+/// use device_envoy::wifi_auto::{FormData, HtmlBuffer, WifiAutoField};
+/// use portable_atomic::{AtomicBool, Ordering};
+///
+/// struct DimAtNightField {
+///     enabled: AtomicBool,
+/// }
+///
+/// impl WifiAutoField for DimAtNightField {
+///     fn render(&self, page: &mut HtmlBuffer) -> Result<()> {
+///         let checked = if self.enabled.load(Ordering::Relaxed) {
+///             " checked"
+///         } else {
+///             ""
+///         };
+///         write!(
+///             page,
+///             "<label class=\"toggle\">\
+///              <input type=\"checkbox\" name=\"dim_at_night\"{checked}>Dim at night\
+///              </label>"
+///         )
+///         .map_err(|_| Error::FormatError)
+///     }
+///
+///     fn parse(&self, form: &FormData<'_>) -> Result<()> {
+///         // Browsers omit unchecked checkboxes from the submission.
+///         let enabled = form.get("dim_at_night").is_some();
+///         self.enabled.store(enabled, Ordering::Relaxed);
+///         Ok(())
+///     }
+/// }
+///
+/// static DIM_AT_NIGHT_FIELD: DimAtNightField = DimAtNightField {
+///     enabled: AtomicBool::new(false),
+/// };
+/// // Pass `&DIM_AT_NIGHT_FIELD` in the `custom_fields` array of `WifiAuto::new`.
+/// ```
 ///
 /// # Methods
 ///
 /// - [`render`](Self::render): Generate HTML form elements for the captive portal
 /// - [`parse`](Self::parse): Parse and save submitted form data
 /// - [`is_satisfied`](Self::is_satisfied): Check if field has valid configuration
+/// - [`value`](Self::value): Read the stored value by form key
 pub trait WifiAutoField: Sync {
     /// Render HTML form elements for this field.
     ///
@@ -56,8 +115,32 @@ pub trait WifiAutoField: Sync {
     fn is_satisfied(&self) -> Result<bool> {
         Ok(true)
     }
+
+    /// Return this field's stored value as text, if `key` is the field's form control name.
+    ///
+    /// Returns `None` if `key` names some other field or nothing is stored yet. The default
+    /// implementation always returns `None`; override it so
+    /// [`WifiAuto::field_value`](crate::wifi_auto::WifiAuto::field_value) can find the field.
+    ///
+    /// See the [wifi_auto::fields module example](crate::wifi_auto::fields) for usage.
+    fn value(&self, key: &str) -> Result<Option<FieldValue>> {
+        let _ = key;
+        Ok(None)
+    }
+
+    /// Forget this field's stored value, so the captive portal asks for it again.
+    ///
+    /// Called for every field when the button is held for a factory reset; see
+    /// [`WifiAuto`](crate::wifi_auto::WifiAuto#resetting-with-the-button). The default
+    /// implementation does nothing; override it if the field stores anything.
+    fn clear(&self) -> Result<()> {
+        Ok(())
+    }
 }
 
+/// Form values submitted from the captive portal, passed to [`WifiAutoField::parse`].
+///
+/// See the [`WifiAutoField` trait example](WifiAutoField) for usage.
 pub struct FormData<'a> {
     params: &'a FormMap,
 }
@@ -67,6 +150,12 @@ impl<'a> FormData<'a> {
         Self { params }
     }
 
+    /// Look up the URL-decoded value submitted for the form control named `key`.
+    ///
+    /// Returns `None` if the submission did not include `key`.
+    ///
+    /// See the [`WifiAutoField` trait example](WifiAutoField) for usage.
+    #[must_use]
     pub fn get(&self, key: &str) -> Option<&str> {
         self.params
             .iter()
@@ -76,7 +165,7 @@ impl<'a> FormData<'a> {
 }
 
 type FormKey = String<32>;
-type FormValue = String<256>;
+type FormValue = FieldValue;
 type FormMap = FnvIndexMap<FormKey, FormValue, 32>;
 
 static CREDENTIAL_CHANNEL: Channel<CriticalSectionRawMutex, WifiCredentials, 1> = Channel::new();
@@ -92,6 +181,37 @@ static FORM_STATE: Mutex<CriticalSectionRawMutex, RefCell<FormState>> =
 static FORM_FIELDS: Mutex<CriticalSectionRawMutex, RefCell<&'static [&'static dyn WifiAutoField]>> =
     Mutex::new(RefCell::new(&[]));
 
+/// Most scanned network names offered as SSID suggestions.
+const MAX_SCANNED_SSIDS: usize = 16;
+
+/// Room for a 32-byte SSID after HTML escaping, where each byte may become `&quot;`.
+const ESCAPED_SSID_CAPACITY: usize = 6 * 32;
+
+// Network names found by the scan before the portal's access point started.
+static SCANNED_SSIDS: Mutex<
+    CriticalSectionRawMutex,
+    RefCell<heapless::Vec<String<32>, MAX_SCANNED_SSIDS>>,
+> = Mutex::new(RefCell::new(heapless::Vec::new()));
+
+/// Record a network name found by a scan, to suggest in the portal's SSID input.
+///
+/// Empty (hidden) and repeated names are skipped, as are names beyond the first
+/// `MAX_SCANNED_SSIDS`.
+pub(crate) fn add_scanned_ssid(ssid: &str) {
+    let Ok(ssid) = String::try_from(ssid) else {
+        return;
+    };
+    if ssid.is_empty() {
+        return;
+    }
+    SCANNED_SSIDS.lock(|scanned_ssids| {
+        let mut scanned_ssids = scanned_ssids.borrow_mut();
+        if !scanned_ssids.contains(&ssid) {
+            scanned_ssids.push(ssid).ok();
+        }
+    });
+}
+
 pub async fn collect_credentials(
     stack: &'static Stack<'static>,
     spawner: Spawner,
@@ -237,7 +357,7 @@ fn generate_config_page(state: &FormState, fields: &[&'static dyn WifiAutoField]
     let ssid = state
         .defaults
         .as_ref()
-        .map(|creds| escape_html::<160>(creds.ssid.as_str()))
+        .map(|creds| escape_html::<ESCAPED_SSID_CAPACITY>(creds.ssid.as_str()))
         .unwrap_or_else(heapless::String::new);
     let password = state
         .defaults
@@ -280,12 +400,40 @@ fn generate_config_page(state: &FormState, fields: &[&'static dyn WifiAutoField]
              <p>Enter your WiFi network credentials:</p>\
              <form method=\"POST\" action=\"/\">\
                 <label for=\"ssid\">WiFi Network Name (SSID):</label>\
-                <input type=\"text\" id=\"ssid\" name=\"ssid\" value=\"{}\" required>\
-                <label for=\"password\">Password:</label>\
+                <input type=\"text\" id=\"ssid\" name=\"ssid\" value=\"{}\" list=\"scanned-ssids\" required>\
+",
+        ssid
+    )
+    .ok();
+
+    // Offer scanned networks as suggestions; with none, the input stays a plain text box,
+    // and hidden networks can always be typed.
+    SCANNED_SSIDS.lock(|scanned_ssids| {
+        let scanned_ssids = scanned_ssids.borrow();
+        if scanned_ssids.is_empty() {
+            return;
+        }
+        page.push_str("<datalist id=\"scanned-ssids\">")
+            .expect("page HTML exceeds capacity");
+        for scanned_ssid in scanned_ssids.iter() {
+            write!(
+                page,
+                "<option value=\"{}\">",
+                escape_html::<ESCAPED_SSID_CAPACITY>(scanned_ssid.as_str())
+            )
+            .ok();
+        }
+        page.push_str("</datalist>")
+            .expect("page HTML exceeds capacity");
+    });
+
+    write!(
+        page,
+        "<label for=\"password\">Password:</label>\
                 <input type=\"password\" id=\"password\" name=\"password\" value=\"{}\" required>\
                 <label class=\"toggle\"><input type=\"checkbox\" onclick=\"togglePasswordVisibility()\">Show password</label>\
 ",
-        ssid, password
+        password
     )
     .ok();
 
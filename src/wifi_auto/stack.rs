@@ -9,7 +9,7 @@
 )]
 
 use core::cell::{RefCell, UnsafeCell};
-use cyw43::JoinOptions;
+use cyw43::{JoinOptions, ScanOptions};
 use cyw43_pio::{DEFAULT_CLOCK_DIVIDER, PioSpi};
 use defmt::*;
 use embassy_executor::Spawner;
@@ -29,6 +29,7 @@ use embassy_time::Timer;
 use portable_atomic::{AtomicBool, Ordering};
 use static_cell::StaticCell;
 
+use super::WifiPowerMode;
 use super::credentials::WifiCredentials;
 use super::dhcp::dhcp_server_task;
 use crate::flash_array::FlashBlock;
@@ -43,6 +44,8 @@ pub enum WifiEvent {
     CaptivePortalReady,
     /// Network stack is initialized in client mode and DHCP is configured
     ClientReady,
+    /// Network stack is initialized in access-point-only mode and the DHCP server is running
+    AccessPointReady,
 }
 
 #[derive(Clone, Copy, serde::Serialize, serde::Deserialize, PartialEq, Eq)]
@@ -74,6 +77,11 @@ pub enum WifiMode {
     CaptivePortal,
     /// Connect to WiFi network using provisioned credentials
     ClientConfigured(WifiCredentials),
+    /// Host a persistent access point (empty password for an open network)
+    AccessPoint {
+        ssid: &'static str,
+        password: &'static str,
+    },
 }
 
 /// Single-threaded once-storage for network stack
@@ -148,6 +156,7 @@ pub trait WifiPio: Instance {
         dma: Peri<'static, AnyChannel>,
         mode: WifiMode,
         captive_portal_ssid: &'static str,
+        power_mode: WifiPowerMode,
         wifi_events: &'static WifiEvents,
         stack_storage: &'static StackStorage,
     );
@@ -160,7 +169,7 @@ pub struct WifiStatic {
     wifi_cell: StaticCell<Wifi>,
 }
 
-/// A device abstraction that manages WiFi connectivity and network stack in both captive portal and client modes.
+/// A device abstraction that manages WiFi connectivity and network stack in captive portal, client, and access-point-only modes.
 ///
 /// See the [WifiAuto struct example](crate::wifi_auto::WifiAuto) for usage.
 pub struct Wifi {
@@ -189,7 +198,7 @@ impl Wifi {
     ///
     /// This provides access to the Embassy network stack for TCP/UDP operations.
     /// The stack will be configured differently depending on the WiFi mode:
-    /// - In captive portal mode: static IP 192.168.4.1
+    /// - In captive portal and access-point-only modes: static IP 192.168.4.1
     /// - In client mode: DHCP-assigned IP
     ///
     /// See the [WifiAuto struct example](crate::wifi_auto::WifiAuto) for usage.
@@ -218,6 +227,7 @@ impl Wifi {
         dma: Peri<'static, DMA>,
         credential_store: FlashBlock,
         captive_portal_ssid: &'static str,
+        power_mode: WifiPowerMode,
         spawner: Spawner,
     ) -> &'static Self {
         let mut store_block = credential_store;
@@ -243,6 +253,7 @@ impl Wifi {
             dma,
             mode,
             captive_portal_ssid,
+            power_mode,
             &wifi_static.events,
             &wifi_static.stack,
         );
@@ -253,6 +264,42 @@ impl Wifi {
         })
     }
 
+    /// Bring up a persistent access point and wait for its network stack.
+    ///
+    /// Unlike captive portal mode, the device never switches to client mode and no
+    /// credential store is needed. The stack has static IP 192.168.4.1 and a DHCP server
+    /// hands out 192.168.4.2-254 to clients.
+    pub async fn start_access_point<PIO: WifiPio, DMA: Channel>(
+        wifi_static: &'static WifiStatic,
+        pin_23: Peri<'static, PIN_23>,
+        pin_24: Peri<'static, PIN_24>,
+        pin_25: Peri<'static, PIN_25>,
+        pin_29: Peri<'static, PIN_29>,
+        pio: Peri<'static, PIO>,
+        dma: Peri<'static, DMA>,
+        ssid: &'static str,
+        password: &'static str,
+        power_mode: WifiPowerMode,
+        spawner: Spawner,
+    ) -> &'static Stack<'static> {
+        let dma = dma.into();
+        PIO::spawn_device_loop(
+            spawner,
+            pin_23,
+            pin_24,
+            pin_25,
+            pin_29,
+            pio,
+            dma,
+            WifiMode::AccessPoint { ssid, password },
+            ssid,
+            power_mode,
+            &wifi_static.events,
+            &wifi_static.stack,
+        );
+        wifi_static.stack.get().await
+    }
+
     fn update_state<F>(&self, f: F) -> Result<(), &'static str>
     where
         F: FnOnce(&mut WifiStoredState),
@@ -282,6 +329,14 @@ impl Wifi {
         })
     }
 
+    /// Erase stored credentials and start in captive-portal mode next time.
+    pub fn clear_credentials(&self) -> Result<(), &'static str> {
+        self.update_state(|state| {
+            state.credentials = None;
+            state.start_mode = WifiStartMode::CaptivePortal;
+        })
+    }
+
     /// Return whether credentials currently exist in flash.
     pub fn has_persisted_credentials(&self) -> bool {
         self.read_state(|state| state.credentials.is_some())
@@ -360,6 +415,7 @@ async fn wifi_device_loop_impl<PIO: WifiPio>(
     dma: Peri<'static, AnyChannel>,
     mode: WifiMode,
     captive_portal_ssid: &'static str,
+    power_mode: WifiPowerMode,
     wifi_events: &'static WifiEvents,
     stack_storage: &'static StackStorage,
     spawner: Spawner,
@@ -388,6 +444,7 @@ async fn wifi_device_loop_impl<PIO: WifiPio>(
                 pin_29,
                 pio,
                 dma,
+                power_mode,
                 wifi_events,
                 stack_storage,
                 spawner,
@@ -395,6 +452,25 @@ async fn wifi_device_loop_impl<PIO: WifiPio>(
             )
             .await
         }
+        WifiMode::AccessPoint { ssid, password } => {
+            wifi_device_loop_access_point_impl(
+                pin_23,
+                pin_24,
+                pin_25,
+                pin_29,
+                pio,
+                dma,
+                ssid,
+                password,
+                power_mode,
+                false,
+                WifiEvent::AccessPointReady,
+                wifi_events,
+                stack_storage,
+                spawner,
+            )
+            .await
+        }
     }
 }
 
@@ -416,6 +492,43 @@ async fn wifi_device_loop_captive_portal<PIO: WifiPio>(
         captive_portal_ssid
     );
 
+    // Open network
+    wifi_device_loop_access_point_impl(
+        pin_23,
+        pin_24,
+        pin_25,
+        pin_29,
+        pio,
+        dma,
+        captive_portal_ssid,
+        "",
+        WifiPowerMode::PowerSave,
+        true,
+        WifiEvent::CaptivePortalReady,
+        wifi_events,
+        stack_storage,
+        spawner,
+    )
+    .await
+}
+
+/// Shared access-point implementation for captive portal and access-point-only modes.
+async fn wifi_device_loop_access_point_impl<PIO: WifiPio>(
+    pin_23: Peri<'static, PIN_23>,
+    pin_24: Peri<'static, PIN_24>,
+    pin_25: Peri<'static, PIN_25>,
+    pin_29: Peri<'static, PIN_29>,
+    pio: Peri<'static, PIO>,
+    dma: Peri<'static, AnyChannel>,
+    ssid: &'static str,
+    password: &'static str,
+    power_mode: WifiPowerMode,
+    scan_networks: bool,
+    ready_event: WifiEvent,
+    wifi_events: &'static WifiEvents,
+    stack_storage: &'static StackStorage,
+    spawner: Spawner,
+) -> ! {
     // Initialize WiFi hardware
     let fw = cyw43_firmware::CYW43_43439A0;
     let clm = cyw43_firmware::CYW43_43439A0_CLM;
@@ -440,16 +553,22 @@ async fn wifi_device_loop_captive_portal<PIO: WifiPio>(
     PIO::spawn_wifi_task(spawner, runner);
 
     control.init(clm).await;
-    control
-        .set_power_management(cyw43::PowerManagementMode::PowerSave)
-        .await;
-
-    // Start captive portal mode
-    const CAPTIVE_PORTAL_PASSWORD: &str = ""; // Open network
+    control.set_power_management(power_mode.to_cyw43()).await;
+
+    // Scan before starting the access point, so the portal can suggest nearby networks.
+    if scan_networks {
+        let mut scanner = control.scan(ScanOptions::default()).await;
+        while let Some(bss) = scanner.next().await {
+            let ssid_len = usize::from(bss.ssid_len).min(bss.ssid.len());
+            if let Ok(scanned_ssid) = core::str::from_utf8(&bss.ssid[..ssid_len]) {
+                super::portal::add_scanned_ssid(scanned_ssid);
+            }
+        }
+    }
 
-    info!("Starting captive portal mode: {}", captive_portal_ssid);
+    info!("Starting access point: {}", ssid);
 
-    // Configure static IP for captive portal mode (we are the gateway)
+    // Configure static IP for the access point (we are the gateway)
     let config = Config::ipv4_static(embassy_net::StaticConfigV4 {
         address: embassy_net::Ipv4Cidr::new(embassy_net::Ipv4Address::new(192, 168, 4, 1), 24),
         gateway: Some(embassy_net::Ipv4Address::new(192, 168, 4, 1)),
@@ -471,24 +590,22 @@ async fn wifi_device_loop_captive_portal<PIO: WifiPio>(
 
     unwrap!(spawner.spawn(net_task(runner)));
 
-    // Start captive portal network
-    if CAPTIVE_PORTAL_PASSWORD.is_empty() {
-        control.start_ap_open(captive_portal_ssid, 1).await;
+    // Start access point network
+    if password.is_empty() {
+        control.start_ap_open(ssid, 1).await;
     } else {
-        control
-            .start_ap_wpa2(captive_portal_ssid, CAPTIVE_PORTAL_PASSWORD, 1)
-            .await;
+        control.start_ap_wpa2(ssid, password, 1).await;
     }
 
-    info!("Captive portal mode started! SSID: {}", captive_portal_ssid);
+    info!("Access point started! SSID: {}", ssid);
 
     stack.wait_config_up().await;
 
     if let Some(config) = stack.config_v4() {
-        info!("Captive portal IP Address: {}", config.address);
+        info!("Access point IP Address: {}", config.address);
     }
 
-    // Start DHCP server for captive portal mode
+    // Start DHCP server for access point clients
     let server_ip = embassy_net::Ipv4Address::new(192, 168, 4, 1);
     let netmask = embassy_net::Ipv4Address::new(255, 255, 255, 0);
     let pool_start = embassy_net::Ipv4Address::new(192, 168, 4, 2);
@@ -499,14 +616,11 @@ async fn wifi_device_loop_captive_portal<PIO: WifiPio>(
     )));
 
     info!("DHCP server started (pool: 192.168.4.2-254)");
-    info!(
-        "WiFi captive portal ready - connect to '{}'",
-        captive_portal_ssid
-    );
+    info!("WiFi access point ready - connect to '{}'", ssid);
 
-    // Store stack reference and emit CaptivePortalReady event
+    // Store stack reference and emit the ready event
     stack_storage.init(stack);
-    wifi_events.signal(WifiEvent::CaptivePortalReady);
+    wifi_events.signal(ready_event);
 
     // Keep task alive
     loop {
@@ -522,6 +636,7 @@ async fn wifi_device_loop_client_configured<PIO: WifiPio>(
     pin_29: Peri<'static, PIN_29>,
     pio: Peri<'static, PIO>,
     dma: Peri<'static, AnyChannel>,
+    power_mode: WifiPowerMode,
     wifi_events: &'static WifiEvents,
     stack_storage: &'static StackStorage,
     spawner: Spawner,
@@ -536,6 +651,7 @@ async fn wifi_device_loop_client_configured<PIO: WifiPio>(
         pin_29,
         pio,
         dma,
+        power_mode,
         wifi_events,
         stack_storage,
         spawner,
@@ -553,6 +669,7 @@ async fn wifi_device_loop_client_impl<PIO: WifiPio>(
     pin_29: Peri<'static, PIN_29>,
     pio: Peri<'static, PIO>,
     dma: Peri<'static, AnyChannel>,
+    power_mode: WifiPowerMode,
     wifi_events: &'static WifiEvents,
     stack_storage: &'static StackStorage,
     spawner: Spawner,
@@ -588,9 +705,6 @@ async fn wifi_device_loop_client_impl<PIO: WifiPio>(
     PIO::spawn_wifi_task(spawner, runner);
 
     control.init(clm).await;
-    control
-        .set_power_management(cyw43::PowerManagementMode::PowerSave)
-        .await;
 
     // Initialize network stack
     let config = Config::dhcpv4(Default::default());
@@ -623,6 +737,9 @@ async fn wifi_device_loop_client_impl<PIO: WifiPio>(
     {
         Ok(_) => {
             info!("WiFi join succeeded");
+            // Power management takes effect once associated with the access point
+            info!("Setting WiFi power mode: {}", power_mode);
+            control.set_power_management(power_mode.to_cyw43()).await;
         }
         Err(err) => {
             info!("WiFi join failed: {:?}", defmt::Debug2Format(&err));
@@ -694,6 +811,7 @@ macro_rules! impl_wifi_pio {
                     dma: Peri<'static, AnyChannel>,
                     mode: WifiMode,
                     captive_portal_ssid: &'static str,
+                    power_mode: WifiPowerMode,
                     wifi_events: &'static WifiEvents,
                     stack_storage: &'static StackStorage,
                 ) {
@@ -706,6 +824,7 @@ macro_rules! impl_wifi_pio {
                         dma,
                         mode,
                         captive_portal_ssid,
+                        power_mode,
                         wifi_events,
                         stack_storage,
                         spawner,
@@ -723,6 +842,7 @@ macro_rules! impl_wifi_pio {
                 dma: Peri<'static, AnyChannel>,
                 mode: WifiMode,
                 captive_portal_ssid: &'static str,
+                power_mode: WifiPowerMode,
                 wifi_events: &'static WifiEvents,
                 stack_storage: &'static StackStorage,
                 spawner: Spawner,
@@ -736,6 +856,7 @@ macro_rules! impl_wifi_pio {
                     dma,
                     mode,
                     captive_portal_ssid,
+                    power_mode,
                     wifi_events,
                     stack_storage,
                     spawner,
@@ -0,0 +1,53 @@
+#![allow(missing_docs)]
+#![cfg(feature = "host")]
+//! Host-level tests for driving stub buttons without hardware.
+
+use device_envoy::button::{Button, ButtonSimulator, PressDuration};
+use embassy_futures::block_on;
+
+#[test]
+fn trigger_virtual_makes_wait_for_press_return() {
+    let mut button = Button::new_virtual();
+    button.trigger_virtual();
+
+    block_on(button.wait_for_press());
+
+    assert!(!button.is_pressed());
+}
+
+#[test]
+fn simulator_reports_short_and_long_presses() {
+    let mut button_simulator = ButtonSimulator::<2>::new();
+
+    button_simulator.press(0);
+    button_simulator.release(0);
+    button_simulator.press(1);
+
+    assert_eq!(
+        block_on(button_simulator.button(0).wait_for_press_duration()),
+        PressDuration::Short
+    );
+    assert_eq!(
+        block_on(button_simulator.button(1).wait_for_press_duration()),
+        PressDuration::Long
+    );
+    assert!(button_simulator.button(1).is_pressed());
+
+    button_simulator.release(1);
+    block_on(button_simulator.button(1).wait_for_release());
+    assert!(!button_simulator.button(1).is_pressed());
+}
+
+#[test]
+fn pressing_a_held_button_queues_nothing() {
+    let mut button_simulator = ButtonSimulator::<1>::new();
+    button_simulator.press(0);
+    button_simulator.press(0);
+    button_simulator.release(0);
+
+    assert_eq!(
+        block_on(button_simulator.button(0).wait_for_press_duration()),
+        PressDuration::Short
+    );
+    assert!(!button_simulator.button(0).is_pressed());
+}
@@ -0,0 +1,47 @@
+#![allow(missing_docs)]
+//! Host-level tests for `Frame1d` helpers.
+
+use device_envoy::led_strip::{Frame1d, colors};
+
+const TILE: Frame1d<3> = Frame1d([colors::RED, colors::GREEN, colors::BLUE]);
+
+#[test]
+fn repeat_copies_tile_into_every_block() {
+    const FRAME: Frame1d<6> = Frame1d::repeat(TILE);
+
+    assert_eq!(
+        FRAME.0,
+        [
+            colors::RED,
+            colors::GREEN,
+            colors::BLUE,
+            colors::RED,
+            colors::GREEN,
+            colors::BLUE
+        ]
+    );
+}
+
+#[test]
+fn repeat_with_offset_shifts_toward_end_and_wraps() {
+    let frame = Frame1d::<6>::repeat_with_offset(TILE, 1);
+
+    assert_eq!(
+        frame.0,
+        [
+            colors::BLUE,
+            colors::RED,
+            colors::GREEN,
+            colors::BLUE,
+            colors::RED,
+            colors::GREEN
+        ]
+    );
+    assert_eq!(Frame1d::<6>::repeat_with_offset(TILE, 4).0, frame.0);
+}
+
+#[test]
+#[should_panic(expected = "tile length must divide frame length exactly")]
+fn repeat_rejects_uneven_tile() {
+    let _ = Frame1d::<5>::repeat(TILE);
+}
@@ -0,0 +1,305 @@
+#![allow(missing_docs)]
+#![cfg(feature = "host")]
+//! Host-level tests for `Frame2d` helpers.
+
+use device_envoy::led_strip::Frame1d;
+use device_envoy::led2d::{Easing, Frame2d, LedLayout, Point, Rectangle, Size};
+use smart_leds::{RGB8, colors};
+
+#[test]
+fn fill_gradient_radial_runs_from_center_to_edge() {
+    let frame = Frame2d::<5, 5>::fill_gradient_radial(colors::WHITE, colors::BLACK);
+
+    assert_eq!(frame[(2, 2)], colors::WHITE);
+    // Corners lie outside the inscribed circle.
+    assert_eq!(frame[(0, 0)], colors::BLACK);
+    assert_eq!(frame[(4, 4)], colors::BLACK);
+    // Edge midpoints are 80% of the way out.
+    assert_eq!(frame[(0, 2)], RGB8::new(51, 51, 51));
+    assert_eq!(frame[(2, 4)], frame[(2, 0)]);
+}
+
+#[test]
+fn fill_gradient_radial_is_symmetric_on_even_frames() {
+    let frame = Frame2d::<4, 4>::fill_gradient_radial(colors::RED, colors::BLUE);
+
+    assert_eq!(frame[(1, 1)], frame[(2, 2)]);
+    assert_eq!(frame[(1, 1)], frame[(1, 2)]);
+    assert_eq!(frame[(0, 1)], frame[(3, 2)]);
+    assert_eq!(frame[(0, 0)], colors::BLUE);
+}
+
+#[test]
+fn fill_rect_colors_only_the_clipped_rectangle() {
+    let mut frame = Frame2d::<4, 3>::new();
+    frame.fill_rect(
+        Rectangle::new(Point::new(2, 1), Size::new(5, 5)),
+        colors::RED,
+    );
+
+    let black = RGB8::new(0, 0, 0);
+    assert_eq!(
+        frame.0,
+        [
+            [black, black, black, black],
+            [black, black, colors::RED, colors::RED],
+            [black, black, colors::RED, colors::RED],
+        ]
+    );
+}
+
+#[test]
+fn fill_circle_is_centered_and_filled() {
+    let mut frame = Frame2d::<5, 5>::new();
+    frame.fill_circle(Point::new(2, 2), 5, colors::GREEN);
+
+    let black = RGB8::new(0, 0, 0);
+    assert_eq!(frame[(2, 2)], colors::GREEN);
+    assert_eq!(frame[(0, 2)], colors::GREEN);
+    assert_eq!(frame[(2, 4)], colors::GREEN);
+    assert_eq!(frame[(0, 0)], black);
+    assert_eq!(frame[(4, 4)], black);
+}
+
+#[test]
+fn draw_border_sets_outer_ring_only() {
+    let mut frame = Frame2d::<4, 3>::new();
+    frame.draw_border(colors::RED);
+
+    assert_eq!(frame[(0, 0)], colors::RED);
+    assert_eq!(frame[(3, 2)], colors::RED);
+    assert_eq!(frame[(1, 0)], colors::RED);
+    assert_eq!(frame[(1, 1)], colors::BLACK);
+    assert_eq!(frame[(2, 1)], colors::BLACK);
+}
+
+#[test]
+fn draw_border_width_clamps_to_half_the_smaller_side() {
+    let mut frame = Frame2d::<6, 5>::new();
+    frame.draw_border_width(colors::RED, 10);
+
+    // min(6, 5) / 2 = 2 rings; the center row's middle pixels stay untouched.
+    assert_eq!(frame[(1, 1)], colors::RED);
+    assert_eq!(frame[(4, 3)], colors::RED);
+    assert_eq!(frame[(2, 2)], colors::BLACK);
+    assert_eq!(frame[(3, 2)], colors::BLACK);
+}
+
+#[test]
+fn checkerboard_alternates_by_column_plus_row() {
+    const FRAME: Frame2d<3, 2> = Frame2d::checkerboard(colors::RED, colors::BLUE);
+
+    assert_eq!(
+        FRAME.0,
+        [
+            [colors::RED, colors::BLUE, colors::RED],
+            [colors::BLUE, colors::RED, colors::BLUE],
+        ]
+    );
+}
+
+#[test]
+fn as_frame1d_row_major_matches_remap_for_row_major_layout() {
+    const ROW_MAJOR: LedLayout<6, 3, 2> =
+        LedLayout::new([(0, 0), (1, 0), (2, 0), (0, 1), (1, 1), (2, 1)]);
+    const _: () = assert!(ROW_MAJOR.is_row_major());
+    const _: () = assert!(!LedLayout::<6, 3, 2>::serpentine_row_major().is_row_major());
+
+    let mut frame = Frame2d::<3, 2>::new();
+    frame[(2, 0)] = colors::RED;
+    frame[(0, 1)] = colors::GREEN;
+    let strip_frame: Frame1d<6> = frame.as_frame1d_row_major();
+
+    assert_eq!(strip_frame.0, ROW_MAJOR.remap(&frame).0);
+    assert_eq!(strip_frame[2], colors::RED);
+    assert_eq!(strip_frame[3], colors::GREEN);
+}
+
+#[test]
+fn as_frame2d_row_major_inverts_as_frame1d_row_major() {
+    let mut strip_frame = Frame1d::<6>::new();
+    strip_frame[2] = colors::RED;
+    strip_frame[3] = colors::GREEN;
+
+    let frame: Frame2d<3, 2> = strip_frame.as_frame2d_row_major();
+    assert_eq!(frame[(2, 0)], colors::RED);
+    assert_eq!(frame[(0, 1)], colors::GREEN);
+    assert_eq!(frame.as_frame1d_row_major::<6>().0, strip_frame.0);
+}
+
+#[test]
+fn center_of_mass_weights_pixels_by_luminance() {
+    assert_eq!(Frame2d::<12, 4>::new().center_of_mass(), None);
+
+    let mut frame = Frame2d::<12, 4>::new();
+    frame.fill_rect(
+        Rectangle::new(Point::new(4, 1), Size::new(4, 2)),
+        colors::WHITE,
+    );
+    assert_eq!(frame.center_of_mass(), Some((5.5, 1.5)));
+
+    let mut frame = Frame2d::<12, 4>::new();
+    frame[(0, 0)] = colors::WHITE;
+    frame[(10, 0)] = RGB8::new(0, 0, 255);
+    let (x, y) = frame.center_of_mass().expect("frame has lit pixels");
+    assert!(x > 0.0 && x < 1.0, "the bright pixel dominates, got {x}");
+    assert!(y.abs() < f32::EPSILON);
+}
+
+#[test]
+fn luminance_uses_integer_perceptual_weights() {
+    let mut frame = Frame2d::<3, 2>::new();
+    frame[(0, 0)] = colors::WHITE;
+    frame[(1, 0)] = RGB8::new(255, 0, 0);
+    frame[(2, 1)] = RGB8::new(0, 0, 255);
+
+    assert_eq!(frame.luminance(), [[255, 76, 0], [0, 0, 29]]);
+    assert_eq!(frame.average_luminance(), 60); // (255 + 76 + 29) / 6
+    assert_eq!(frame.max_luminance(), 255);
+    assert_eq!(Frame2d::<3, 2>::new().max_luminance(), 0);
+}
+
+#[test]
+fn row_and_col_iters_walk_the_grid() {
+    let mut frame = Frame2d::<3, 2>::new();
+    for pixel in frame.col_iter_mut(1) {
+        *pixel = colors::RED;
+    }
+    if let Some(bottom_row) = frame.row_iter_mut().last() {
+        bottom_row[2] = colors::BLUE;
+    }
+
+    let black = RGB8::new(0, 0, 0);
+    let rows: Vec<[RGB8; 3]> = frame.row_iter().copied().collect();
+    assert_eq!(
+        rows,
+        [
+            [black, colors::RED, black],
+            [black, colors::RED, colors::BLUE]
+        ]
+    );
+    let right_column: Vec<RGB8> = frame.col_iter(2).copied().collect();
+    assert_eq!(right_column, [black, colors::BLUE]);
+}
+
+#[test]
+fn saturate_and_desaturate_keep_hue_and_value() {
+    let muted = RGB8::new(200, 150, 100);
+    let gray = RGB8::new(80, 80, 80);
+    let mut frame = Frame2d::<2, 1>::new();
+    frame[(0, 0)] = muted;
+    frame[(1, 0)] = gray;
+
+    assert_eq!(frame.desaturate(255).0, frame.0);
+    assert_eq!(frame.saturate(0).0, frame.0);
+    assert_eq!(
+        frame.desaturate(0).0,
+        [[RGB8::new(200, 200, 200), RGB8::new(80, 80, 80)]]
+    );
+    assert_eq!(frame.desaturate(127)[(0, 0)], RGB8::new(200, 176, 151));
+    assert_eq!(frame.saturate(255).0, [[RGB8::new(200, 100, 0), gray]]);
+}
+
+#[test]
+fn get_returns_none_outside_the_frame() {
+    let mut frame = Frame2d::<3, 2>::new();
+    frame[(2, 1)] = colors::RED;
+
+    assert_eq!(frame.get(2, 1), Some(&colors::RED));
+    assert_eq!(frame.get(0, 0), Some(&RGB8::new(0, 0, 0)));
+    assert_eq!(frame.get(3, 0), None);
+    assert_eq!(frame.get(0, 2), None);
+
+    *frame.get_mut(1, 0).expect("pixel is inside the frame") = colors::BLUE;
+    assert_eq!(frame[(1, 0)], colors::BLUE);
+    assert!(frame.get_mut(usize::MAX, 1).is_none());
+}
+
+#[test]
+fn blur_averages_neighbors_with_clamped_edges() {
+    let mut frame = Frame2d::<5, 1>::new();
+    frame[(2, 0)] = RGB8::new(255, 30, 0);
+    frame.blur(1);
+    assert_eq!(
+        frame.0,
+        [[
+            RGB8::new(0, 0, 0),
+            RGB8::new(85, 10, 0),
+            RGB8::new(85, 10, 0),
+            RGB8::new(85, 10, 0),
+            RGB8::new(0, 0, 0),
+        ]]
+    );
+
+    // The edge pixel repeats past the end, so it keeps two of the three samples.
+    let mut edge = Frame2d::<3, 1>::new();
+    edge[(0, 0)] = RGB8::new(90, 90, 90);
+    edge.blur(1);
+    assert_eq!(edge[(0, 0)], RGB8::new(60, 60, 60));
+    assert_eq!(edge[(1, 0)], RGB8::new(30, 30, 30));
+
+    // Both passes run, and a uniform frame is unchanged.
+    let mut dot = Frame2d::<3, 3>::new();
+    dot[(1, 1)] = RGB8::new(90, 0, 0);
+    dot.blur(1);
+    assert!(
+        dot.iter()
+            .flatten()
+            .all(|pixel| *pixel == RGB8::new(10, 0, 0))
+    );
+    let mut uniform = Frame2d::<4, 3>::filled(colors::ORANGE);
+    uniform.blur(7);
+    assert_eq!(uniform.0, Frame2d::<4, 3>::filled(colors::ORANGE).0);
+}
+
+#[test]
+fn equal_to_and_diff_compare_pixels() {
+    let mut frame = Frame2d::<3, 2>::filled(colors::BLUE);
+    let expected = frame;
+    assert!(frame.equal_to(&expected));
+    assert_eq!(frame, expected);
+    assert_eq!(frame.diff(&expected), Frame2d::new());
+
+    frame[(1, 1)] = colors::GREEN;
+    assert!(!frame.equal_to(&expected));
+    assert_ne!(frame, expected);
+    let mut changed = Frame2d::<3, 2>::new();
+    changed[(1, 1)] = RGB8::new(255, 0, 0);
+    assert_eq!(frame.diff(&expected), changed);
+}
+
+#[test]
+fn easing_curves_keep_endpoints_and_bend_the_middle() {
+    for easing in [
+        Easing::Linear,
+        Easing::EaseIn,
+        Easing::EaseOut,
+        Easing::EaseInOut,
+    ] {
+        assert_eq!(easing.apply(0), 0);
+        assert_eq!(easing.apply(255), 255);
+        assert!((1..=255).all(|amount| easing.apply(amount - 1) <= easing.apply(amount)));
+    }
+    assert_eq!(Easing::Linear.apply(64), 64);
+    assert!(Easing::EaseIn.apply(64) < 64);
+    assert!(Easing::EaseOut.apply(64) > 64);
+    assert!(Easing::EaseInOut.apply(64) < 64);
+    assert!(Easing::EaseInOut.apply(191) > 191);
+}
+
+#[test]
+fn tween_blends_each_keyframe_into_the_next_and_loops() {
+    let black = Frame2d::<2, 2>::new();
+    let red = Frame2d::<2, 2>::filled(RGB8::new(255, 0, 0));
+    assert_eq!(black.lerp(red, 0), black);
+    assert_eq!(black.lerp(red, 255), red);
+
+    let frames: Vec<_> = Frame2d::tween(&[black, red], 4, Easing::Linear).collect();
+    let reds: Vec<u8> = frames.iter().map(|frame| frame[(1, 1)].r).collect();
+    assert_eq!(reds, [0, 63, 127, 191, 255, 192, 128, 64]);
+
+    let eased: Vec<_> = Frame2d::tween(&[black, red], 4, Easing::EaseIn).collect();
+    assert_eq!(eased[0], black);
+    assert_eq!(eased[4], red);
+    assert!(eased[1][(0, 0)].r < frames[1][(0, 0)].r);
+}
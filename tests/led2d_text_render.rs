@@ -1,7 +1,12 @@
 #![allow(missing_docs)]
 #![cfg(feature = "host")]
 
-use device_envoy::led2d::{Frame2d, Led2dFont, render_text_to_frame};
+use device_envoy::Error;
+use device_envoy::led2d::{
+    Frame2d, Led2dFont, Point, marquee_frame_count, render_rainbow_marquee_frame,
+    render_text_to_frame, render_text_to_frame_at, render_text_to_frame_vertical,
+    render_text_to_frame_with_bounds,
+};
 use png::{BitDepth, ColorType, Decoder, Encoder};
 use smart_leds::{RGB8, colors};
 use std::fs::File;
@@ -55,6 +60,172 @@ fn font3x4_on_12x4_no_colors_defaults_to_white() {
     run_render_test::<12, 4>("font3x4_12x4_white", Led2dFont::Font3x4Trim, "RUST", &[]);
 }
 
+#[test]
+fn text_bounds_enclose_only_the_rendered_text() {
+    let font = Led2dFont::Font3x4Trim;
+    let spacing_reduction = font.spacing_reduction();
+
+    // A pixel lit before rendering must not widen the bounds.
+    let mut frame = Frame2d::<12, 4>::new();
+    frame[(11, 3)] = colors::BLUE;
+    let bounds = render_text_to_frame_with_bounds(
+        &mut frame,
+        &font.to_font(),
+        "RU",
+        &[colors::RED],
+        spacing_reduction,
+    )
+    .expect("render succeeds")
+    .expect("text is visible");
+    assert_eq!(frame[(11, 3)], colors::BLUE);
+
+    let mut text_only = Frame2d::<12, 4>::new();
+    render_text_to_frame(
+        &mut text_only,
+        &font.to_font(),
+        "RU",
+        &[colors::RED],
+        spacing_reduction,
+        false,
+    )
+    .expect("render succeeds");
+    let is_lit = |x_index: i32, y_index: i32| {
+        let x_index = usize::try_from(x_index).expect("x is non-negative");
+        let y_index = usize::try_from(y_index).expect("y is non-negative");
+        text_only[(x_index, y_index)] == colors::RED
+    };
+    let bottom_right = bounds.bottom_right().expect("bounds are not empty");
+    for y_index in 0..4 {
+        for x_index in 0..12 {
+            if is_lit(x_index, y_index) {
+                assert!(bounds.contains(Point::new(x_index, y_index)));
+            }
+        }
+    }
+    // Every edge of the rectangle touches a lit pixel, so the bounds are tight.
+    assert!((0..4).any(|y_index| is_lit(bounds.top_left.x, y_index)));
+    assert!((0..4).any(|y_index| is_lit(bottom_right.x, y_index)));
+    assert!((0..12).any(|x_index| is_lit(x_index, bounds.top_left.y)));
+    assert!((0..12).any(|x_index| is_lit(x_index, bottom_right.y)));
+    assert!(
+        bottom_right.x < 6,
+        "two 3-pixel glyphs stay in the first 6 columns"
+    );
+}
+
+#[test]
+fn text_bounds_are_none_when_nothing_visible_is_drawn() {
+    let font = Led2dFont::Font3x4Trim;
+    let mut frame = Frame2d::<12, 4>::new();
+    frame[(0, 0)] = colors::BLUE;
+    let bounds = render_text_to_frame_with_bounds(
+        &mut frame,
+        &font.to_font(),
+        "  ",
+        &[],
+        font.spacing_reduction(),
+    )
+    .expect("render succeeds");
+    assert_eq!(bounds, None);
+}
+
+#[test]
+fn vertical_text_is_horizontal_text_rotated_clockwise() {
+    let font = Led2dFont::Font3x4Trim;
+    let text_colors = [colors::RED, colors::GREEN];
+
+    let mut horizontal = Frame2d::<12, 4>::new();
+    render_text_to_frame(
+        &mut horizontal,
+        &font.to_font(),
+        "RUS",
+        &text_colors,
+        font.spacing_reduction(),
+        false,
+    )
+    .expect("render succeeds");
+    let mut vertical = Frame2d::<4, 12>::new();
+    render_text_to_frame_vertical(
+        &mut vertical,
+        &font.to_font(),
+        "RUS",
+        &text_colors,
+        font.spacing_reduction(),
+    )
+    .expect("render succeeds");
+
+    assert!(
+        horizontal
+            .0
+            .iter()
+            .flatten()
+            .any(|pixel| *pixel != RGB8::default())
+    );
+    for y_index in 0..4 {
+        for x_index in 0..12 {
+            assert_eq!(
+                vertical[(3 - y_index, x_index)],
+                horizontal[(x_index, y_index)]
+            );
+        }
+    }
+}
+
+#[test]
+fn vertical_text_errors_when_font_is_taller_than_panel_width() {
+    let font = Led2dFont::Font4x6;
+    let mut frame = Frame2d::<4, 12>::new();
+    let result = render_text_to_frame_vertical(
+        &mut frame,
+        &font.to_font(),
+        "RU",
+        &[],
+        font.spacing_reduction(),
+    );
+    assert!(matches!(result, Err(Error::TextDoesNotFit)));
+}
+
+#[test]
+fn chained_text_runs_match_single_run() {
+    let font = Led2dFont::Font3x4Trim;
+    let spacing_reduction = font.spacing_reduction();
+
+    let mut expected = Frame2d::<12, 4>::new();
+    render_text_to_frame(
+        &mut expected,
+        &font.to_font(),
+        "RUST",
+        &[colors::RED, colors::RED, colors::GREEN, colors::GREEN],
+        spacing_reduction,
+        false,
+    )
+    .expect("render succeeds");
+
+    let mut chained = Frame2d::<12, 4>::new();
+    let cursor = render_text_to_frame_at(
+        &mut chained,
+        &font.to_font(),
+        "RU",
+        &[colors::RED],
+        spacing_reduction,
+        Point::zero(),
+    )
+    .expect("render succeeds");
+    assert_eq!(cursor, Point::new(6, 0));
+    let cursor = render_text_to_frame_at(
+        &mut chained,
+        &font.to_font(),
+        "ST",
+        &[colors::GREEN],
+        spacing_reduction,
+        cursor,
+    )
+    .expect("render succeeds");
+    assert_eq!(cursor, Point::new(12, 0));
+
+    assert_eq!(chained.0, expected.0);
+}
+
 fn run_render_test<const W: usize, const H: usize>(
     name: &str,
     font: Led2dFont,
@@ -62,7 +233,7 @@ fn run_render_test<const W: usize, const H: usize>(
     colors: &[RGB8],
 ) {
     let mut frame: Frame2d<W, H> = Frame2d::new();
-    render_text_to_frame(&mut frame, &font.to_font(), text, colors, (0, 0))
+    render_text_to_frame(&mut frame, &font.to_font(), text, colors, (0, 0), false)
         .expect("render must succeed");
 
     if let Some(dir) = generation_dir() {
@@ -97,7 +268,7 @@ fn run_render_test_heap<const W: usize, const H: usize>(
     let frame_ptr = frame_box.as_mut_ptr() as *mut [[RGB8; W]; H];
     let frame_ref: &mut Frame2d<W, H> = unsafe { &mut *(frame_ptr as *mut Frame2d<W, H>) };
 
-    render_text_to_frame(frame_ref, &font.to_font(), text, colors, (0, 0))
+    render_text_to_frame(frame_ref, &font.to_font(), text, colors, (0, 0), false)
         .expect("render must succeed");
 
     if let Some(dir) = generation_dir() {
@@ -173,3 +344,117 @@ fn frame_pixels<const W: usize, const H: usize>(frame: &Frame2d<W, H>) -> Vec<u8
 fn four_colors() -> [RGB8; 4] {
     [colors::RED, colors::GREEN, colors::BLUE, colors::YELLOW]
 }
+
+#[test]
+fn font3x4_on_3_wide_panel_renders_one_full_glyph() {
+    // Same font and spacing calls that `led2d!`'s generated `write_text_to_frame` makes.
+    let font_variant = Led2dFont::Font3x4Trim;
+    assert_eq!(font_variant.spacing_reduction(), (0, 0));
+    let mut frame = Frame2d::<3, 4>::new();
+    render_text_to_frame(
+        &mut frame,
+        &font_variant.to_font(),
+        "AB",
+        &[colors::RED],
+        font_variant.spacing_reduction(),
+        false,
+    )
+    .expect("render must succeed");
+
+    // "A" fills the 3x4 cell; "B" starts at column 3 and is clipped entirely.
+    let (on, off) = (colors::RED, RGB8::new(0, 0, 0));
+    assert_eq!(
+        frame.0,
+        [[on, on, on], [on, off, on], [on, on, on], [on, off, on],]
+    );
+}
+
+#[test]
+fn rainbow_marquee_scrolls_in_from_the_right_in_color() {
+    let font_variant = Led2dFont::Font3x4Trim;
+    let font = font_variant.to_font();
+    let spacing_reduction = font_variant.spacing_reduction();
+    let marquee_frame = |frame_index| {
+        let mut frame = Frame2d::<12, 4>::new();
+        render_rainbow_marquee_frame(&mut frame, &font, "RUST", spacing_reduction, frame_index, 0);
+        frame
+    };
+    let is_lit = |frame: &Frame2d<12, 4>, x: usize, y: usize| frame[(x, y)] != RGB8::new(0, 0, 0);
+
+    assert_eq!(
+        marquee_frame_count(&font, "RUST", spacing_reduction, 12),
+        4 * 3 + 12
+    );
+    assert_eq!(marquee_frame(0).0, Frame2d::<12, 4>::new().0);
+
+    // After 12 frames the text has reached the left edge, laid out like static text.
+    let mut static_text = Frame2d::<12, 4>::new();
+    render_text_to_frame(
+        &mut static_text,
+        &font,
+        "RUST",
+        &[colors::WHITE],
+        spacing_reduction,
+        false,
+    )
+    .expect("render succeeds");
+    let arrived = marquee_frame(12);
+    let one_step_later = marquee_frame(13);
+    for y in 0..4 {
+        for x in 0..12 {
+            assert_eq!(is_lit(&arrived, x, y), is_lit(&static_text, x, y));
+            if x > 0 {
+                assert_eq!(is_lit(&one_step_later, x - 1, y), is_lit(&arrived, x, y));
+            }
+        }
+    }
+
+    // With no hue motion, column 0 sits at hue 0 (red).
+    let first_lit_row = (0..4)
+        .find(|y| is_lit(&arrived, 0, *y))
+        .expect("R lights its left column");
+    assert_eq!(arrived[(0, first_lit_row)], RGB8::new(255, 0, 0));
+}
+
+#[test]
+fn proportional_text_packs_glyphs_one_column_apart() {
+    let font = Led2dFont::Font5x8.to_font();
+    let mut monospace = Frame2d::<24, 8>::new();
+    render_text_to_frame(
+        &mut monospace,
+        &font,
+        "iiii",
+        &[colors::WHITE],
+        (0, 0),
+        false,
+    )
+    .expect("render succeeds");
+    let mut proportional = Frame2d::<24, 8>::new();
+    render_text_to_frame(
+        &mut proportional,
+        &font,
+        "iiii",
+        &[colors::WHITE],
+        (0, 0),
+        true,
+    )
+    .expect("render succeeds");
+
+    let monospace_columns = lit_columns(&monospace);
+    let proportional_columns = lit_columns(&proportional);
+    assert_eq!(proportional_columns.first(), Some(&0));
+    assert!(proportional_columns.last() < monospace_columns.last());
+    // Four runs of ink, each separated by exactly one blank column.
+    let gaps: Vec<usize> = proportional_columns
+        .windows(2)
+        .map(|pair| pair[1] - pair[0] - 1)
+        .filter(|&gap| gap > 0)
+        .collect();
+    assert_eq!(gaps, [1, 1, 1]);
+}
+
+fn lit_columns<const W: usize, const H: usize>(frame: &Frame2d<W, H>) -> Vec<usize> {
+    (0..W)
+        .filter(|&x| (0..H).any(|y| frame[(x, y)] != RGB8::default()))
+        .collect()
+}
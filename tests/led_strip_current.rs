@@ -0,0 +1,14 @@
+#![allow(missing_docs)]
+use device_envoy::led_strip::Current;
+
+#[test]
+fn max_brightness_scales_with_budget() {
+    assert_eq!(Current::Milliamps(250).max_brightness(48 * 60), 22);
+    assert_eq!(Current::Unlimited.max_brightness(48 * 60), 255);
+}
+
+#[test]
+#[should_panic(expected = "MAX_BRIGHTNESS would be 0")]
+fn max_brightness_rejects_budget_that_rounds_to_zero() {
+    let _ = Current::Milliamps(1).max_brightness(300 * 60);
+}
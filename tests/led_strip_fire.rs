@@ -0,0 +1,59 @@
+#![allow(missing_docs)]
+#![cfg(feature = "host")]
+use device_envoy::led_strip::{FireHeat, heat_to_color};
+use device_envoy::led2d::{Frame2d, render_fire_frame};
+use smart_leds::RGB8;
+
+#[test]
+fn heat_palette_runs_black_red_yellow_white() {
+    assert_eq!(heat_to_color(0), RGB8::new(0, 0, 0));
+    assert_eq!(heat_to_color(80), RGB8::new(240, 0, 0));
+    assert_eq!(heat_to_color(160), RGB8::new(255, 224, 0));
+    let hottest = heat_to_color(255);
+    assert_eq!((hottest.r, hottest.g), (255, 255));
+    assert!(hottest.b > 200);
+}
+
+#[test]
+fn fire_without_sparks_stays_dark() {
+    let mut fire = FireHeat::<16>::new(1);
+    for _ in 0..50 {
+        fire.step(55, 0);
+    }
+    assert_eq!(fire.heat(), &[0; 16]);
+    assert!(
+        fire.to_frame()
+            .iter()
+            .all(|pixel| *pixel == RGB8::new(0, 0, 0))
+    );
+}
+
+#[test]
+fn sparks_heat_the_base_and_rise() {
+    let mut fire = FireHeat::<16>::new(12345);
+    for _ in 0..30 {
+        fire.step(20, 255);
+    }
+    let heat = fire.heat();
+    assert!(heat[..7].iter().any(|&cell| cell > 100));
+    assert!(heat[7..].iter().any(|&cell| cell > 0));
+}
+
+#[test]
+fn fire_frame_puts_each_flame_base_on_bottom_row() {
+    let mut columns = [FireHeat::<4>::new(7); 2];
+    columns[1] = FireHeat::new(99);
+    for _ in 0..10 {
+        columns[1].step(0, 255);
+    }
+    let mut frame = Frame2d::<2, 4>::new();
+    render_fire_frame(&mut frame, &columns);
+    for y_index in 0..4 {
+        assert_eq!(frame.0[y_index][0], RGB8::new(0, 0, 0));
+        assert_eq!(
+            frame.0[y_index][1],
+            heat_to_color(columns[1].heat()[3 - y_index])
+        );
+    }
+    assert_ne!(frame.0[3][1], RGB8::new(0, 0, 0));
+}
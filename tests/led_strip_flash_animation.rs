@@ -0,0 +1,71 @@
+#![allow(missing_docs)]
+#![cfg(feature = "host")]
+use device_envoy::Error;
+use device_envoy::led_strip::flash_animation::{
+    MAX_ENCODED_LEN, decode_animation, encode_animation, encoded_len,
+};
+use device_envoy::led_strip::{Frame1d, colors};
+use embassy_time::Duration;
+
+fn sample_frames() -> [(Frame1d<4>, Duration); 2] {
+    let mut sparkle = Frame1d::<4>::new();
+    sparkle[1] = colors::BLUE;
+    sparkle[3] = colors::RED;
+    [
+        (Frame1d::filled(colors::ORANGE), Duration::from_millis(300)),
+        (sparkle, Duration::from_micros(1_500)),
+    ]
+}
+
+#[test]
+fn animation_round_trips_through_compact_encoding() {
+    let frames = sample_frames();
+    let mut buffer = [0u8; 64];
+    let encoded_len_written = encode_animation(&frames, &mut buffer).expect("buffer fits");
+    assert_eq!(encoded_len_written, encoded_len::<4>(2));
+    assert_eq!(encoded_len_written, 5 + 2 * (4 + 12));
+
+    let decoded = decode_animation::<4, 8>(&buffer[..encoded_len_written]).expect("valid");
+    assert_eq!(decoded.len(), 2);
+    for ((frame, duration), (expected_frame, expected_duration)) in decoded.iter().zip(&frames) {
+        assert_eq!(frame.0, expected_frame.0);
+        assert_eq!(duration, expected_duration);
+    }
+}
+
+#[test]
+fn decoding_for_another_strip_length_is_rejected() {
+    let mut buffer = [0u8; 64];
+    let len = encode_animation(&sample_frames(), &mut buffer).expect("buffer fits");
+    let result = decode_animation::<5, 8>(&buffer[..len]);
+    assert!(matches!(
+        result,
+        Err(Error::AnimationLengthMismatch {
+            stored: 4,
+            expected: 5
+        })
+    ));
+}
+
+#[test]
+fn truncated_or_oversized_animations_are_rejected() {
+    let mut buffer = [0u8; 64];
+    let len = encode_animation(&sample_frames(), &mut buffer).expect("buffer fits");
+    assert!(matches!(
+        decode_animation::<4, 8>(&buffer[..len - 1]),
+        Err(Error::StorageCorrupted)
+    ));
+    assert!(matches!(
+        decode_animation::<4, 1>(&buffer[..len]),
+        Err(Error::FormatError)
+    ));
+    assert!(matches!(
+        encode_animation(&sample_frames(), &mut buffer[..len - 1]),
+        Err(Error::FormatError)
+    ));
+}
+
+#[test]
+fn one_block_holds_38_frames_of_a_32_led_strip() {
+    assert!(encoded_len::<32>(38) <= MAX_ENCODED_LEN);
+}
@@ -0,0 +1,550 @@
+#![allow(missing_docs)]
+#![cfg(feature = "host")]
+//! Host-level tests for observing frames sent to a stub LED strip.
+
+use device_envoy::led_strip::{Frame1d, FrameObserver, RGB8, VirtualClock, colors};
+use device_envoy::led2d::{
+    Corner, DeviceInfo, Frame2d, Led2d, Led2dTiled, LedDisplay, LedLayout, LedPanel, LedStrip,
+};
+use embassy_time::Duration;
+use std::sync::Mutex;
+
+struct RecordingObserver {
+    frames: Mutex<Vec<[RGB8; 6]>>,
+}
+
+impl RecordingObserver {
+    const fn new() -> Self {
+        Self {
+            frames: Mutex::new(Vec::new()),
+        }
+    }
+
+    fn frames(&self) -> Vec<[RGB8; 6]> {
+        self.frames
+            .lock()
+            .expect("observer lock is not poisoned")
+            .clone()
+    }
+}
+
+impl FrameObserver<6> for RecordingObserver {
+    fn on_frame(&self, frame: &Frame1d<6>) {
+        self.frames
+            .lock()
+            .expect("observer lock is not poisoned")
+            .push(frame.0);
+    }
+}
+
+#[test]
+fn led_strip_reports_written_and_animated_frames() {
+    static RECORDING_OBSERVER: RecordingObserver = RecordingObserver::new();
+    static LED_STRIP: LedStrip<6, 4> = LedStrip::new(&RECORDING_OBSERVER);
+
+    LED_STRIP
+        .write_frame(Frame1d::filled(colors::RED))
+        .expect("write_frame succeeds");
+    let frame_duration = Duration::from_millis(100);
+    LED_STRIP
+        .animate([
+            (Frame1d::filled(colors::GREEN), frame_duration),
+            (Frame1d::filled(colors::BLUE), frame_duration),
+        ])
+        .expect("animate succeeds");
+
+    let frames = RECORDING_OBSERVER.frames();
+    assert_eq!(frames.len(), 3);
+    assert_eq!(frames[0], [colors::RED; 6]);
+    assert_eq!(frames[1], [colors::GREEN; 6]);
+    assert_eq!(frames[2], [colors::BLUE; 6]);
+}
+
+#[test]
+fn led_strip_animates_borrowed_const_sequence_repeatedly() {
+    static RECORDING_OBSERVER: RecordingObserver = RecordingObserver::new();
+    static LED_STRIP: LedStrip<6, 4> = LedStrip::new(&RECORDING_OBSERVER);
+    const FRAMES: [(Frame1d<6>, Duration); 2] = [
+        (Frame1d::filled(colors::RED), Duration::from_millis(100)),
+        (Frame1d::filled(colors::BLUE), Duration::from_millis(200)),
+    ];
+
+    LED_STRIP.animate(&FRAMES).expect("animate succeeds");
+    LED_STRIP
+        .animate(&FRAMES[..1])
+        .expect("animate of a borrowed slice succeeds");
+
+    let frames = RECORDING_OBSERVER.frames();
+    assert_eq!(
+        frames,
+        vec![[colors::RED; 6], [colors::BLUE; 6], [colors::RED; 6]]
+    );
+}
+
+#[test]
+fn led_strip_calls_back_after_each_animated_frame() {
+    static RECORDING_OBSERVER: RecordingObserver = RecordingObserver::new();
+    static LED_STRIP: LedStrip<6, 4> = LedStrip::new(&RECORDING_OBSERVER);
+    static FRAME_INDEXES: Mutex<Vec<usize>> = Mutex::new(Vec::new());
+
+    let frame_duration = Duration::from_millis(100);
+    LED_STRIP
+        .animate_with_callback(
+            [
+                (Frame1d::filled(colors::GREEN), frame_duration),
+                (Frame1d::filled(colors::BLUE), frame_duration),
+                (Frame1d::filled(colors::RED), frame_duration),
+            ],
+            |frame_index| {
+                // Each callback runs after its frame has been reported.
+                assert_eq!(RECORDING_OBSERVER.frames().len(), frame_index + 1);
+                FRAME_INDEXES
+                    .lock()
+                    .expect("frame index lock is not poisoned")
+                    .push(frame_index);
+            },
+        )
+        .expect("animate_with_callback succeeds");
+
+    assert_eq!(
+        *FRAME_INDEXES
+            .lock()
+            .expect("frame index lock is not poisoned"),
+        [0, 1, 2]
+    );
+}
+
+#[test]
+fn led_strip_displays_animation_frames_by_virtual_time() {
+    static VIRTUAL_CLOCK: VirtualClock = VirtualClock::new();
+    static LED_STRIP: LedStrip<6, 4> = LedStrip::new_with_clock(&(), &VIRTUAL_CLOCK);
+
+    assert!(LED_STRIP.displayed_frame().is_none());
+    LED_STRIP
+        .animate([
+            (Frame1d::filled(colors::GREEN), Duration::from_millis(200)),
+            (Frame1d::filled(colors::BLUE), Duration::from_millis(300)),
+        ])
+        .expect("animate succeeds");
+
+    let displayed_color = || LED_STRIP.displayed_frame().expect("frame is displayed")[0];
+    assert_eq!(displayed_color(), colors::GREEN);
+    VIRTUAL_CLOCK.advance(Duration::from_millis(199));
+    assert_eq!(displayed_color(), colors::GREEN);
+    VIRTUAL_CLOCK.advance(Duration::from_millis(101));
+    assert_eq!(displayed_color(), colors::BLUE);
+    // The animation loops: 500 ms per cycle.
+    VIRTUAL_CLOCK.advance(Duration::from_millis(200));
+    assert_eq!(displayed_color(), colors::GREEN);
+
+    LED_STRIP
+        .write_frame(Frame1d::filled(colors::RED))
+        .expect("write_frame succeeds");
+    VIRTUAL_CLOCK.advance(Duration::from_millis(1_000));
+    assert_eq!(displayed_color(), colors::RED);
+}
+
+#[test]
+fn led_panel_trait_reports_device_info_and_writes_frames() {
+    fn fill_and_measure<P: LedPanel<Frame = Frame1d<6>>>(led_panel: &P) -> DeviceInfo {
+        led_panel
+            .write_frame(Frame1d::filled(colors::RED))
+            .expect("write_frame succeeds");
+        led_panel.device_info()
+    }
+
+    static RECORDING_OBSERVER: RecordingObserver = RecordingObserver::new();
+    static LED_STRIP: LedStrip<6, 4> = LedStrip::new(&RECORDING_OBSERVER);
+
+    assert_eq!(
+        fill_and_measure(&LED_STRIP),
+        DeviceInfo {
+            len: 6,
+            width: 6,
+            height: 1,
+            max_frames: 4,
+        }
+    );
+    assert_eq!(RECORDING_OBSERVER.frames(), [[colors::RED; 6]]);
+}
+
+#[test]
+fn led_panel_trait_animates_a_borrowed_const_table() {
+    fn animate_table<P: LedPanel<Frame = Frame1d<6>>>(
+        led_panel: &P,
+        frames: &[(Frame1d<6>, Duration)],
+    ) {
+        led_panel.animate(frames).expect("animate succeeds");
+    }
+
+    const FRAMES: [(Frame1d<6>, Duration); 2] = [
+        (Frame1d::filled(colors::GREEN), Duration::from_millis(100)),
+        (Frame1d::filled(colors::BLUE), Duration::from_millis(100)),
+    ];
+    static RECORDING_OBSERVER: RecordingObserver = RecordingObserver::new();
+    static LED_STRIP: LedStrip<6, 4> = LedStrip::new(&RECORDING_OBSERVER);
+
+    animate_table(&LED_STRIP, &FRAMES);
+
+    assert_eq!(
+        RECORDING_OBSERVER.frames(),
+        [[colors::GREEN; 6], [colors::BLUE; 6]]
+    );
+}
+
+#[test]
+fn led2d_reports_frames_in_strip_order() {
+    const LED_LAYOUT: LedLayout<6, 3, 2> = LedLayout::serpentine_column_major();
+    static RECORDING_OBSERVER: RecordingObserver = RecordingObserver::new();
+    static LED_STRIP: LedStrip<6, 4> = LedStrip::new(&RECORDING_OBSERVER);
+    let led2d = Led2d::new(&LED_STRIP, &LED_LAYOUT);
+
+    let mut frame = Frame2d::<3, 2>::new();
+    frame[(1, 0)] = colors::RED;
+    frame[(2, 1)] = colors::BLUE;
+    led2d.write_frame(frame).expect("write_frame succeeds");
+
+    let black = RGB8::new(0, 0, 0);
+    assert_eq!(
+        RECORDING_OBSERVER.frames(),
+        [[black, black, black, colors::RED, black, colors::BLUE]]
+    );
+}
+
+#[test]
+fn led2d_write_frame_ref_matches_write_frame() {
+    const LED_LAYOUT: LedLayout<6, 3, 2> = LedLayout::serpentine_column_major();
+    static RECORDING_OBSERVER: RecordingObserver = RecordingObserver::new();
+    static LED_STRIP: LedStrip<6, 4> = LedStrip::new(&RECORDING_OBSERVER);
+    let led2d = Led2d::new(&LED_STRIP, &LED_LAYOUT);
+
+    let mut frame = Frame2d::<3, 2>::new();
+    frame[(1, 0)] = colors::RED;
+    led2d
+        .write_frame_ref(&frame)
+        .expect("write_frame_ref succeeds");
+    led2d.write_frame(frame).expect("write_frame succeeds");
+
+    let frames = RECORDING_OBSERVER.frames();
+    assert_eq!(frames.len(), 2);
+    assert_eq!(frames[0], frames[1]);
+}
+
+#[test]
+fn led2d_origin_mirrors_frames_before_layout() {
+    const LED_LAYOUT: LedLayout<6, 3, 2> = LedLayout::serpentine_column_major();
+    static RECORDING_OBSERVER: RecordingObserver = RecordingObserver::new();
+    static LED_STRIP: LedStrip<6, 4> = LedStrip::new(&RECORDING_OBSERVER);
+    let led2d = Led2d::new(&LED_STRIP, &LED_LAYOUT).with_origin(Corner::BottomRight);
+
+    let mut frame = Frame2d::<3, 2>::new();
+    frame[(1, 0)] = colors::RED;
+    frame[(2, 1)] = colors::BLUE;
+    led2d.write_frame(frame).expect("write_frame succeeds");
+
+    // (1, 0) lands on physical (1, 1) and (2, 1) on physical (0, 0).
+    let black = RGB8::new(0, 0, 0);
+    assert_eq!(
+        RECORDING_OBSERVER.frames(),
+        [[colors::BLUE, black, colors::RED, black, black, black]]
+    );
+}
+
+#[test]
+fn led2d_tiled_splits_frame_across_strips() {
+    const PANEL_LAYOUT: LedLayout<6, 3, 2> = LedLayout::serpentine_row_major();
+    static LEFT_OBSERVER: RecordingObserver = RecordingObserver::new();
+    static RIGHT_OBSERVER: RecordingObserver = RecordingObserver::new();
+    static LEFT_STRIP: LedStrip<6, 4> = LedStrip::new(&LEFT_OBSERVER);
+    static RIGHT_STRIP: LedStrip<6, 4> = LedStrip::new(&RIGHT_OBSERVER);
+    let led6x2 = Led2dTiled::new([[&LEFT_STRIP, &RIGHT_STRIP]], &PANEL_LAYOUT);
+
+    let mut frame = Frame2d::<6, 2>::new();
+    frame[(0, 0)] = colors::RED;
+    frame[(4, 1)] = colors::BLUE;
+    led6x2.write_frame(frame).expect("write_frame succeeds");
+    let frame_duration = Duration::from_millis(100);
+    led6x2
+        .animate([(Frame2d::<6, 2>::filled(colors::GREEN), frame_duration)])
+        .expect("animate succeeds");
+
+    let black = RGB8::new(0, 0, 0);
+    assert_eq!(
+        LEFT_OBSERVER.frames(),
+        [
+            [colors::RED, black, black, black, black, black],
+            [colors::GREEN; 6]
+        ]
+    );
+    assert_eq!(
+        RIGHT_OBSERVER.frames(),
+        [
+            [black, black, black, black, colors::BLUE, black],
+            [colors::GREEN; 6]
+        ]
+    );
+}
+
+#[test]
+fn led2d_animate_generated_converts_each_generated_frame() {
+    const LED_LAYOUT: LedLayout<6, 3, 2> = LedLayout::serpentine_row_major();
+    static RECORDING_OBSERVER: RecordingObserver = RecordingObserver::new();
+    static LED_STRIP: LedStrip<6, 0> = LedStrip::new(&RECORDING_OBSERVER);
+    let led2d = Led2d::new(&LED_STRIP, &LED_LAYOUT);
+
+    fn generate_strip_frame(frame_index: usize) -> Frame1d<6> {
+        let mut frame = Frame2d::<3, 2>::new();
+        frame[(frame_index, 0)] = colors::RED;
+        LED_LAYOUT.remap(&frame)
+    }
+    led2d
+        .animate_generated(3, Duration::from_millis(100), generate_strip_frame)
+        .expect("animate_generated succeeds");
+
+    let black = RGB8::new(0, 0, 0);
+    assert_eq!(
+        RECORDING_OBSERVER.frames(),
+        [
+            [colors::RED, black, black, black, black, black],
+            [black, colors::RED, black, black, black, black],
+            [black, black, colors::RED, black, black, black],
+        ]
+    );
+}
+
+#[test]
+fn led2d_animate_uniform_shows_each_frame_for_the_same_duration() {
+    const LED_LAYOUT: LedLayout<6, 3, 2> = LedLayout::serpentine_column_major();
+    static VIRTUAL_CLOCK: VirtualClock = VirtualClock::new();
+    static RECORDING_OBSERVER: RecordingObserver = RecordingObserver::new();
+    static LED_STRIP: LedStrip<6, 4> =
+        LedStrip::new_with_clock(&RECORDING_OBSERVER, &VIRTUAL_CLOCK);
+    let led2d = Led2d::new(&LED_STRIP, &LED_LAYOUT);
+
+    let mut first_frame = Frame2d::<3, 2>::new();
+    first_frame[(1, 0)] = colors::RED;
+    let second_frame = Frame2d::filled(colors::BLUE);
+    led2d
+        .animate_uniform([first_frame, second_frame], Duration::from_millis(250))
+        .expect("animate_uniform succeeds");
+
+    let black = RGB8::new(0, 0, 0);
+    assert_eq!(
+        RECORDING_OBSERVER.frames(),
+        [
+            [black, black, black, colors::RED, black, black],
+            [colors::BLUE; 6],
+        ]
+    );
+    let displayed_color = || LED_STRIP.displayed_frame().expect("frame is displayed")[3];
+    assert_eq!(displayed_color(), colors::RED);
+    VIRTUAL_CLOCK.advance(Duration::from_millis(250));
+    assert_eq!(displayed_color(), colors::BLUE);
+    VIRTUAL_CLOCK.advance(Duration::from_millis(250));
+    assert_eq!(displayed_color(), colors::RED);
+}
+
+#[test]
+fn led_display_trait_objects_fill_and_clear_strips_of_different_sizes() {
+    static RECORDING_OBSERVER: RecordingObserver = RecordingObserver::new();
+    static LED_STRIP6: LedStrip<6, 4> = LedStrip::new(&RECORDING_OBSERVER);
+    static LED_STRIP3: LedStrip<3, 0> = LedStrip::new(&());
+
+    let led_displays: [&dyn LedDisplay; 2] = [&LED_STRIP6, &LED_STRIP3];
+    let total_len: usize = led_displays
+        .iter()
+        .map(|led_display| led_display.len())
+        .sum();
+    assert_eq!(total_len, 9);
+    for led_display in led_displays {
+        led_display.fill(colors::GREEN).expect("fill succeeds");
+    }
+    assert_eq!(
+        LED_STRIP3.displayed_frame().expect("frame is displayed").0,
+        [colors::GREEN; 3]
+    );
+    for led_display in led_displays {
+        led_display.clear().expect("clear succeeds");
+    }
+
+    let black = RGB8::new(0, 0, 0);
+    assert_eq!(
+        RECORDING_OBSERVER.frames(),
+        [[colors::GREEN; 6], [black; 6]]
+    );
+    assert_eq!(
+        LED_STRIP3.displayed_frame().expect("frame is displayed").0,
+        [black; 3]
+    );
+}
+
+#[test]
+fn led_strip_shutdown_turns_off_and_ignores_later_commands() {
+    static RECORDING_OBSERVER: RecordingObserver = RecordingObserver::new();
+    static LED_STRIP: LedStrip<6, 4> = LedStrip::new(&RECORDING_OBSERVER);
+
+    LED_STRIP
+        .write_frame(Frame1d::filled(colors::RED))
+        .expect("write_frame succeeds");
+    LED_STRIP.shutdown();
+    LED_STRIP
+        .write_frame(Frame1d::filled(colors::GREEN))
+        .expect("write_frame after shutdown is ignored");
+    LED_STRIP
+        .animate([(Frame1d::filled(colors::BLUE), Duration::from_millis(100))])
+        .expect("animate after shutdown is ignored");
+
+    let black = RGB8::new(0, 0, 0);
+    assert_eq!(RECORDING_OBSERVER.frames(), [[colors::RED; 6], [black; 6]]);
+    assert_eq!(
+        LED_STRIP.displayed_frame().expect("frame is displayed").0,
+        [black; 6]
+    );
+}
+
+#[test]
+fn led2d_shows_static_strip_frames_without_an_animation_buffer() {
+    const LED_LAYOUT: LedLayout<6, 3, 2> = LedLayout::serpentine_column_major();
+    static STRIP_FRAMES: [(Frame1d<6>, Duration); 2] = [
+        (
+            LED_LAYOUT.remap(&Frame2d::filled(colors::RED)),
+            Duration::from_millis(100),
+        ),
+        (
+            LED_LAYOUT.remap(&Frame2d::filled(colors::BLUE)),
+            Duration::from_millis(300),
+        ),
+    ];
+    static STRIP_FRAME: Frame1d<6> = LED_LAYOUT.remap(&Frame2d::filled(colors::LIME));
+    static VIRTUAL_CLOCK: VirtualClock = VirtualClock::new();
+    static RECORDING_OBSERVER: RecordingObserver = RecordingObserver::new();
+    static LED_STRIP: LedStrip<6, 0> =
+        LedStrip::new_with_clock(&RECORDING_OBSERVER, &VIRTUAL_CLOCK);
+    let led2d = Led2d::new(&LED_STRIP, &LED_LAYOUT);
+
+    led2d
+        .animate_static_frames(&STRIP_FRAMES)
+        .expect("animate_static_frames succeeds");
+    let displayed_color = || LED_STRIP.displayed_frame().expect("frame is displayed")[0];
+    assert_eq!(displayed_color(), colors::RED);
+    VIRTUAL_CLOCK.advance(Duration::from_millis(100));
+    assert_eq!(displayed_color(), colors::BLUE);
+
+    led2d
+        .write_static_frame(&STRIP_FRAME)
+        .expect("write_static_frame succeeds");
+    assert_eq!(
+        RECORDING_OBSERVER.frames(),
+        [[colors::RED; 6], [colors::BLUE; 6], [colors::LIME; 6]]
+    );
+    assert_eq!(displayed_color(), colors::LIME);
+}
+
+#[test]
+fn led_strip_write_segment_merges_into_last_written_frame() {
+    static RECORDING_OBSERVER: RecordingObserver = RecordingObserver::new();
+    static LED_STRIP: LedStrip<6, 4> = LedStrip::new(&RECORDING_OBSERVER);
+
+    let black = RGB8::new(0, 0, 0);
+    LED_STRIP
+        .write_segment(&[colors::RED; 2], 0)
+        .expect("segment fits");
+    LED_STRIP
+        .write_segment(&[colors::BLUE; 3], 3)
+        .expect("segment fits");
+    assert!(matches!(
+        LED_STRIP.write_segment(&[colors::LIME; 2], 5),
+        Err(device_envoy::Error::IndexOutOfBounds)
+    ));
+    LED_STRIP
+        .write_frame(Frame1d::filled(colors::LIME))
+        .expect("write_frame succeeds");
+    LED_STRIP.write_segment(&[black], 2).expect("segment fits");
+
+    assert_eq!(
+        RECORDING_OBSERVER.frames(),
+        [
+            [colors::RED, colors::RED, black, black, black, black],
+            [
+                colors::RED,
+                colors::RED,
+                black,
+                colors::BLUE,
+                colors::BLUE,
+                colors::BLUE
+            ],
+            [colors::LIME; 6],
+            [
+                colors::LIME,
+                colors::LIME,
+                black,
+                colors::LIME,
+                colors::LIME,
+                colors::LIME
+            ],
+        ]
+    );
+}
+
+#[test]
+fn led_strip_is_idle_except_while_animating() {
+    static LED_STRIP: LedStrip<6, 4> = LedStrip::new(&());
+
+    assert!(!LED_STRIP.is_animating());
+    embassy_futures::block_on(LED_STRIP.wait_idle());
+
+    LED_STRIP
+        .animate([(Frame1d::filled(colors::BLUE), Duration::from_millis(100))])
+        .expect("animate succeeds");
+    assert!(LED_STRIP.is_animating());
+
+    let waiter = std::thread::spawn(|| embassy_futures::block_on(LED_STRIP.wait_idle()));
+    LED_STRIP
+        .write_frame(Frame1d::filled(colors::RED))
+        .expect("write_frame succeeds");
+    waiter
+        .join()
+        .expect("wait_idle resolves after a static frame");
+    assert!(!LED_STRIP.is_animating());
+}
+
+#[test]
+fn led_strip_brightness_starts_full_and_does_not_scale_reported_frames() {
+    static LED_STRIP: LedStrip<6, 4> = LedStrip::new(&());
+
+    assert_eq!(LED_STRIP.brightness(), u8::MAX);
+    LED_STRIP.set_brightness(64);
+    assert_eq!(LED_STRIP.brightness(), 64);
+
+    LED_STRIP
+        .write_frame(Frame1d::filled(colors::RED))
+        .expect("write_frame succeeds");
+    assert_eq!(
+        LED_STRIP.displayed_frame().expect("frame is displayed")[0],
+        colors::RED
+    );
+}
+
+#[test]
+fn led2d_animate_crossfade_blends_from_one_frame_to_the_other() {
+    const LED_LAYOUT: LedLayout<6, 3, 2> = LedLayout::serpentine_row_major();
+    static RECORDING_OBSERVER: RecordingObserver = RecordingObserver::new();
+    static LED_STRIP: LedStrip<6, 4> = LedStrip::new(&RECORDING_OBSERVER);
+    let led2d = Led2d::new(&LED_STRIP, &LED_LAYOUT);
+
+    led2d
+        .animate_crossfade(
+            Frame2d::<3, 2>::new(),
+            Frame2d::filled(RGB8::new(0, 0, 255)),
+            4,
+            Duration::from_millis(50),
+        )
+        .expect("animate_crossfade succeeds");
+
+    let blues: Vec<u8> = RECORDING_OBSERVER
+        .frames()
+        .iter()
+        .map(|frame| frame[0].b)
+        .collect();
+    assert_eq!(blues, [0, 85, 170, 255]);
+}
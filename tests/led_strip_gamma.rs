@@ -0,0 +1,40 @@
+#![allow(missing_docs)]
+#![cfg(feature = "host")]
+use device_envoy::led_strip::host::{combo_table, combo_table_preview, generate_gamma_table_f64};
+use device_envoy::led_strip::{Gamma, generate_combo_table};
+
+#[test]
+fn generated_gamma_2_2_matches_srgb_table() {
+    let gamma_table: &'static [u8; 256] = Box::leak(Box::new(generate_gamma_table_f64(2.2)));
+    assert_eq!(
+        generate_combo_table(Gamma::Custom(gamma_table), 255),
+        generate_combo_table(Gamma::Srgb, 255)
+    );
+}
+
+#[test]
+fn custom_gamma_table_is_scaled_by_max_brightness() {
+    let gamma_table: &'static [u8; 256] = Box::leak(Box::new(generate_gamma_table_f64(2.5)));
+    assert_eq!(gamma_table[255], 255);
+    assert!(gamma_table[128] < generate_gamma_table_f64(2.2)[128]);
+
+    let combo_table = generate_combo_table(Gamma::Custom(gamma_table), 128);
+    assert_eq!(combo_table[0], 0);
+    assert_eq!(combo_table[255], 128);
+}
+
+#[test]
+fn combo_table_preview_lists_every_output() {
+    assert_eq!(
+        combo_table(Gamma::SmartLeds, 200),
+        generate_combo_table(Gamma::SmartLeds, 200)
+    );
+
+    let preview = combo_table_preview(Gamma::Srgb, 128);
+    let lines: Vec<&str> = preview.lines().collect();
+    assert_eq!(lines.len(), 17);
+    assert_eq!(lines[0], "Srgb, max_brightness 128:");
+    assert!(lines[1].starts_with("  0:   0   0"));
+    assert!(lines[16].starts_with("240:"));
+    assert!(lines[16].ends_with(" 128"));
+}
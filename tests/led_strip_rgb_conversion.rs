@@ -1,4 +1,7 @@
 #![allow(missing_docs)]
+use device_envoy::led_strip::extended_colors::{
+    COOL_WHITE, NEUTRAL_WHITE, WARM_WHITE, rgb8_from_kelvin,
+};
 use device_envoy::led_strip::{RGB8, Rgb888, ToRgb8, ToRgb888};
 
 #[test]
@@ -29,3 +32,17 @@ fn rgb888_to_rgb888_is_identity() {
 
     assert_eq!(rgb888_color, converted);
 }
+
+#[test]
+fn rgb8_from_kelvin_matches_reference_white_points() {
+    assert_eq!(rgb8_from_kelvin(2700), WARM_WHITE);
+    assert_eq!(rgb8_from_kelvin(4000), NEUTRAL_WHITE);
+    assert_eq!(rgb8_from_kelvin(6500), COOL_WHITE);
+    assert_eq!(rgb8_from_kelvin(6600), RGB8::new(255, 255, 255));
+    assert_eq!(rgb8_from_kelvin(1000), RGB8::new(255, 68, 0));
+    // Out-of-range temperatures clamp; in-between ones interpolate.
+    assert_eq!(rgb8_from_kelvin(500), rgb8_from_kelvin(1000));
+    assert_eq!(rgb8_from_kelvin(u16::MAX), rgb8_from_kelvin(40_000));
+    let between = rgb8_from_kelvin(2750);
+    assert!((167..=rgb8_from_kelvin(2800).g).contains(&between.g));
+}
@@ -2,6 +2,7 @@
 //! Host-level tests for mapping primitives.
 
 use device_envoy::led2d::layout::LedLayout;
+use device_envoy::led2d::layout_csv;
 
 #[test]
 fn linear_single_row_matches_expected() {
@@ -60,6 +61,27 @@ fn rotate_and_flip_small_grid() {
     );
 }
 
+#[test]
+fn zigzag_column_major_from_bottom_starts_bottom_left() {
+    const ZIGZAG: LedLayout<9, 3, 3> = LedLayout::zigzag_column_major_from_bottom();
+
+    assert_eq!(
+        *ZIGZAG.index_to_xy(),
+        [
+            (0, 2),
+            (0, 1),
+            (0, 0),
+            (1, 0),
+            (1, 1),
+            (1, 2),
+            (2, 2),
+            (2, 1),
+            (2, 0),
+        ]
+    );
+    assert!(ZIGZAG.equals(&LedLayout::serpentine_column_major().flip_v()));
+}
+
 #[test]
 fn serpentine_transforms_match_expected() {
     const SERPENTINE: LedLayout<6, 3, 2> = LedLayout::<6, 3, 2>::serpentine_column_major();
@@ -133,6 +155,26 @@ fn serpentine_transforms_match_expected() {
     );
 }
 
+#[test]
+fn reflect_x_and_reflect_y_mirror_layout() {
+    const SERPENTINE: LedLayout<6, 3, 2> = LedLayout::<6, 3, 2>::serpentine_column_major();
+    const REFLECTED_X: LedLayout<6, 3, 2> = SERPENTINE.reflect_x();
+    const REFLECTED_Y: LedLayout<6, 3, 2> = SERPENTINE.reflect_y();
+
+    assert_eq!(
+        *REFLECTED_X.index_to_xy(),
+        [(2, 0), (2, 1), (1, 1), (1, 0), (0, 0), (0, 1),]
+    );
+    assert_eq!(
+        *REFLECTED_Y.index_to_xy(),
+        [(0, 1), (0, 0), (1, 0), (1, 1), (2, 1), (2, 0),]
+    );
+    assert!(REFLECTED_X.equals(&SERPENTINE.flip_h()));
+    assert!(REFLECTED_Y.equals(&SERPENTINE.flip_v()));
+    assert!(REFLECTED_X.reflect_x().equals(&SERPENTINE));
+    assert!(REFLECTED_Y.reflect_y().equals(&SERPENTINE));
+}
+
 #[test]
 fn combine_horizontal_and_vertical() {
     const LEFT: LedLayout<2, 2, 1> = LedLayout::new([(0, 0), (1, 0)]);
@@ -176,3 +218,52 @@ fn new_panics_on_missing_cells() {
 fn new_panics_on_mismatched_dimensions() {
     let _ = LedLayout::<5, 3, 2>::new([(0, 0), (1, 0), (2, 0), (0, 1), (1, 1)]);
 }
+
+#[test]
+fn remap_places_each_pixel_at_its_wiring_index() {
+    use device_envoy::led_strip::{Frame1d, RGB8};
+    use device_envoy::led2d::Frame2d;
+
+    const LAYOUT: LedLayout<6, 3, 2> = LedLayout::serpentine_column_major();
+    const FRAME: Frame2d<3, 2> = Frame2d([
+        [RGB8::new(0, 0, 0), RGB8::new(1, 0, 0), RGB8::new(2, 0, 0)],
+        [RGB8::new(0, 1, 0), RGB8::new(1, 1, 0), RGB8::new(2, 1, 0)],
+    ]);
+    const STRIP_FRAME: Frame1d<6> = LAYOUT.remap(&FRAME);
+
+    // Each pixel encodes its own (x, y), so the strip order reads back the layout.
+    for (led_index, &(x_index, y_index)) in LAYOUT.index_to_xy().iter().enumerate() {
+        let pixel = STRIP_FRAME[led_index];
+        assert_eq!((u16::from(pixel.r), u16::from(pixel.g)), (x_index, y_index));
+    }
+}
+
+#[test]
+fn csv_round_trips_a_transformed_layout() {
+    const ROTATED: LedLayout<6, 2, 3> = LedLayout::serpentine_column_major().rotate_cw();
+    let csv = layout_csv::to_csv(&ROTATED);
+    assert_eq!(csv, "1,0\n2,3\n5,4\n");
+    let parsed = layout_csv::from_csv::<6, 2, 3>(&csv).expect("to_csv output parses");
+    assert!(parsed.equals(&ROTATED));
+
+    let spaced = layout_csv::from_csv::<6, 2, 3>(" 1, 0\n2 ,3\n5,4\n\n").expect("spaces parse");
+    assert!(spaced.equals(&ROTATED));
+}
+
+#[test]
+fn from_csv_rejects_malformed_layouts() {
+    for csv in [
+        "1,0\n2,3\n",
+        "1,0\n2,3\n5,4\n0,1\n",
+        "1,0\n2\n5,4\n",
+        "1,0,2\n3,4\n5,6\n",
+        "1,0\n2,x\n5,4\n",
+        "1,0\n2,6\n5,4\n",
+        "1,0\n2,1\n5,4\n",
+    ] {
+        assert!(
+            layout_csv::from_csv::<6, 2, 3>(csv).is_err(),
+            "{csv:?} should be rejected"
+        );
+    }
+}
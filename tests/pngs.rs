@@ -108,7 +108,7 @@ fn build_led2d1_frame() -> Led12x4Frame {
     let spacing_reduction = font_variant.spacing_reduction();
     let colors = [colors::CYAN, colors::RED, colors::YELLOW];
 
-    render_text_to_frame(&mut frame, &font, "Rust", &colors, spacing_reduction)
+    render_text_to_frame(&mut frame, &font, "Rust", &colors, spacing_reduction, false)
         .expect("text render must succeed");
 
     frame
@@ -124,7 +124,7 @@ fn build_led2d2_frame_0() -> Led8x12Frame {
     let font = font_variant.to_font();
     let spacing_reduction = font_variant.spacing_reduction();
 
-    render_text_to_frame(&mut frame, &font, "Go", &[], spacing_reduction)
+    render_text_to_frame(&mut frame, &font, "Go", &[], spacing_reduction, false)
         .expect("text render must succeed");
 
     frame
@@ -137,7 +137,7 @@ fn build_led2d2_frame_1() -> Led8x12Frame {
     let spacing_reduction = font_variant.spacing_reduction();
     let colors = [colors::HOT_PINK, colors::LIME];
 
-    render_text_to_frame(&mut frame, &font, "\nGo", &colors, spacing_reduction)
+    render_text_to_frame(&mut frame, &font, "\nGo", &colors, spacing_reduction, false)
         .expect("text render must succeed");
 
     frame
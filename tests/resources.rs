@@ -0,0 +1,30 @@
+#![allow(missing_docs)]
+//! Host-level tests for the PIO/DMA resource registry.
+
+use device_envoy::resources::{DEFAULT_RESOURCES, same_resource};
+
+// Distinct claims compile; a repeated PIO or DMA channel would fail the build.
+device_envoy::resources! {
+    wifi: PIO0, DMA_CH0;
+    led12x4: PIO1, DMA_CH1;
+    ir: PIO2;
+}
+
+#[test]
+fn same_resource_compares_whole_names() {
+    assert!(same_resource("PIO0", "PIO0"));
+    assert!(!same_resource("PIO0", "PIO1"));
+    assert!(!same_resource("DMA_CH1", "DMA_CH10"));
+}
+
+#[test]
+fn led_macros_default_to_pio0_and_dma_ch0() {
+    for defaults in DEFAULT_RESOURCES {
+        assert_eq!(
+            (defaults.pio, defaults.dma),
+            ("PIO0", "DMA_CH0"),
+            "{}",
+            defaults.device
+        );
+    }
+}
@@ -0,0 +1,121 @@
+#![allow(missing_docs)]
+#![cfg(feature = "host")]
+//! Host-level tests for the RFID NDEF parsers.
+
+use device_envoy::Error;
+use device_envoy::rfid::{NdefMessage, find_ndef_message, parse_ndef_message};
+
+// Short, well-known URI record "https://example.com" (prefix code 4).
+const URI_RECORD: [u8; 16] = [
+    0xD1, 0x01, 0x0C, b'U', 0x04, b'e', b'x', b'a', b'm', b'p', b'l', b'e', b'.', b'c', b'o', b'm',
+];
+
+fn uri(text: &str) -> NdefMessage {
+    NdefMessage::Uri(text.try_into().expect("test URI fits"))
+}
+
+#[test]
+fn finds_message_after_a_tlv_with_a_long_length() {
+    // A 300-byte proprietary TLV uses the three-byte length form.
+    let mut memory = vec![0x00, 0xFD, 0xFF, 0x01, 0x2C];
+    memory.extend_from_slice(&[0xAA; 300]);
+    memory.extend_from_slice(&[0x03, 0x10]);
+    memory.extend_from_slice(&URI_RECORD);
+    memory.push(0xFE);
+
+    let message = find_ndef_message(&memory).expect("valid TLVs");
+    assert_eq!(message, Some(&URI_RECORD[..]));
+    assert_eq!(
+        parse_ndef_message(&URI_RECORD).expect("URI record"),
+        uri("https://example.com")
+    );
+}
+
+#[test]
+fn finds_message_with_a_long_length() {
+    let mut memory = vec![0x03, 0xFF, 0x00, 0x10];
+    memory.extend_from_slice(&URI_RECORD);
+    memory.push(0xFE);
+
+    let message = find_ndef_message(&memory).expect("valid TLVs");
+    assert_eq!(message, Some(&URI_RECORD[..]));
+}
+
+#[test]
+fn waits_for_more_memory_when_a_tlv_is_cut_off() {
+    // Ends inside the three-byte length field.
+    assert_eq!(
+        find_ndef_message(&[0x03, 0xFF, 0x00]).expect("no error"),
+        None
+    );
+    // Ends inside the message value.
+    assert_eq!(
+        find_ndef_message(&[0x03, 0x10, 0xD1, 0x01]).expect("no error"),
+        None
+    );
+}
+
+#[test]
+fn rejects_memory_without_a_message() {
+    assert!(matches!(
+        find_ndef_message(&[0x00, 0xFE, 0x03, 0x00]),
+        Err(Error::NdefUnsupported)
+    ));
+}
+
+#[test]
+fn parses_a_record_with_a_four_byte_payload_length() {
+    let record = [
+        0xC1, 0x01, 0x00, 0x00, 0x00, 0x05, b'U', 0x06, b'a', b'@', b'b', b'c',
+    ];
+    assert_eq!(
+        parse_ndef_message(&record).expect("URI record"),
+        uri("mailto:a@bc")
+    );
+}
+
+#[test]
+fn rejects_a_truncated_record() {
+    // The header promises a 12-byte payload, but only 5 bytes follow.
+    let record = &URI_RECORD[..9];
+    assert!(matches!(
+        parse_ndef_message(record),
+        Err(Error::NdefUnsupported)
+    ));
+}
+
+#[test]
+fn rejects_an_unknown_uri_prefix_code() {
+    let mut record = URI_RECORD;
+    record[4] = 0x24;
+    assert!(matches!(
+        parse_ndef_message(&record),
+        Err(Error::NdefUnsupported)
+    ));
+}
+
+#[test]
+fn parses_a_utf8_text_record() {
+    let record = [
+        0xD1, 0x01, 0x08, b'T', 0x02, b'e', b'n', b'h', b'e', b'l', b'l', b'o',
+    ];
+    assert_eq!(
+        parse_ndef_message(&record).expect("text record"),
+        NdefMessage::Text {
+            language: "en".try_into().expect("fits"),
+            text: "hello".try_into().expect("fits"),
+        }
+    );
+}
+
+#[test]
+fn rejects_a_utf16_text_record() {
+    // Status byte 0x82: UTF-16 encoding, two-byte language code.
+    let record = [
+        0xD1, 0x01, 0x07, b'T', 0x82, b'e', b'n', 0x00, b'h', 0x00, b'i',
+    ];
+    assert!(matches!(
+        parse_ndef_message(&record),
+        Err(Error::NdefUnsupported)
+    ));
+}
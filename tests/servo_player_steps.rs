@@ -0,0 +1,80 @@
+#![allow(missing_docs)]
+#![cfg(feature = "host")]
+//! Host-level tests for servo animation step helpers.
+
+use device_envoy::servo_player::{ease_steps, linear, trapezoid};
+use embassy_time::Duration;
+
+fn total_duration(steps: &[(u16, Duration)]) -> Duration {
+    steps
+        .iter()
+        .fold(Duration::from_micros(0), |total, step| total + step.1)
+}
+
+#[test]
+fn easing_keeps_turnaround_waypoints_and_durations() {
+    let step_duration = Duration::from_millis(500);
+    let mut steps = [(0, step_duration), (180, step_duration), (0, step_duration)];
+    ease_steps(&mut steps, 2, 2);
+
+    assert_eq!(
+        steps,
+        [(0, step_duration), (180, step_duration), (0, step_duration)]
+    );
+}
+
+#[test]
+fn easing_bunches_a_sweep_toward_its_ends() {
+    const SWEEP: [(u16, Duration); 11] = linear(0, 100, Duration::from_secs(1));
+    let mut steps = SWEEP;
+    ease_steps(&mut steps, 5, 5);
+
+    let degrees: Vec<u16> = steps.iter().map(|step| step.0).collect();
+    assert_eq!(degrees[0], 0);
+    assert_eq!(degrees[5], 50);
+    assert_eq!(degrees[10], 100);
+    assert!(degrees.is_sorted());
+    // Moves are smallest at the ends and largest in the middle.
+    assert!(degrees[1] - degrees[0] < 10);
+    assert!(degrees[5] - degrees[4] > 10);
+    assert!(degrees[10] - degrees[9] < 10);
+    assert_eq!(total_duration(&steps), total_duration(&SWEEP));
+    assert_eq!(steps.len(), SWEEP.len());
+}
+
+#[test]
+fn easing_keeps_every_waypoint_of_a_zigzag() {
+    let step_duration = Duration::from_millis(100);
+    let zigzag = [
+        (0, step_duration),
+        (30, step_duration),
+        (60, step_duration),
+        (90, step_duration),
+        (45, step_duration),
+        (0, step_duration),
+    ];
+    let mut steps = zigzag;
+    ease_steps(&mut steps, 5, 0);
+
+    assert_eq!(steps[0], zigzag[0]);
+    assert_eq!(steps[3], zigzag[3]);
+    assert_eq!(steps[5], zigzag[5]);
+    assert!(steps[1].0 < 30 && steps[2].0 < 60);
+    assert!(steps[4].0 > 45);
+    assert_eq!(total_duration(&steps), total_duration(&zigzag));
+}
+
+#[test]
+fn trapezoid_steps_all_have_positive_durations() {
+    let steps: [(u16, Duration); 10] = trapezoid(0, 90, 180, 720);
+
+    assert_eq!(steps[0].0, 0);
+    assert_eq!(steps[9].0, 90);
+    assert!(steps.iter().all(|step| step.1.as_micros() > 0));
+}
+
+#[test]
+#[should_panic(expected = "trapezoid requires distinct start and end angles")]
+fn trapezoid_rejects_a_zero_distance_move() {
+    let _steps: [(u16, Duration); 4] = trapezoid(90, 90, 180, 720);
+}
@@ -54,9 +54,9 @@ led2d! {
 pub struct Led2dGenerated;
 
 #[cfg(doc)]
-use crate::led2d::{Frame2d, Point, Size};
+use crate::led2d::{Frame2d, Point, Rectangle, Size};
 #[cfg(doc)]
-use crate::led_strip::RGB8;
+use crate::led_strip::{AnimationTrigger, Frame1d, RGB8};
 #[cfg(doc)]
 use crate::Result;
 
@@ -157,6 +157,26 @@ impl Led2dGenerated {
         Ok(())
     }
 
+    /// Draw into the panel's reusable frame buffer, then display it.
+    ///
+    /// `draw` receives the same buffer on every call, and its contents persist between
+    /// calls: clear it first (for example with `*frame = Frame2d::new()`) unless you
+    /// want to build on the previous image. Because the frame is drawn in place and read
+    /// in place, this skips the full-frame copy of passing a `Frame2d` by value to
+    /// [`write_frame`](Self::write_frame), which helps high-rate updates such as a VU
+    /// meter.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from the underlying strip.
+    pub fn with_frame(
+        &self,
+        draw: impl FnOnce(&mut Frame2d<{ Self::WIDTH }, { Self::HEIGHT }>),
+    ) -> Result<()> {
+        let _ = draw;
+        Ok(())
+    }
+
     /// Write text to the LED panel.
     ///
     /// See the [`led2d`](mod@crate::led2d) module docs for usage.
@@ -165,6 +185,21 @@ impl Led2dGenerated {
         Ok(())
     }
 
+    /// Write text rotated 90° clockwise to the LED panel, for narrow panels read
+    /// sideways.
+    ///
+    /// Characters run top-to-bottom and each one spans the panel's width; `\n` starts a
+    /// new column of characters to the left.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::TextDoesNotFit`](crate::Error::TextDoesNotFit) if the font's line
+    /// height is larger than the panel width.
+    pub async fn write_text_vertical(&self, text: &str, colors: &[RGB8]) -> Result<()> {
+        let _ = (text, colors);
+        Ok(())
+    }
+
     /// Write text into a frame.
     ///
     /// See the [`led2d`](mod@crate::led2d) module docs for usage.
@@ -178,17 +213,262 @@ impl Led2dGenerated {
         Ok(())
     }
 
+    /// Write text into a frame like [`write_text_to_frame`](Self::write_text_to_frame),
+    /// but advance each character by its inked width plus one pixel instead of the
+    /// font's fixed width, packing more text onto the panel. Blank characters such as
+    /// space keep the fixed width.
+    pub fn write_text_to_frame_proportional(
+        &self,
+        text: &str,
+        colors: &[RGB8],
+        frame: &mut Frame2d<{ Self::WIDTH }, { Self::HEIGHT }>,
+    ) -> Result<()> {
+        let _ = (text, colors, frame);
+        Ok(())
+    }
+
+    /// Write text into a frame starting at `position` and return where the next
+    /// character would go, so differently colored runs can be chained on one line.
+    ///
+    /// See the [`led2d`](mod@crate::led2d) module docs for usage.
+    pub fn write_text_to_frame_at(
+        &self,
+        text: &str,
+        colors: &[RGB8],
+        position: Point,
+        frame: &mut Frame2d<{ Self::WIDTH }, { Self::HEIGHT }>,
+    ) -> Result<Point> {
+        let _ = (text, colors, frame);
+        Ok(position)
+    }
+
+    /// Write text rotated 90° clockwise into a frame.
+    ///
+    /// See [`write_text_vertical`](Self::write_text_vertical) for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::TextDoesNotFit`](crate::Error::TextDoesNotFit) if the font's line
+    /// height is larger than the panel width.
+    pub fn write_text_to_frame_vertical(
+        &self,
+        text: &str,
+        colors: &[RGB8],
+        frame: &mut Frame2d<{ Self::WIDTH }, { Self::HEIGHT }>,
+    ) -> Result<()> {
+        let _ = (text, colors, frame);
+        Ok(())
+    }
+
+    /// Write text into a frame and return the smallest rectangle enclosing the pixels
+    /// it lit, or `None` if no visible characters were drawn.
+    ///
+    /// Use the rectangle to center text, draw a border around it, or place more
+    /// graphics beside it.
+    ///
+    /// See the [`led2d`](mod@crate::led2d) module docs for usage.
+    pub fn write_text_to_frame_with_bounds(
+        &self,
+        text: &str,
+        colors: &[RGB8],
+        frame: &mut Frame2d<{ Self::WIDTH }, { Self::HEIGHT }>,
+    ) -> Result<Option<Rectangle>> {
+        let _ = (text, colors, frame);
+        Ok(None)
+    }
+
     /// Animate frames on the LED panel.
     ///
     /// See the [`led2d`](mod@crate::led2d) module docs for usage.
-    pub fn animate<const N: usize>(
+    pub fn animate<I>(&self, frames: I) -> Result<()>
+    where
+        I: IntoIterator,
+        I::Item: core::borrow::Borrow<(
+            Frame2d<{ Self::WIDTH }, { Self::HEIGHT }>,
+            embassy_time::Duration,
+        )>,
+    {
+        let _ = frames;
+        Ok(())
+    }
+
+    /// Animate frames on the LED panel, showing each for the same `frame_duration`.
+    ///
+    /// See the [`led2d`](mod@crate::led2d) module docs for usage.
+    pub fn animate_uniform<I>(&self, frames: I, frame_duration: embassy_time::Duration) -> Result<()>
+    where
+        I: IntoIterator,
+        I::Item: core::borrow::Borrow<Frame2d<{ Self::WIDTH }, { Self::HEIGHT }>>,
+    {
+        let _ = (frames, frame_duration);
+        Ok(())
+    }
+
+    /// Cross-fade from `from` to `to` over `steps` frames, each shown for `step_duration`.
+    ///
+    /// Frame `i` is [`from.lerp(to, t)`](Frame2d::lerp), with `t` rising in equal increments
+    /// from 0 to 255. Like every animation, the fade loops; to stay on `to`, write it with
+    /// [`write_frame`](Self::write_frame) once the fade has played. `steps` must not exceed
+    /// [`MAX_FRAMES`](Self::MAX_FRAMES).
+    pub fn animate_crossfade(
+        &self,
+        from: Frame2d<{ Self::WIDTH }, { Self::HEIGHT }>,
+        to: Frame2d<{ Self::WIDTH }, { Self::HEIGHT }>,
+        steps: u8,
+        step_duration: embassy_time::Duration,
+    ) -> Result<()> {
+        let _ = (from, to, steps, step_duration);
+        Ok(())
+    }
+
+    /// Like [`animate`](Self::animate), but the animation starts only when `trigger` is
+    /// signaled.
+    ///
+    /// See [`LedStripHandle::animate_on_signal`](crate::led_strip::LedStripHandle::animate_on_signal)
+    /// for details.
+    pub fn animate_on_signal<I>(&self, frames: I, trigger: &'static AnimationTrigger) -> Result<()>
+    where
+        I: IntoIterator,
+        I::Item: core::borrow::Borrow<(
+            Frame2d<{ Self::WIDTH }, { Self::HEIGHT }>,
+            embassy_time::Duration,
+        )>,
+    {
+        let _ = (frames, trigger);
+        Ok(())
+    }
+
+    /// Display a frame from static memory without copying it onto the stack.
+    ///
+    /// The frame must already be in strip order; build it with
+    /// [`LedLayout::remap`](crate::led2d::LedLayout::remap). See
+    /// [Stack Usage on Large Panels](mod@crate::led2d#stack-usage-on-large-panels).
+    pub fn write_static_frame(&self, frame: &'static Frame1d<{ Self::LEN }>) -> Result<()> {
+        let _ = frame;
+        Ok(())
+    }
+
+    /// Loop through frames from static memory without copying or buffering them.
+    ///
+    /// The frames must already be in strip order; build them with
+    /// [`LedLayout::remap`](crate::led2d::LedLayout::remap). See
+    /// [Stack Usage on Large Panels](mod@crate::led2d#stack-usage-on-large-panels).
+    pub fn animate_static_frames(
+        &self,
+        frames: &'static [(Frame1d<{ Self::LEN }>, embassy_time::Duration)],
+    ) -> Result<()> {
+        let _ = frames;
+        Ok(())
+    }
+
+    /// Loop forever through `frame_count` frames that `generate(frame_index)` computes on
+    /// demand, showing each for `frame_duration`.
+    ///
+    /// No frames are buffered, so long procedural animations are not limited by
+    /// `MAX_FRAMES`. `generate` is a plain function pointer; see
+    /// [`LedStripHandle::animate_generated`](crate::led_strip::LedStripHandle::animate_generated)
+    /// for details.
+    pub fn animate_generated(
+        &self,
+        frame_count: usize,
+        frame_duration: embassy_time::Duration,
+        generate: fn(usize) -> Frame2d<{ Self::WIDTH }, { Self::HEIGHT }>,
+    ) -> Result<()> {
+        let _ = (frame_count, frame_duration, generate);
+        Ok(())
+    }
+
+    /// Scroll `text` right to left in a rainbow that shifts across the panel and over
+    /// time, looping until interrupted by another command.
+    ///
+    /// The text moves one column per `speed` and its hue advances `hue_speed` steps (of
+    /// 256 per turn) each column. Frames are rendered on demand, so long text is not
+    /// limited by `MAX_FRAMES`.
+    ///
+    /// See the [`led2d`](mod@crate::led2d) module docs for text rendering.
+    pub fn scroll_rainbow_text(
         &self,
-        frames: [(Frame2d<{ Self::WIDTH }, { Self::HEIGHT }>, embassy_time::Duration); N],
+        text: &'static str,
+        speed: embassy_time::Duration,
+        hue_speed: u8,
     ) -> Result<()> {
+        let _ = (text, speed, hue_speed);
+        Ok(())
+    }
+
+    /// Show a flickering fire that rises up every column from the bottom row, computing a
+    /// new frame every `speed`. It runs until another command replaces it.
+    ///
+    /// `cooling` and `sparking` work as in
+    /// [`LedStripHandle::fire`](crate::led_strip::LedStripHandle::fire), with each column
+    /// burning as its own flame.
+    pub fn fire(&self, cooling: u8, sparking: u8, speed: embassy_time::Duration) -> Result<()> {
+        let _ = (cooling, sparking, speed);
+        Ok(())
+    }
+
+    /// Returns `true` while the panel is running an animation or finishing a command.
+    ///
+    /// See [`LedStripHandle::is_animating`](crate::led_strip::LedStripHandle::is_animating)
+    /// for details.
+    #[must_use]
+    pub fn is_animating(&self) -> bool {
+        false
+    }
+
+    /// Wait until the panel shows a static frame, with no animation running and no
+    /// command pending.
+    ///
+    /// See [`LedStripHandle::wait_idle`](crate::led_strip::LedStripHandle::wait_idle) for
+    /// details.
+    pub async fn wait_idle(&self) {}
+
+    /// Turn the panel off and stop its background task. Later commands are ignored.
+    ///
+    /// See [`LedStripHandle::shutdown`](crate::led_strip::LedStripHandle::shutdown) for
+    /// details.
+    pub fn shutdown(&self) {}
+}
+
+#[cfg(doc)]
+impl crate::led2d::LedPanel for Led2dGenerated {
+    type Frame = Frame2d<12, 4>;
+
+    fn device_info(&self) -> crate::led2d::DeviceInfo {
+        crate::led2d::DeviceInfo {
+            len: 48,
+            width: 12,
+            height: 4,
+            max_frames: 16,
+        }
+    }
+
+    fn write_frame(&self, frame: Self::Frame) -> Result<()> {
+        let _ = frame;
+        Ok(())
+    }
+
+    fn animate<I>(&self, frames: I) -> Result<()>
+    where
+        I: IntoIterator,
+        I::Item: core::borrow::Borrow<(Self::Frame, embassy_time::Duration)>,
+    {
         let _ = frames;
         Ok(())
     }
 }
+
+#[cfg(doc)]
+impl crate::led2d::LedDisplay for Led2dGenerated {
+    fn fill(&self, color: crate::led_strip::RGB8) -> Result<()> {
+        let _ = color;
+        Ok(())
+    }
+
+    fn len(&self) -> usize {
+        48
+    }
+}
 "#;
 
 pub fn generate_led2d_generated(workspace_root: &Path) -> Result<(), Box<dyn Error>> {
@@ -31,7 +31,7 @@ led_strip! {
 pub struct LedStripGenerated;
 
 #[cfg(doc)]
-use crate::led_strip::{Current, Frame1d, LedStripStatic};
+use crate::led_strip::{AnimationTrigger, Current, Frame1d, LedStripHandle, LedStripStatic, RGB8};
 #[cfg(doc)]
 use crate::Result;
 
@@ -102,8 +102,25 @@ impl LedStripGenerated {
         Ok(&INSTANCE)
     }
 
+    /// Return a copyable handle that other tasks can use to write to this strip.
+    ///
+    /// See [`LedStripHandle`] for details.
+    #[must_use]
+    pub fn handle(&self) -> LedStripHandle<{ Self::LEN }, { Self::MAX_FRAMES }> {
+        static LED_STRIP_STATIC: LedStripStatic<48, 16> = LedStripStatic::new_static();
+        let _ = self;
+        LedStripHandle {
+            command_signal: &LED_STRIP_STATIC.command_signal,
+            current_frame: &LED_STRIP_STATIC.current_frame,
+        }
+    }
+
     /// Write a frame to the LED strip.
     ///
+    /// Never blocks. A newer command replaces a pending one, so at high frame rates
+    /// stale frames are skipped rather than queued; see
+    /// [`LedStripHandle::write_frame`](crate::led_strip::LedStripHandle::write_frame).
+    ///
     /// See the [`led_strip`](mod@crate::led_strip) module docs for usage.
     pub fn write_frame(
         &self,
@@ -116,13 +133,199 @@ impl LedStripGenerated {
     /// Animate frames on the LED strip.
     ///
     /// See the [`led_strip`](mod@crate::led_strip) module docs for usage.
-    pub fn animate<const N: usize>(
+    pub fn animate<I>(&self, frames: I) -> Result<()>
+    where
+        I: IntoIterator,
+        I::Item: core::borrow::Borrow<(Frame1d<{ Self::LEN }>, embassy_time::Duration)>,
+    {
+        let _ = frames;
+        Ok(())
+    }
+
+    /// Animate frames on the LED strip, calling `callback(frame_index)` after each
+    /// frame is written to the hardware.
+    ///
+    /// See [`LedStripHandle::animate_with_callback`] for details.
+    pub fn animate_with_callback<I>(&self, frames: I, callback: fn(usize)) -> Result<()>
+    where
+        I: IntoIterator,
+        I::Item: core::borrow::Borrow<(Frame1d<{ Self::LEN }>, embassy_time::Duration)>,
+    {
+        let _ = (frames, callback);
+        Ok(())
+    }
+
+    /// Animate frames on the LED strip, showing each for the same `frame_duration`.
+    ///
+    /// See [`LedStripHandle::animate_uniform`] for details.
+    pub fn animate_uniform<I>(&self, frames: I, frame_duration: embassy_time::Duration) -> Result<()>
+    where
+        I: IntoIterator,
+        I::Item: core::borrow::Borrow<Frame1d<{ Self::LEN }>>,
+    {
+        let _ = (frames, frame_duration);
+        Ok(())
+    }
+
+    /// Like [`animate`](Self::animate), but the animation starts only when `trigger` is
+    /// signaled.
+    ///
+    /// See [`LedStripHandle::animate_on_signal`] for details.
+    pub fn animate_on_signal<I>(&self, frames: I, trigger: &'static AnimationTrigger) -> Result<()>
+    where
+        I: IntoIterator,
+        I::Item: core::borrow::Borrow<(Frame1d<{ Self::LEN }>, embassy_time::Duration)>,
+    {
+        let _ = (frames, trigger);
+        Ok(())
+    }
+
+    /// Overwrite the LEDs starting at `start_led` with `pixels`, leaving the rest of the
+    /// last written frame unchanged.
+    ///
+    /// See [`LedStripHandle::write_segment`] for details.
+    pub fn write_segment(&self, pixels: &[RGB8], start_led: usize) -> Result<()> {
+        let _ = (pixels, start_led);
+        Ok(())
+    }
+
+    /// Write a frame from static memory without copying it onto the stack.
+    ///
+    /// See [`LedStripHandle::write_static_frame`] for details.
+    pub fn write_static_frame(&self, frame: &'static Frame1d<{ Self::LEN }>) -> Result<()> {
+        let _ = frame;
+        Ok(())
+    }
+
+    /// Animate frames from static memory without copying or buffering them.
+    ///
+    /// See [`LedStripHandle::animate_static_frames`] for details.
+    pub fn animate_static_frames(
         &self,
-        frames: [(Frame1d<{ Self::LEN }>, embassy_time::Duration); N],
+        frames: &'static [(Frame1d<{ Self::LEN }>, embassy_time::Duration)],
     ) -> Result<()> {
         let _ = frames;
         Ok(())
     }
+
+    /// Animate frames that `generate(frame_index)` computes on demand, without
+    /// buffering them.
+    ///
+    /// See [`LedStripHandle::animate_generated`] for details.
+    pub fn animate_generated(
+        &self,
+        frame_count: usize,
+        frame_duration: embassy_time::Duration,
+        generate: fn(usize) -> Frame1d<{ Self::LEN }>,
+    ) -> Result<()> {
+        let _ = (frame_count, frame_duration, generate);
+        Ok(())
+    }
+
+    /// Show a flickering fire that rises from LED 0, computing a new frame every `speed`.
+    ///
+    /// See [`LedStripHandle::fire`] for details.
+    pub fn fire(&self, cooling: u8, sparking: u8, speed: embassy_time::Duration) -> Result<()> {
+        let _ = (cooling, sparking, speed);
+        Ok(())
+    }
+
+    /// Play frames from `frames` in order as they arrive, showing each for
+    /// `frame_duration`.
+    ///
+    /// See [`LedStripHandle::stream`] for details.
+    pub fn stream<const DEPTH: usize>(
+        &self,
+        frames: &'static crate::led_strip::FrameChannel<{ Self::LEN }, DEPTH>,
+        frame_duration: embassy_time::Duration,
+    ) -> Result<()> {
+        let _ = (frames, frame_duration);
+        Ok(())
+    }
+
+    /// Returns `true` while the strip is running an animation or finishing a command.
+    ///
+    /// See [`LedStripHandle::is_animating`] for details.
+    #[must_use]
+    pub fn is_animating(&self) -> bool {
+        false
+    }
+
+    /// Wait until the strip shows a static frame, with no animation running and no
+    /// command pending.
+    ///
+    /// See [`LedStripHandle::wait_idle`] for details.
+    pub async fn wait_idle(&self) {}
+
+    /// Scale the strip's output by `brightness` out of 255, on top of the `max_current`
+    /// limit.
+    ///
+    /// See [`LedStripHandle::set_brightness`] for details.
+    pub fn set_brightness(&self, brightness: u8) {
+        let _ = brightness;
+    }
+
+    /// The brightness last set with [`set_brightness`](Self::set_brightness).
+    #[must_use]
+    pub fn brightness(&self) -> u8 {
+        u8::MAX
+    }
+
+    /// Follow ambient light, setting the brightness from `sensor` every `poll_interval`.
+    /// Never returns.
+    ///
+    /// See [`LedStripHandle::ambient_mode`] for details.
+    pub async fn ambient_mode(&self, sensor: fn() -> u8, poll_interval: embassy_time::Duration) -> ! {
+        let _ = (sensor, poll_interval);
+        loop {
+            core::future::pending::<()>().await;
+        }
+    }
+
+    /// Turn the LEDs off and stop the strip's background task.
+    ///
+    /// See [`LedStripHandle::shutdown`] for details.
+    pub fn shutdown(&self) {}
+}
+
+#[cfg(doc)]
+impl crate::led2d::LedPanel for LedStripGenerated {
+    type Frame = Frame1d<48>;
+
+    fn device_info(&self) -> crate::led2d::DeviceInfo {
+        crate::led2d::DeviceInfo {
+            len: 48,
+            width: 48,
+            height: 1,
+            max_frames: 16,
+        }
+    }
+
+    fn write_frame(&self, frame: Self::Frame) -> Result<()> {
+        let _ = frame;
+        Ok(())
+    }
+
+    fn animate<I>(&self, frames: I) -> Result<()>
+    where
+        I: IntoIterator,
+        I::Item: core::borrow::Borrow<(Self::Frame, embassy_time::Duration)>,
+    {
+        let _ = frames;
+        Ok(())
+    }
+}
+
+#[cfg(doc)]
+impl crate::led2d::LedDisplay for LedStripGenerated {
+    fn fill(&self, color: crate::led_strip::RGB8) -> Result<()> {
+        let _ = color;
+        Ok(())
+    }
+
+    fn len(&self) -> usize {
+        48
+    }
 }
 "#;
 